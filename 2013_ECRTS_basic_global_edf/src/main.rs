@@ -1,13 +1,18 @@
 use clap::Parser;
 use lib::{
     dag_creator::create_dag_set_from_dir,
-    dag_set_scheduler::{DAGSetSchedulerBase, PreemptiveType},
+    dag_set_scheduler::{DAGSetSchedulerBase, PreemptiveType, SchedulerConfig},
     global_edf_scheduler::GlobalEDFScheduler,
-    graph_extension::GraphExtension,
     homogeneous::HomogeneousProcessor,
-    log::dump_dag_set_scheduler_result_to_yaml,
+    log::{
+        dump_dag_set_scheduler_result_to_writer, dump_dag_set_scheduler_result_to_yaml,
+        dump_struct_to_writer,
+    },
     processor::ProcessorBase,
-    util::{adjust_to_implicit_deadline, load_yaml},
+    util::{
+        adjust_to_implicit_deadline, dump_dag_set_to_dot, validate_dag_set_ids,
+        validate_dag_set_required_params,
+    },
 };
 
 #[derive(Parser)]
@@ -32,13 +37,31 @@ struct ArgParser {
     ///Enable or disable preemptive scheduling.
     #[clap(short = 'p', long = "enable_preemption", default_value = "false")]
     enable_preemption: bool,
+    ///Cache-related preemption delay added each time a preempted node resumes.
+    #[clap(long = "crpd", default_value = "0")]
+    crpd: i32,
+    ///Write the log and the result to stdout instead of a file in the output directory.
+    #[clap(long = "stdout")]
+    stdout: bool,
+    ///Stop the simulation at this time instead of the hyper-period. The results are partial if it is shorter.
+    #[clap(long = "hyper_period")]
+    hyper_period: Option<i32>,
+    ///Write each DAG to a Graphviz DOT file in the output directory. Nodes on the critical path are colored red.
+    #[clap(long = "dump_dot")]
+    dump_dot: bool,
 }
 
 fn main() {
     let arg: ArgParser = ArgParser::parse();
 
     let mut dag_set = create_dag_set_from_dir(&arg.dag_dir_path);
+    validate_dag_set_ids(&dag_set).unwrap_or_else(|err| panic!("Invalid DAG set: {}", err));
+    validate_dag_set_required_params(&dag_set, &["execution_time"])
+        .unwrap_or_else(|err| panic!("Invalid DAG set: {}", err));
     adjust_to_implicit_deadline(&mut dag_set);
+    if arg.dump_dot {
+        dump_dag_set_to_dot(&dag_set, &arg.output_dir_path);
+    }
 
     let homogeneous_processor = HomogeneousProcessor::new(arg.number_of_cores);
     let mut gedf_scheduler = GlobalEDFScheduler::new(&dag_set, &homogeneous_processor);
@@ -56,23 +79,24 @@ fn main() {
     };
 
     // To make it preemptive, rename the second argument of dump_log.
-    gedf_scheduler.schedule(preemptive_type);
-    let file_path = gedf_scheduler.dump_log(&arg.output_dir_path, file_name);
+    gedf_scheduler.schedule_with_config(
+        &SchedulerConfig::default()
+            .preemptive_type(preemptive_type)
+            .crpd(arg.crpd)
+            .hyper_period_override(arg.hyper_period),
+    );
 
-    // Check the result
-    let yaml_doc = &load_yaml(&file_path)[0];
-    let dag_set_log = &yaml_doc["dag_set_log"];
-    let mut result = true;
-    for dag in dag_set {
-        if dag_set_log[dag.get_dag_param("dag_id") as usize]["worst_response_time"]
-            .as_i64()
-            .unwrap()
-            > dag.get_head_period().unwrap() as i64
-        {
-            result = false;
-            break;
-        }
+    // Every DAG has the implicit deadline, so a deadline miss is equivalent to a response time beyond the period.
+    let result = gedf_scheduler
+        .get_log()
+        .first_deadline_miss(&dag_set)
+        .is_none();
+    if arg.stdout {
+        let mut stdout = std::io::stdout().lock();
+        dump_struct_to_writer(&mut stdout, gedf_scheduler.get_log());
+        dump_dag_set_scheduler_result_to_writer(&mut stdout, result);
+        return;
     }
-
+    let file_path = gedf_scheduler.dump_log(&arg.output_dir_path, file_name);
     dump_dag_set_scheduler_result_to_yaml(&file_path, result);
 }
@@ -81,9 +81,8 @@ pub fn federated(dag_set: &mut [Graph<NodeData, i32>], number_of_cores: usize) -
 
         let utilization = volume as f32 / period as f32;
         if utilization > 1.0 {
-            let high_dedicated_cores = ((volume - critical_path_wcet) as f32
-                / (end_to_end_deadline - critical_path_wcet) as f32)
-                .ceil() as usize;
+            let high_dedicated_cores =
+                calculate_high_dedicated_cores(volume, critical_path_wcet, end_to_end_deadline);
             if high_dedicated_cores > remaining_cores {
                 return Unschedulable {
                     reason: "Insufficient number of cores for high-utilization tasks.".to_string(),
@@ -109,10 +108,86 @@ pub fn federated(dag_set: &mut [Graph<NodeData, i32>], number_of_cores: usize) -
     }
 }
 
-#[cfg(test)]
+/// Number of cores dedicated to a high-utilization DAG, as in the original paper.
+fn calculate_high_dedicated_cores(
+    volume: i32,
+    critical_path_wcet: i32,
+    end_to_end_deadline: i32,
+) -> usize {
+    ((volume - critical_path_wcet) as f32 / (end_to_end_deadline - critical_path_wcet) as f32)
+        .ceil() as usize
+}
+
+/// Minimum number of cores with which `federated` schedules the DAG set: the cores dedicated to the high-utilization DAGs
+/// plus the fewest shared cores exceeding twice the total utilization of the low-utilization DAGs (at least one).
+/// None if the critical path of a DAG exceeds its period, or reaches it for a high-utilization DAG,
+/// in which case no number of cores suffices.
+pub fn federated_required_cores(dag_set: &mut [Graph<NodeData, i32>]) -> Option<usize> {
+    let mut high_dedicated_cores = 0;
+    let mut low_utilizations = 0.0;
+    for dag in dag_set {
+        let period = dag.get_head_period().unwrap();
+        let volume = dag.get_volume();
+        let critical_path = dag.get_critical_path();
+        let critical_path_wcet = dag.get_total_wcet_from_nodes(&critical_path);
+        if critical_path_wcet > period {
+            return None;
+        }
+
+        let utilization = volume as f32 / period as f32;
+        if utilization > 1.0 {
+            // The remaining work after the critical path cannot be parallelized into no time.
+            if critical_path_wcet >= period {
+                return None;
+            }
+            high_dedicated_cores +=
+                calculate_high_dedicated_cores(volume, critical_path_wcet, period);
+        } else {
+            low_utilizations += utilization;
+        }
+    }
+    Some(high_dedicated_cores + (2.0 * low_utilizations).floor() as usize + 1)
+}
 
+/// Upper bound on the response time of a DAG running alone on `allocated_cores` dedicated cores:
+/// critical_path + (volume - critical_path) / allocated_cores, rounded up to an integer.
+pub fn federated_response_bound(dag: &mut Graph<NodeData, i32>, allocated_cores: usize) -> i32 {
+    assert!(allocated_cores > 0, "At least one core must be allocated.");
+    let volume = dag.get_volume();
+    let critical_path = dag.get_critical_path();
+    let critical_path_wcet = dag.get_total_wcet_from_nodes(&critical_path);
+    let allocated_cores = allocated_cores as i32;
+    critical_path_wcet + (volume - critical_path_wcet + allocated_cores - 1) / allocated_cores
+}
+
+/// `federated_response_bound` of each DAG on the cores `federated` dedicates to it.
+/// None for a low-utilization DAG, which shares the remaining cores, and for a DAG whose critical path reaches its deadline.
+pub fn federated_response_bounds(dag_set: &mut [Graph<NodeData, i32>]) -> Vec<Option<i32>> {
+    dag_set
+        .iter_mut()
+        .map(|dag| {
+            let period = dag.get_head_period().unwrap();
+            let volume = dag.get_volume();
+            let critical_path = dag.get_critical_path();
+            let critical_path_wcet = dag.get_total_wcet_from_nodes(&critical_path);
+            if volume <= period || critical_path_wcet >= period {
+                return None;
+            }
+            let high_dedicated_cores =
+                calculate_high_dedicated_cores(volume, critical_path_wcet, period);
+            Some(federated_response_bound(dag, high_dedicated_cores))
+        })
+        .collect()
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
+    use lib::{
+        dag_scheduler::DAGSchedulerBase, fixed_priority_scheduler::FixedPriorityScheduler,
+        homogeneous::HomogeneousProcessor, processor::ProcessorBase,
+    };
+    use petgraph::graph::NodeIndex;
     use std::collections::BTreeMap;
 
     fn create_node(id: i32, key: &str, value: i32) -> NodeData {
@@ -236,6 +311,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_federated_required_cores_normal() {
+        let mut dag_set = vec![
+            create_high_utilization_dag(),
+            create_high_utilization_dag(),
+            create_low_utilization_dag(),
+            create_low_utilization_dag(),
+        ];
+        // 3 cores for each high-utilization DAG, and 2 * (10 / 30) * 2 = 1.33 requires 2 shared cores.
+        let required_cores = federated_required_cores(&mut dag_set).unwrap();
+        assert_eq!(required_cores, 8);
+        assert_eq!(
+            federated(&mut dag_set, required_cores),
+            Schedulable {
+                high_dedicated_cores: 6,
+                low_dedicated_cores: 2
+            }
+        );
+        assert!(matches!(
+            federated(&mut dag_set, required_cores - 1),
+            Unschedulable { .. }
+        ));
+    }
+
+    #[test]
+    fn test_federated_required_cores_unsuited_tasks() {
+        assert_eq!(
+            federated_required_cores(&mut [create_period_exceeding_dag()]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_federated_required_cores_critical_path_equal_to_period() {
+        // The critical path of 8 leaves no time for the remaining volume of 6.
+        let mut high_utilization_dag = create_high_utilization_dag();
+        high_utilization_dag.update_param(NodeIndex::new(0), "period", 8);
+        let mut dag_set = vec![high_utilization_dag];
+
+        assert_eq!(federated_required_cores(&mut dag_set), None);
+        assert_eq!(federated_response_bounds(&mut dag_set), vec![None]);
+    }
+
+    #[test]
+    fn test_federated_response_bound_covers_simulated_makespan() {
+        // Same shape as create_high_utilization_dag, but with unique node ids, which the scheduler requires.
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = {
+            let mut params = BTreeMap::new();
+            params.insert("execution_time".to_owned(), 4);
+            params.insert("period".to_owned(), 10);
+            dag.add_node(NodeData { id: 0, params })
+        };
+        let n1 = dag.add_node(create_node(1, "execution_time", 4));
+        let n2 = dag.add_node(create_node(2, "execution_time", 3));
+        let n3 = dag.add_node(create_node(3, "execution_time", 3));
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n0, n2, 1);
+        dag.add_edge(n0, n3, 1);
+        // volume = 14, critical path = 8 and period = 10, so 3 cores are dedicated.
+        let bound = federated_response_bound(&mut dag, 3);
+        assert_eq!(bound, 10);
+
+        let processor = HomogeneousProcessor::new(3);
+        let (makespan, _) = FixedPriorityScheduler::new(&dag, &processor).schedule();
+        assert!(bound >= makespan);
+        assert!(bound <= dag.get_head_period().unwrap());
+    }
+
+    #[test]
+    fn test_federated_response_bounds_normal() {
+        let mut dag_set = vec![
+            create_high_utilization_dag(),
+            create_low_utilization_dag(),
+            create_period_exceeding_dag(),
+        ];
+        assert_eq!(
+            federated_response_bounds(&mut dag_set),
+            vec![Some(10), None, None]
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_federated_no_has_period() {
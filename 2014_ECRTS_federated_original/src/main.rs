@@ -3,17 +3,21 @@ mod outputs_result;
 
 use clap::Parser;
 use lib::{
-    dag_creator::create_dag_set_from_dir, homogeneous, processor::ProcessorBase,
-    util::create_scheduler_log_yaml,
+    dag_creator::create_dag_set_from_dir,
+    homogeneous,
+    log::{dump_struct_to_writer, DAGSetInfo, ProcessorInfo},
+    processor::ProcessorBase,
+    util::{create_scheduler_log_yaml, dump_dag_set_to_dot, validate_dag_set_required_params},
 };
 use outputs_result::{
-    dump_dag_set_info_to_yaml, dump_federated_result_to_yaml, dump_processor_info_to_yaml,
+    dump_dag_set_info_to_yaml, dump_federated_result_to_writer, dump_federated_result_to_yaml,
+    dump_processor_info_to_yaml, dump_required_cores_to_writer, dump_required_cores_to_yaml,
+    dump_response_bounds_to_writer, dump_response_bounds_to_yaml,
 };
 
 /// Application description and arguments definition using clap crate
 #[derive(Parser)]
 #[clap()]
-
 /// Application arguments definition using clap crate
 struct AppArg {
     #[clap(short = 'd', long = "dag_dir_path", required = true)]
@@ -22,6 +26,12 @@ struct AppArg {
     number_of_cores: usize,
     #[clap(short = 'o', long = "output_dir_path", default_value = "../outputs")]
     output_dir_path: String,
+    ///Write the result to stdout instead of a file in the output directory.
+    #[clap(long = "stdout")]
+    stdout: bool,
+    ///Write each DAG to a Graphviz DOT file in the output directory. Nodes on the critical path are colored red.
+    #[clap(long = "dump_dot")]
+    dump_dot: bool,
 }
 
 /// Application main function
@@ -30,10 +40,28 @@ fn main() {
     let dag_dir_path = arg.dag_dir_path;
     let number_of_cores = arg.number_of_cores;
     let mut dag_set = create_dag_set_from_dir(&dag_dir_path);
+    validate_dag_set_required_params(&dag_set, &["execution_time"])
+        .unwrap_or_else(|err| panic!("Invalid DAG set: {}", err));
+    if arg.dump_dot {
+        dump_dag_set_to_dot(&dag_set, &arg.output_dir_path);
+    }
     let result = federated::federated(&mut dag_set, number_of_cores);
+    let response_bounds = federated::federated_response_bounds(&mut dag_set);
+    let required_cores = federated::federated_required_cores(&mut dag_set);
+    if arg.stdout {
+        let mut stdout = std::io::stdout().lock();
+        dump_struct_to_writer(&mut stdout, &DAGSetInfo::new(&dag_set));
+        dump_struct_to_writer(&mut stdout, &ProcessorInfo::new(number_of_cores));
+        dump_federated_result_to_writer(&mut stdout, result);
+        dump_response_bounds_to_writer(&mut stdout, response_bounds);
+        dump_required_cores_to_writer(&mut stdout, required_cores);
+        return;
+    }
     let file_path = create_scheduler_log_yaml(&arg.output_dir_path, "federated");
     let homogeneous_processor = homogeneous::HomogeneousProcessor::new(number_of_cores);
     dump_dag_set_info_to_yaml(&file_path, dag_set);
     dump_processor_info_to_yaml(&file_path, &homogeneous_processor);
     dump_federated_result_to_yaml(&file_path, result);
+    dump_response_bounds_to_yaml(&file_path, response_bounds);
+    dump_required_cores_to_yaml(&file_path, required_cores);
 }
@@ -1,11 +1,12 @@
 use crate::federated::FederateResult;
 use lib::{
     graph_extension::NodeData,
-    log::{dump_struct, DAGSetInfo, ProcessorInfo},
+    log::{dump_struct, dump_struct_to_writer, DAGSetInfo, ProcessorInfo},
     processor::ProcessorBase,
 };
 use petgraph::Graph;
 use serde_derive::{Deserialize, Serialize};
+use std::io::Write;
 
 #[derive(Serialize, Deserialize)]
 struct ResultInfo<FederateResult> {
@@ -17,6 +18,43 @@ pub(crate) fn dump_federated_result_to_yaml(file_path: &str, result: FederateRes
     dump_struct(file_path, &result_info);
 }
 
+pub(crate) fn dump_federated_result_to_writer(writer: &mut impl Write, result: FederateResult) {
+    let result_info = ResultInfo { result };
+    dump_struct_to_writer(writer, &result_info);
+}
+
+#[derive(Serialize, Deserialize)]
+struct ResponseBoundsInfo {
+    response_bounds: Vec<Option<i32>>,
+}
+
+pub(crate) fn dump_response_bounds_to_yaml(file_path: &str, response_bounds: Vec<Option<i32>>) {
+    dump_struct(file_path, &ResponseBoundsInfo { response_bounds });
+}
+
+pub(crate) fn dump_response_bounds_to_writer(
+    writer: &mut impl Write,
+    response_bounds: Vec<Option<i32>>,
+) {
+    dump_struct_to_writer(writer, &ResponseBoundsInfo { response_bounds });
+}
+
+#[derive(Serialize, Deserialize)]
+struct RequiredCoresInfo {
+    required_cores: Option<usize>,
+}
+
+pub(crate) fn dump_required_cores_to_yaml(file_path: &str, required_cores: Option<usize>) {
+    dump_struct(file_path, &RequiredCoresInfo { required_cores });
+}
+
+pub(crate) fn dump_required_cores_to_writer(
+    writer: &mut impl Write,
+    required_cores: Option<usize>,
+) {
+    dump_struct_to_writer(writer, &RequiredCoresInfo { required_cores });
+}
+
 pub(crate) fn dump_dag_set_info_to_yaml(file_path: &str, dag_set: Vec<Graph<NodeData, i32>>) {
     let dag_set_info = DAGSetInfo::new(&dag_set);
     dump_struct(file_path, &dag_set_info);
@@ -189,6 +227,35 @@ mod tests {
         remove_file(file_path).unwrap();
     }
 
+    #[test]
+    fn test_dump_response_bounds_to_yaml_normal() {
+        let mut dag_set = vec![create_high_utilization_dag(), create_low_utilization_dag()];
+        let response_bounds = crate::federated::federated_response_bounds(&mut dag_set);
+        let file_path = create_yaml("../lib/tests", "test_dump_response_bounds");
+        dump_response_bounds_to_yaml(&file_path, response_bounds);
+
+        let yaml_docs = load_yaml(&file_path);
+        let yaml_doc = &yaml_docs[0];
+
+        assert_eq!(yaml_doc["response_bounds"][0].as_i64().unwrap(), 10);
+        assert!(yaml_doc["response_bounds"][1].is_null());
+
+        remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_dump_required_cores_to_yaml_normal() {
+        let mut dag_set = vec![create_high_utilization_dag(), create_low_utilization_dag()];
+        let required_cores = crate::federated::federated_required_cores(&mut dag_set);
+        let file_path = create_yaml("../lib/tests", "test_dump_required_cores");
+        dump_required_cores_to_yaml(&file_path, required_cores);
+
+        let yaml_docs = load_yaml(&file_path);
+        assert_eq!(yaml_docs[0]["required_cores"].as_i64().unwrap(), 4);
+
+        remove_file(file_path).unwrap();
+    }
+
     #[test]
     fn test_dump_dag_set_info_to_yaml_normal() {
         let dag_set = vec![create_high_utilization_dag(), create_high_utilization_dag()];
@@ -1,5 +1,7 @@
-use crate::handle_segment::{calculate_segments_deadline, create_segments};
-use lib::graph_extension::{GraphExtension, NodeData};
+use crate::handle_segment::{
+    calculate_segments_deadline, create_segments, validate_segment_deadlines,
+};
+use lib::graph_extension::{GraphExtension, NodeData, INT_SCALED_DEADLINE_FACTOR};
 use petgraph::{graph::Graph, visit::Topo};
 use std::vec;
 
@@ -7,10 +9,14 @@ use std::vec;
 pub fn decompose(dag: &mut Graph<NodeData, i32>) {
     let mut segments = create_segments(dag);
     calculate_segments_deadline(dag, &mut segments);
+    debug_assert_eq!(
+        validate_segment_deadlines(&segments, dag.get_head_period().unwrap() as f32),
+        Ok(())
+    );
 
     // `deadline_factor` is used to scale the deadline of a node to an integer type.
     // The fifth decimal place is truncated.
-    let deadline_factor = 100000.0;
+    let deadline_factor = INT_SCALED_DEADLINE_FACTOR as f32;
     let mut int_scaled_deadline = vec![0; dag.node_count()];
     for segment in segments.iter() {
         segment.nodes.iter().for_each(|node| {
@@ -92,5 +98,6 @@ mod tests {
                 expect_relative_deadline[node_i.index()]
             );
         }
+        assert!(dag.find_infeasible_node_deadlines().is_empty());
     }
 }
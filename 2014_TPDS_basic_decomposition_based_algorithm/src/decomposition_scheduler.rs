@@ -0,0 +1,139 @@
+//! Scheduler that applies the decomposition-based algorithm end-to-end.
+//! Each DAG is decomposed into segments, every node is given an intermediate deadline derived from the segment deadlines,
+//! and the DAG set is scheduled by global EDF on those intermediate deadlines.
+use crate::decomposition::decompose;
+use lib::{
+    dag_set_scheduler::{DAGSetSchedulerBase, DAGStateManager, NodeDataWrapper, SchedulerConfig},
+    getset_dag_set_scheduler,
+    graph_extension::NodeData,
+    homogeneous::HomogeneousProcessor,
+    log::DAGSetSchedulerLog,
+    processor::ProcessorBase,
+    util::adjust_to_implicit_deadline,
+};
+use petgraph::graph::Graph;
+use std::collections::{BTreeMap, BTreeSet};
+
+pub struct DecompositionScheduler {
+    dag_set: Vec<Graph<NodeData, i32>>,
+    processor: HomogeneousProcessor,
+    log: DAGSetSchedulerLog,
+    current_time: i32,
+    ready_queue: BTreeSet<NodeDataWrapper>,
+    managers: Vec<DAGStateManager>,
+    remaining_times: BTreeMap<(usize, i32), i32>,
+    config: SchedulerConfig,
+}
+
+impl DAGSetSchedulerBase<HomogeneousProcessor> for DecompositionScheduler {
+    /// The DAGs are adjusted to the implicit deadline (T = D) and decomposed here.
+    fn new(dag_set: &[Graph<NodeData, i32>], processor: &HomogeneousProcessor) -> Self {
+        let mut dag_set = dag_set.to_vec();
+        adjust_to_implicit_deadline(&mut dag_set);
+        for dag in dag_set.iter_mut() {
+            decompose(dag);
+        }
+
+        Self {
+            log: DAGSetSchedulerLog::new(&dag_set, processor.get_number_of_cores()),
+            dag_set,
+            processor: processor.clone(),
+            current_time: 0,
+            ready_queue: BTreeSet::new(),
+            managers: Vec::new(),
+            remaining_times: BTreeMap::new(),
+            config: SchedulerConfig::default(),
+        }
+    }
+
+    getset_dag_set_scheduler!(HomogeneousProcessor);
+}
+
+impl DecompositionScheduler {
+    /// Schedulability verdict after `schedule`: true if every DAG instance finished within its implicit deadline (T = D).
+    pub fn is_schedulable(&self) -> bool {
+        self.log.first_deadline_miss(&self.dag_set).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib::{dag_set_scheduler::PreemptiveType, graph_extension::GraphExtension};
+    use petgraph::graph::NodeIndex;
+    use std::collections::BTreeMap;
+
+    fn create_node(id: i32, key: &str, value: i32) -> NodeData {
+        let mut params = BTreeMap::new();
+        params.insert(key.to_string(), value);
+        NodeData { id, params }
+    }
+
+    fn create_sample_dag(period: i32) -> Graph<NodeData, i32> {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 4));
+        let n1 = dag.add_node(create_node(1, "execution_time", 7));
+        let n2 = dag.add_node(create_node(2, "execution_time", 55));
+        let n3 = dag.add_node(create_node(3, "execution_time", 36));
+        let n4 = dag.add_node(create_node(4, "execution_time", 54));
+        dag.add_param(n0, "period", period);
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n0, n2, 1);
+        dag.add_edge(n1, n3, 1);
+        dag.add_edge(n2, n4, 1);
+        dag.set_dag_param("dag_id", 0);
+
+        dag
+    }
+
+    fn get_start_order(scheduler: &DecompositionScheduler) -> Vec<usize> {
+        let mut job_intervals = scheduler.get_log().get_job_intervals();
+        job_intervals.sort_by_key(|job_interval| job_interval.start_time);
+        job_intervals
+            .iter()
+            .map(|job_interval| job_interval.node_id)
+            .collect()
+    }
+
+    fn create_single_sink_dag(period: i32) -> Graph<NodeData, i32> {
+        let mut dag = create_sample_dag(period);
+        let n5 = dag.add_node(create_node(5, "execution_time", 3));
+        dag.add_edge(NodeIndex::new(3), n5, 1);
+        dag.add_edge(NodeIndex::new(4), n5, 1);
+        dag.set_dag_param("dag_id", 0);
+
+        dag
+    }
+
+    #[test]
+    fn test_decomposition_scheduler_segment_deadlines_drive_schedule() {
+        let dag_set = vec![create_single_sink_dag(200)];
+        let mut scheduler = DecompositionScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        for node in scheduler.get_dag_set()[0].node_weights() {
+            assert!(node
+                .params
+                .contains_key("int_scaled_node_relative_deadline"));
+        }
+        scheduler.schedule(PreemptiveType::NonPreemptive);
+
+        // n3 has an earlier intermediate deadline than n2, so it runs first although n2 has been ready since time 4.
+        // Every node runs, and the DAG finishes when the single sink n5 does.
+        assert_eq!(get_start_order(&scheduler), vec![0, 1, 3, 2, 4, 5]);
+        assert_eq!(scheduler.get_log().get_finish_times(0), &[159]);
+        assert!(scheduler.is_schedulable());
+    }
+
+    #[test]
+    fn test_decomposition_scheduler_schedulable() {
+        let dag_set = vec![create_sample_dag(120)];
+        let mut scheduler = DecompositionScheduler::new(&dag_set, &HomogeneousProcessor::new(2));
+        scheduler.schedule(PreemptiveType::NonPreemptive);
+
+        assert_eq!(get_start_order(&scheduler), vec![0, 1, 2, 3, 4]);
+        assert!(scheduler.is_schedulable());
+        assert_eq!(
+            scheduler.get_dag_set()[0].get_end_to_end_deadline(),
+            Some(120)
+        );
+    }
+}
@@ -161,6 +161,31 @@ pub fn calculate_segments_deadline(dag: &mut Graph<NodeData, i32>, segments: &mu
     }
 }
 
+/// Check that the segment deadlines partition the end-to-end deadline of the DAG.
+/// Every segment deadline must be positive so that the absolute deadlines of the segments increase monotonically,
+/// and their sum must equal `dag_deadline` within the rounding error of f32.
+pub fn validate_segment_deadlines(segments: &[Segment], dag_deadline: f32) -> Result<(), String> {
+    let mut total_deadline = 0.0;
+    for (i, segment) in segments.iter().enumerate() {
+        if segment.deadline <= 0.0 {
+            return Err(format!(
+                "The deadline of segment {} is not positive: {}",
+                i, segment.deadline
+            ));
+        }
+        total_deadline += segment.deadline;
+    }
+
+    let tolerance = dag_deadline.abs().max(1.0) * 1e-4;
+    if (total_deadline - dag_deadline).abs() > tolerance {
+        return Err(format!(
+            "The sum of the segment deadlines ({}) does not equal the DAG deadline ({})",
+            total_deadline, dag_deadline
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,6 +286,7 @@ mod tests {
         assert_eq!(segments[2].deadline, 69.23077);
         assert_eq!(segments[3].deadline, 11.538462);
         assert_eq!(segments[4].deadline, 51.923077);
+        assert_eq!(validate_segment_deadlines(&segments, 150.0), Ok(()));
     }
 
     #[test]
@@ -274,6 +300,7 @@ mod tests {
         assert_eq!(segments[2].deadline, 20.707964);
         assert_eq!(segments[3].deadline, 6.9026546);
         assert_eq!(segments[4].deadline, 31.061947);
+        assert_eq!(validate_segment_deadlines(&segments, 65.0), Ok(()));
     }
 
     #[test]
@@ -287,5 +314,24 @@ mod tests {
         assert_eq!(segments[2].deadline, 53.16279);
         assert_eq!(segments[3].deadline, 9.685715);
         assert_eq!(segments[4].deadline, 43.585712);
+        assert_eq!(validate_segment_deadlines(&segments, 120.0), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_segment_deadlines_inconsistent() {
+        let mut dag = create_sample_dag(120);
+        let mut segments = create_segments(&mut dag);
+        calculate_segments_deadline(&mut dag, &mut segments);
+
+        // Off-by-one in the distribution.
+        segments[2].deadline += 1.0;
+        assert!(validate_segment_deadlines(&segments, 120.0)
+            .unwrap_err()
+            .contains("does not equal the DAG deadline"));
+
+        segments[2].deadline = 0.0;
+        assert!(validate_segment_deadlines(&segments, 120.0)
+            .unwrap_err()
+            .contains("segment 2 is not positive"));
     }
 }
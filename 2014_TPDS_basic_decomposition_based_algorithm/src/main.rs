@@ -1,17 +1,19 @@
 mod decomposition;
+mod decomposition_scheduler;
 mod handle_segment;
 
 use clap::Parser;
-use decomposition::decompose;
+use decomposition_scheduler::DecompositionScheduler;
 use lib::{
     dag_creator::create_dag_set_from_dir,
-    dag_set_scheduler::{DAGSetSchedulerBase, PreemptiveType},
-    global_edf_scheduler::GlobalEDFScheduler,
-    graph_extension::GraphExtension,
+    dag_set_scheduler::{DAGSetSchedulerBase, PreemptiveType, SchedulerConfig},
     homogeneous::HomogeneousProcessor,
-    log::dump_dag_set_scheduler_result_to_yaml,
+    log::{
+        dump_dag_set_scheduler_result_to_writer, dump_dag_set_scheduler_result_to_yaml,
+        dump_struct_to_writer,
+    },
     processor::ProcessorBase,
-    util::{adjust_to_implicit_deadline, load_yaml},
+    util::{dump_dag_set_to_dot, validate_dag_set_ids, validate_dag_set_required_params},
 };
 
 #[derive(Parser)]
@@ -36,20 +38,33 @@ struct ArgParser {
     ///Enable or disable preemptive scheduling.
     #[clap(short = 'p', long = "enable_preemption", default_value = "false")]
     enable_preemption: bool,
+    ///Cache-related preemption delay added each time a preempted node resumes.
+    #[clap(long = "crpd", default_value = "0")]
+    crpd: i32,
+    ///Write the log and the result to stdout instead of a file in the output directory.
+    #[clap(long = "stdout")]
+    stdout: bool,
+    ///Stop the simulation at this time instead of the hyper-period. The results are partial if it is shorter.
+    #[clap(long = "hyper_period")]
+    hyper_period: Option<i32>,
+    ///Write each DAG to a Graphviz DOT file in the output directory. Nodes on the critical path are colored red.
+    #[clap(long = "dump_dot")]
+    dump_dot: bool,
 }
 
 fn main() {
     let arg: ArgParser = ArgParser::parse();
 
-    let mut dag_set = create_dag_set_from_dir(&arg.dag_dir_path);
-    adjust_to_implicit_deadline(&mut dag_set);
-    // Decompose DAGs
-    for dag in dag_set.iter_mut() {
-        decompose(dag);
+    let dag_set = create_dag_set_from_dir(&arg.dag_dir_path);
+    validate_dag_set_ids(&dag_set).unwrap_or_else(|err| panic!("Invalid DAG set: {}", err));
+    validate_dag_set_required_params(&dag_set, &["execution_time"])
+        .unwrap_or_else(|err| panic!("Invalid DAG set: {}", err));
+    if arg.dump_dot {
+        dump_dag_set_to_dot(&dag_set, &arg.output_dir_path);
     }
-
     let homogeneous_processor = HomogeneousProcessor::new(arg.number_of_cores);
-    let mut gedf_scheduler = GlobalEDFScheduler::new(&dag_set, &homogeneous_processor);
+    // DAGs are adjusted to the implicit deadline and decomposed in the scheduler.
+    let mut decomposition_scheduler = DecompositionScheduler::new(&dag_set, &homogeneous_processor);
 
     // Change whether it is preemptive or not depending on the argument
     let (preemptive_type, file_name) = if arg.enable_preemption {
@@ -63,23 +78,21 @@ fn main() {
         (PreemptiveType::NonPreemptive, "decomp_gedf_non_preemptive")
     };
 
-    gedf_scheduler.schedule(preemptive_type);
-    let file_path = gedf_scheduler.dump_log(&arg.output_dir_path, file_name);
-
+    decomposition_scheduler.schedule_with_config(
+        &SchedulerConfig::default()
+            .preemptive_type(preemptive_type)
+            .crpd(arg.crpd)
+            .hyper_period_override(arg.hyper_period),
+    );
     // Check the result
-    let yaml_doc = &load_yaml(&file_path)[0];
-    let dag_set_log = &yaml_doc["dag_set_log"];
-    let mut result = true;
-    for dag in dag_set {
-        if dag_set_log[dag.get_dag_param("dag_id") as usize]["worst_response_time"]
-            .as_i64()
-            .unwrap()
-            > dag.get_head_period().unwrap() as i64
-        {
-            result = false;
-            break;
-        }
-    }
+    let result = decomposition_scheduler.is_schedulable();
 
-    dump_dag_set_scheduler_result_to_yaml(&file_path, result);
+    if arg.stdout {
+        let mut stdout = std::io::stdout().lock();
+        dump_struct_to_writer(&mut stdout, decomposition_scheduler.get_log());
+        dump_dag_set_scheduler_result_to_writer(&mut stdout, result);
+    } else {
+        let file_path = decomposition_scheduler.dump_log(&arg.output_dir_path, file_name);
+        dump_dag_set_scheduler_result_to_yaml(&file_path, result);
+    }
 }
@@ -3,12 +3,20 @@ mod prioritization_cpc_model;
 
 use clap::Parser;
 use lib::{
-    dag_creator::create_dag_from_yaml, dag_scheduler::DAGSchedulerBase,
-    fixed_priority_scheduler::FixedPriorityScheduler, graph_extension::GraphExtension,
-    homogeneous::HomogeneousProcessor, log::dump_dag_scheduler_result_to_yaml,
+    dag_creator::create_dag_from_yaml,
+    dag_scheduler::DAGSchedulerBase,
+    graph_extension::GraphExtension,
+    homogeneous::HomogeneousProcessor,
+    log::{
+        dump_dag_scheduler_result_to_writer, dump_dag_scheduler_result_to_yaml,
+        dump_struct_to_writer,
+    },
     processor::ProcessorBase,
+    util::dump_dag_to_dot,
 };
-use log::warn;
+use log::{info, warn};
+use prioritization_cpc_model::{schedule_with_cached_priorities, PriorityCache};
+use std::io::Write;
 
 #[derive(Parser)]
 #[clap(
@@ -24,15 +32,30 @@ struct ArgParser {
     ///Path to DAG file.
     #[clap(short = 'f', long = "dag_file_path", required = true)]
     dag_file_path: String,
-    ///Number of processing cores.
-    #[clap(short = 'c', long = "number_of_cores", required = true)]
-    number_of_cores: usize,
+    ///Number of processing cores. A comma-separated list schedules the DAG for each number of cores,
+    ///computing the priorities only once.
+    #[clap(
+        short = 'c',
+        long = "number_of_cores",
+        required = true,
+        value_delimiter = ','
+    )]
+    number_of_cores: Vec<usize>,
     ///Path to output directory.
     #[clap(short = 'o', long = "output_dir_path", default_value = "../outputs")]
     output_dir_path: String,
     ///Multiplier to compute constrained deadlines
     #[clap(short = 'r', long = "ratio_deadline_to_period", default_value = "1.0")]
     ratio_deadline_to_period: f32,
+    ///Maximum recursion depth of the priority assignment. Unlimited if not specified.
+    #[clap(long = "max_recursion_depth")]
+    max_recursion_depth: Option<usize>,
+    ///Write the log and the result to stdout instead of a file in the output directory.
+    #[clap(long = "stdout")]
+    stdout: bool,
+    ///Write the DAG with the assigned priorities to a Graphviz DOT file in the output directory. Nodes on the critical path are colored red.
+    #[clap(long = "dump_dot")]
+    dump_dot: bool,
 }
 
 fn main() {
@@ -41,23 +64,75 @@ fn main() {
         panic!("ratio_deadline_to_period must be less than or equal to 1.0");
     }
     let mut dag = create_dag_from_yaml(&arg.dag_file_path, false);
-    let homogeneous_processor = HomogeneousProcessor::new(arg.number_of_cores);
-    prioritization_cpc_model::assign_priority_to_cpc_model(&mut dag);
-    let mut fixed_priority_scheduler = FixedPriorityScheduler::new(&dag, &homogeneous_processor);
-    let (schedule_length, _) = fixed_priority_scheduler.schedule();
+    dag.validate_required_params(&["execution_time"])
+        .unwrap_or_else(|missing_params| {
+            panic!(
+                "Nodes without required parameters (node id, key): {:?}",
+                missing_params
+            )
+        });
     let constrained_end_to_end_deadline = if let Some(deadline) = dag.get_end_to_end_deadline() {
         deadline as f32
     } else {
         warn!("Since the end-to-end deadline is not set in the input DAG, the end-to-end deadline is determined using ratio_deadline_to_period.");
         dag.get_head_period().unwrap() as f32 * arg.ratio_deadline_to_period
     };
-    let result = (schedule_length as f32) <= constrained_end_to_end_deadline;
-    let file_path = fixed_priority_scheduler.dump_log(&arg.output_dir_path, "cpc_model_based");
+    let mut priority_cache = PriorityCache::new(arg.max_recursion_depth.unwrap_or(usize::MAX));
+    let mut stdout = std::io::stdout().lock();
+    for (i, &number_of_cores) in arg.number_of_cores.iter().enumerate() {
+        let homogeneous_processor = HomogeneousProcessor::new(number_of_cores);
+        let (schedule_length, fixed_priority_scheduler) =
+            schedule_with_cached_priorities(&mut dag, &homogeneous_processor, &mut priority_cache);
+        if i == 0 && arg.dump_dot {
+            let dag_name = std::path::Path::new(&arg.dag_file_path)
+                .file_stem()
+                .unwrap()
+                .to_string_lossy();
+            dump_dag_to_dot(&dag, &format!("{}/{}.dot", arg.output_dir_path, dag_name));
+        }
+        let result = (schedule_length as f32) <= constrained_end_to_end_deadline;
+        // A DAG without any work finishes immediately both sequentially and in parallel.
+        let speedup = if schedule_length == 0 {
+            1.0
+        } else {
+            dag.sequential_makespan() as f32 / schedule_length as f32
+        };
+        if arg.stdout {
+            // One YAML document per number of cores.
+            if i > 0 {
+                stdout
+                    .write_all(b"---\n")
+                    .expect("Failed to write to stdout.");
+            }
+            dump_struct_to_writer(&mut stdout, &fixed_priority_scheduler.get_log());
+            dump_dag_scheduler_result_to_writer(
+                &mut stdout,
+                schedule_length,
+                arg.ratio_deadline_to_period,
+                speedup,
+                result,
+            );
+            continue;
+        }
+        // Log files created within the same millisecond would collide, so the number of cores is in the name of each.
+        let alg_name = if arg.number_of_cores.len() == 1 {
+            "cpc_model_based".to_string()
+        } else {
+            format!("cpc_model_based-{}-cores", number_of_cores)
+        };
+        let file_path = fixed_priority_scheduler.dump_log(&arg.output_dir_path, &alg_name);
 
-    dump_dag_scheduler_result_to_yaml(
-        &file_path,
-        schedule_length,
-        arg.ratio_deadline_to_period,
-        result,
+        dump_dag_scheduler_result_to_yaml(
+            &file_path,
+            schedule_length,
+            arg.ratio_deadline_to_period,
+            speedup,
+            result,
+        );
+    }
+    info!(
+        "Priority cache: {} hits, {} misses.",
+        priority_cache.get_hits(),
+        priority_cache.get_misses()
     );
 }
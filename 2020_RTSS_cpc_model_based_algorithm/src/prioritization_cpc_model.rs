@@ -1,6 +1,13 @@
 use crate::parallel_provider_consumer::{get_f_consumers, get_providers};
-use lib::graph_extension::{GraphExtension, NodeData};
+use lib::{
+    dag_scheduler::DAGSchedulerBase,
+    fixed_priority_scheduler::FixedPriorityScheduler,
+    graph_extension::{GraphExtension, NodeData},
+    processor::ProcessorBase,
+};
+use log::warn;
 use petgraph::graph::{Graph, NodeIndex};
+use std::collections::HashMap;
 
 //Create a dag for f_consumer only
 //NodeIndex is changed, but NodeData is retained.
@@ -21,10 +28,11 @@ fn create_shrunk_dag(
 fn convert_shrunk_indices_to_original(
     shrunk_dag: &Graph<NodeData, i32>,
     shrunk_indices: &[NodeIndex],
+    original_id_index: &HashMap<i32, NodeIndex>,
 ) -> Vec<NodeIndex> {
     shrunk_indices
         .iter()
-        .map(|&node_i| NodeIndex::new(shrunk_dag[node_i].id as usize))
+        .map(|&node_i| original_id_index[&shrunk_dag[node_i].id])
         .collect()
 }
 
@@ -43,21 +51,124 @@ fn prioritize_path_from_head_with_increment(
 
 #[allow(dead_code)] //TODO: remove
 pub fn assign_priority_to_cpc_model(dag: &mut Graph<NodeData, i32>) {
-    assign_priority_to_cpc_model_core(dag, &mut dag.clone(), &mut 0);
+    assign_priority_to_cpc_model_with_max_depth(dag, usize::MAX);
+}
+
+/// Same as `assign_priority_to_cpc_model`, but the recursion into f-consumers is capped at `max_recursion_depth`.
+/// When the cap is hit, the remaining f-consumer nodes are prioritized in longest-path order (Rule 3) without recursion.
+/// A warning is logged once if the cap was hit anywhere in the DAG.
+pub fn assign_priority_to_cpc_model_with_max_depth(
+    dag: &mut Graph<NodeData, i32>,
+    max_recursion_depth: usize,
+) {
+    let original_id_index = dag.build_id_index();
+    let depth_limit_reached = assign_priority_to_cpc_model_core(
+        dag,
+        &original_id_index,
+        &mut dag.clone(),
+        &mut 0,
+        0,
+        max_recursion_depth,
+    );
+    if depth_limit_reached {
+        warn!(
+            "The recursion depth reached the limit ({}). The longest path order is used for the remaining nodes.",
+            max_recursion_depth
+        );
+    }
+}
+
+/// Priorities computed by `assign_priority_to_cpc_model_with_max_depth`, keyed by `dag_fingerprint`.
+/// Reused while the structure and the execution times of the DAG are unchanged, e.g., during a parameter sweep.
+pub struct PriorityCache {
+    max_recursion_depth: usize,
+    /// `(node id, priority)` of each node.
+    priorities: HashMap<u64, Vec<(i32, i32)>>,
+    hits: usize,
+    misses: usize,
+}
+
+impl Default for PriorityCache {
+    fn default() -> Self {
+        Self::new(usize::MAX)
+    }
+}
+
+impl PriorityCache {
+    /// The priorities are computed with the recursion capped at `max_recursion_depth`.
+    pub fn new(max_recursion_depth: usize) -> Self {
+        Self {
+            max_recursion_depth,
+            priorities: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn get_hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn get_misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Set the CPC priorities to the DAG, computing them only on a cache miss.
+    pub fn assign_priority(&mut self, dag: &mut Graph<NodeData, i32>) {
+        let fingerprint = dag.dag_fingerprint();
+        if let Some(priorities) = self.priorities.get(&fingerprint) {
+            self.hits += 1;
+            let id_index = dag.build_id_index();
+            for &(node_id, priority) in priorities {
+                dag[id_index[&node_id]]
+                    .params
+                    .insert("priority".to_string(), priority);
+            }
+            return;
+        }
+        self.misses += 1;
+        assign_priority_to_cpc_model_with_max_depth(dag, self.max_recursion_depth);
+        self.priorities.insert(
+            fingerprint,
+            dag.node_weights()
+                .map(|node| (node.id, node.get_params_value("priority")))
+                .collect(),
+        );
+    }
+}
+
+/// Schedule the DAG by fixed-priority scheduling with the CPC priorities, reusing them from `cache` on a fingerprint hit.
+/// Returns the schedule length and the scheduler, whose log holds the schedule.
+pub fn schedule_with_cached_priorities<T>(
+    dag: &mut Graph<NodeData, i32>,
+    processor: &T,
+    cache: &mut PriorityCache,
+) -> (i32, FixedPriorityScheduler<T>)
+where
+    T: ProcessorBase + Clone,
+{
+    cache.assign_priority(dag);
+    let mut scheduler = FixedPriorityScheduler::new(dag, processor);
+    let (schedule_length, _) = scheduler.schedule();
+    (schedule_length, scheduler)
 }
 
 fn assign_priority_to_cpc_model_core(
     original_dag: &mut Graph<NodeData, i32>,
+    original_id_index: &HashMap<i32, NodeIndex>,
     shrunk_dag: &mut Graph<NodeData, i32>,
     current_priority: &mut i32,
-) {
+    recursion_depth: usize,
+    max_recursion_depth: usize,
+) -> bool {
+    let mut depth_limit_reached = false;
     let critical_path = shrunk_dag.get_critical_path();
     let providers = get_providers(shrunk_dag, &critical_path);
     let f_consumers = get_f_consumers(shrunk_dag, &critical_path);
     //Rule 1. Priority is given to critical nodes
     prioritize_path_from_head_with_increment(
         original_dag,
-        &convert_shrunk_indices_to_original(shrunk_dag, &critical_path),
+        &convert_shrunk_indices_to_original(shrunk_dag, &critical_path, original_id_index),
         current_priority,
     );
     //Rule 2. Priority is given to consumers for providers located before
@@ -67,15 +178,22 @@ fn assign_priority_to_cpc_model_core(
             while f_consumer_dag.node_count() != 0 {
                 let f_consumer_critical_path = f_consumer_dag.get_critical_path();
                 //recursion if there are dependencies in the f-consumer.
-                if f_consumer_critical_path.iter().any(|&node_i| {
+                let has_dependencies = f_consumer_critical_path.iter().any(|&node_i| {
                     f_consumer_dag
                         .get_pre_nodes(node_i)
-                        .map_or(false, |pre_nodes| pre_nodes.len() > 1)
-                }) {
-                    assign_priority_to_cpc_model_core(
+                        .is_some_and(|pre_nodes| pre_nodes.len() > 1)
+                });
+                if has_dependencies && recursion_depth >= max_recursion_depth {
+                    depth_limit_reached = true;
+                }
+                if has_dependencies && recursion_depth < max_recursion_depth {
+                    depth_limit_reached |= assign_priority_to_cpc_model_core(
                         original_dag,
+                        original_id_index,
                         &mut f_consumer_dag,
                         current_priority,
+                        recursion_depth + 1,
+                        max_recursion_depth,
                     );
                 } else {
                     //Rule 3. give high priority to the nodes in the longest path
@@ -84,6 +202,7 @@ fn assign_priority_to_cpc_model_core(
                         &convert_shrunk_indices_to_original(
                             &f_consumer_dag,
                             &f_consumer_critical_path,
+                            original_id_index,
                         ),
                         current_priority,
                     );
@@ -92,11 +211,13 @@ fn assign_priority_to_cpc_model_core(
             }
         }
     }
+    depth_limit_reached
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lib::homogeneous::HomogeneousProcessor;
     use std::collections::BTreeMap;
 
     fn create_node(id: i32, key: &str, value: i32) -> NodeData {
@@ -224,6 +345,61 @@ mod tests {
         dag
     }
 
+    /// Diamond whose short branch is itself a diamond, nested `depth` times.
+    /// Every nested diamond becomes an f-consumer with dependencies, so it causes one more recursion.
+    fn add_nested_diamond(
+        dag: &mut Graph<NodeData, i32>,
+        depth: usize,
+        scale: i32,
+    ) -> (NodeIndex, NodeIndex) {
+        let head = dag.add_node(create_node(dag.node_count() as i32, "execution_time", 1));
+        let middle = dag.add_node(create_node(
+            dag.node_count() as i32,
+            "execution_time",
+            scale,
+        ));
+        let tail = dag.add_node(create_node(dag.node_count() as i32, "execution_time", 1));
+        dag.add_edge(head, middle, 1);
+        dag.add_edge(middle, tail, 1);
+        if depth > 0 {
+            let (nested_head, nested_tail) = add_nested_diamond(dag, depth - 1, scale / 2);
+            dag.add_edge(head, nested_head, 1);
+            dag.add_edge(nested_tail, tail, 1);
+        } else {
+            let leaf = dag.add_node(create_node(dag.node_count() as i32, "execution_time", 1));
+            dag.add_edge(head, leaf, 1);
+            dag.add_edge(leaf, tail, 1);
+        }
+        (head, tail)
+    }
+
+    #[test]
+    fn test_assign_priority_cpc_model_max_recursion_depth() {
+        let depth = 8;
+        let mut dag = Graph::<NodeData, i32>::new();
+        add_nested_diamond(&mut dag, depth, 4 << depth);
+        let mut capped_dag = dag.clone();
+
+        assign_priority_to_cpc_model(&mut dag);
+        assign_priority_to_cpc_model_with_max_depth(&mut capped_dag, 2);
+
+        for target_dag in [&dag, &capped_dag] {
+            let mut priorities: Vec<i32> = target_dag
+                .node_indices()
+                .map(|node_i| target_dag[node_i].params["priority"])
+                .collect();
+            priorities.sort();
+            assert_eq!(priorities, (0..dag.node_count() as i32).collect::<Vec<_>>());
+        }
+        // The critical path is prioritized first regardless of the cap.
+        for node_i in dag.get_critical_path() {
+            assert_eq!(
+                dag[node_i].params["priority"],
+                capped_dag[node_i].params["priority"]
+            );
+        }
+    }
+
     #[test]
     fn test_assign_priority_cpc_model_normal() {
         let mut dag = create_sample_dag();
@@ -241,7 +417,8 @@ mod tests {
     #[test]
     fn test_assign_priority_cpc_model_normal_dag_not_consolidated() {
         let mut dag = create_sample_dag_not_consolidated();
-        let expected_value = [0, 1, 2, 8, 6, 3, 7, 4, 5];
+        // n5 -> n6 -> n8 and n5 -> n7 -> n8 tie, and the critical path with smaller node ids is taken.
+        let expected_value = [0, 1, 2, 8, 6, 3, 4, 7, 5];
 
         assign_priority_to_cpc_model(&mut dag);
         for node_i in dag.node_indices() {
@@ -255,7 +432,8 @@ mod tests {
     #[test]
     fn test_assign_priority_cpc_model_normal_recursion() {
         let mut dag = create_sample_dag_complex();
-        let expected_value = [0, 1, 2, 8, 6, 3, 7, 4, 5];
+        // n5 -> n6 -> n8 and n5 -> n7 -> n8 tie, and the critical path with smaller node ids is taken.
+        let expected_value = [0, 1, 2, 8, 6, 3, 4, 7, 5];
 
         assign_priority_to_cpc_model(&mut dag);
         for node_i in dag.node_indices() {
@@ -265,4 +443,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_schedule_with_cached_priorities_hit() {
+        let mut cache = PriorityCache::default();
+        let processor = HomogeneousProcessor::new(2);
+        let mut dag = create_sample_dag();
+        let (first_schedule_length, _) =
+            schedule_with_cached_priorities(&mut dag, &processor, &mut cache);
+        assert_eq!((cache.get_hits(), cache.get_misses()), (0, 1));
+
+        // A fresh copy of the same DAG has no priorities, which are taken from the cache.
+        let mut same_dag = create_sample_dag();
+        let (second_schedule_length, _) =
+            schedule_with_cached_priorities(&mut same_dag, &processor, &mut cache);
+        assert_eq!((cache.get_hits(), cache.get_misses()), (1, 1));
+        assert_eq!(second_schedule_length, first_schedule_length);
+        for node_i in dag.node_indices() {
+            assert_eq!(
+                same_dag[node_i].params["priority"],
+                dag[node_i].params["priority"]
+            );
+        }
+
+        let mut other_dag = create_sample_dag_not_consolidated();
+        schedule_with_cached_priorities(&mut other_dag, &processor, &mut cache);
+        assert_eq!((cache.get_hits(), cache.get_misses()), (1, 2));
+    }
 }
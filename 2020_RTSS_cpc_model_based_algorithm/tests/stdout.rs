@@ -0,0 +1,31 @@
+use std::{path::Path, process::Command};
+
+#[test]
+fn test_stdout_writes_yaml_without_output_file() {
+    let dag_file_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../lib/tests/sample_dags/chain_base_format.yaml");
+    let output_dir_path =
+        std::env::temp_dir().join(format!("rtss_cpc_stdout_test_{}", std::process::id()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rtss_cpc"))
+        .arg("-f")
+        .arg(&dag_file_path)
+        .args(["-c", "2", "--stdout", "-o"])
+        .arg(&output_dir_path)
+        .output()
+        .expect("Failed to run rtss_cpc.");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let yaml: serde_yaml::Value = serde_yaml::from_slice(&output.stdout).unwrap();
+    assert_eq!(yaml["schedule_length"].as_i64().unwrap(), 190);
+    assert_eq!(
+        yaml["processor_info"]["number_of_cores"].as_i64().unwrap(),
+        2
+    );
+    assert!(!yaml["node_logs"].as_sequence().unwrap().is_empty());
+    assert!(!output_dir_path.exists());
+}
@@ -9,16 +9,22 @@ use getset::{CopyGetters, Setters};
 use lib::{
     core::ProcessResult,
     dag_scheduler::DAGSchedulerBase,
-    dag_set_scheduler::{DAGSetSchedulerBase, DAGState, DAGStateManagerBase, PreemptiveType},
+    dag_set_scheduler::{
+        DAGSetSchedulerBase, DAGState, DAGStateManager, DAGStateManagerBase, NodeDataWrapper,
+        SchedulerConfig,
+    },
     getset_dag_set_scheduler, getset_dag_state_manager,
     graph_extension::{GraphExtension, NodeData},
     homogeneous::HomogeneousProcessor,
     log::DAGSetSchedulerLog,
     processor::ProcessorBase,
-    util::{get_hyper_period, get_process_core_indices},
+    util::get_process_core_indices,
 };
 use petgraph::{graph::NodeIndex, Graph};
-use std::collections::VecDeque;
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    time::Instant,
+};
 
 /// Calculate the execution order when minimum number of cores required to meet the end-to-end deadline.
 ///
@@ -137,6 +143,10 @@ where
     scheduler: T,
     log: DAGSetSchedulerLog,
     current_time: i32,
+    ready_queue: BTreeSet<NodeDataWrapper>,
+    managers: Vec<DAGStateManager>,
+    remaining_times: BTreeMap<(usize, i32), i32>,
+    config: SchedulerConfig,
 }
 
 impl<T> DAGSetSchedulerBase<HomogeneousProcessor> for DynamicFederatedScheduler<T>
@@ -152,10 +162,35 @@ where
             scheduler: T::new(&Graph::<NodeData, i32>::new(), processor),
             log: DAGSetSchedulerLog::new(dag_set, processor.get_number_of_cores()),
             current_time: 0,
+            ready_queue: BTreeSet::new(),
+            managers: Vec::new(),
+            remaining_times: BTreeMap::new(),
+            config: SchedulerConfig::default(),
         }
     }
 
-    fn schedule(&mut self, _: PreemptiveType) -> i32 {
+    /// DynFed is non-preemptive and runs its own scheduling loop, so it panics if `preemptive_type`,
+    /// `crpd`, `clusters` or `deadline_miss_policy` of the config is not the default.
+    fn schedule_with_config(&mut self, config: &SchedulerConfig) -> i32 {
+        let default_config = SchedulerConfig::default();
+        assert!(
+            config.preemptive_type == default_config.preemptive_type,
+            "DynFed does not support preemptive_type other than NonPreemptive."
+        );
+        assert!(
+            config.crpd == default_config.crpd,
+            "DynFed does not support crpd because it never preempts nodes."
+        );
+        assert!(
+            config.clusters == default_config.clusters,
+            "DynFed does not support clusters."
+        );
+        assert!(
+            config.deadline_miss_policy == default_config.deadline_miss_policy,
+            "DynFed does not support deadline_miss_policy other than Continue."
+        );
+        self.set_config(config.clone());
+        let start_instant = Instant::now();
         // Initialize DAGStateManagers
         let mut managers = vec![DynFedDAGStateManager::default(); self.dag_set.len()];
         for dag in self.dag_set.iter() {
@@ -167,8 +202,8 @@ where
         }
 
         // Start scheduling
-        let hyper_period = get_hyper_period(&self.dag_set);
-        while self.get_current_time() < hyper_period {
+        let end_time = self.get_simulation_end_time();
+        while self.get_current_time() < end_time {
             // Release DAGs
             self.release_dags(&mut managers);
             // Start DAGs if there are free cores
@@ -218,6 +253,7 @@ where
         }
 
         self.calculate_log();
+        self.log.set_wall_clock_time(start_instant.elapsed());
         self.get_current_time()
     }
 }
@@ -225,6 +261,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lib::dag_set_scheduler::PreemptiveType;
     use lib::fixed_priority_scheduler::FixedPriorityScheduler;
     use lib::homogeneous::HomogeneousProcessor;
     use lib::processor::ProcessorBase;
@@ -449,4 +486,15 @@ mod tests {
 
         remove_file(file_path).unwrap();
     }
+
+    #[test]
+    #[should_panic(expected = "crpd")]
+    fn test_dynfed_rejects_crpd() {
+        let mut dag = create_sample_dag();
+        dag.set_dag_param("dag_id", 0);
+
+        let mut dynfed: DynamicFederatedScheduler<FixedPriorityScheduler<HomogeneousProcessor>> =
+            DynamicFederatedScheduler::new(&[dag], &HomogeneousProcessor::new(5));
+        dynfed.schedule_with_config(&SchedulerConfig::default().crpd(1));
+    }
 }
@@ -4,13 +4,18 @@ use clap::Parser;
 use dynfed::DynamicFederatedScheduler;
 use lib::{
     dag_creator::create_dag_set_from_dir,
-    dag_set_scheduler::{DAGSetSchedulerBase, PreemptiveType},
+    dag_set_scheduler::{DAGSetSchedulerBase, SchedulerConfig},
     fixed_priority_scheduler::FixedPriorityScheduler,
-    graph_extension::GraphExtension,
     homogeneous::HomogeneousProcessor,
-    log::dump_dag_set_scheduler_result_to_yaml,
+    log::{
+        dump_dag_set_scheduler_result_with_misses,
+        dump_dag_set_scheduler_result_with_misses_to_writer, dump_struct_to_writer,
+    },
     processor::ProcessorBase,
-    util::{adjust_to_implicit_deadline, load_yaml},
+    util::{
+        adjust_to_constrained_deadline, adjust_to_implicit_deadline, dump_dag_set_to_dot,
+        validate_dag_set_ids, validate_dag_set_required_params,
+    },
 };
 
 #[derive(Parser)]
@@ -32,36 +37,67 @@ struct ArgParser {
     ///Path to output directory.
     #[clap(short = 'o', long = "output_dir_path", default_value = "../outputs")]
     output_dir_path: String,
+    ///Write the log and the result to stdout instead of a file in the output directory.
+    #[clap(long = "stdout")]
+    stdout: bool,
+    ///Stop the simulation at this time instead of the hyper-period. The results are partial if it is shorter.
+    #[clap(long = "hyper_period")]
+    hyper_period: Option<i32>,
+    ///Keep the period and the end-to-end deadline independent (constrained deadline) instead of equating them.
+    #[clap(long = "constrained_deadline")]
+    constrained_deadline: bool,
+    ///Write each DAG to a Graphviz DOT file in the output directory. Nodes on the critical path are colored red.
+    #[clap(long = "dump_dot")]
+    dump_dot: bool,
+    ///Write an SVG Gantt chart of the schedule next to the log file. Ignored with --stdout.
+    #[clap(long = "gantt")]
+    gantt: bool,
 }
 
 fn main() {
     let arg: ArgParser = ArgParser::parse();
 
     let mut dag_set = create_dag_set_from_dir(&arg.dag_dir_path);
-    adjust_to_implicit_deadline(&mut dag_set);
+    validate_dag_set_ids(&dag_set).unwrap_or_else(|err| panic!("Invalid DAG set: {}", err));
+    validate_dag_set_required_params(&dag_set, &["execution_time"])
+        .unwrap_or_else(|err| panic!("Invalid DAG set: {}", err));
+    if arg.constrained_deadline {
+        adjust_to_constrained_deadline(&mut dag_set);
+    } else {
+        adjust_to_implicit_deadline(&mut dag_set);
+    }
+    if arg.dump_dot {
+        dump_dag_set_to_dot(&dag_set, &arg.output_dir_path);
+    }
 
     let homogeneous_processor = HomogeneousProcessor::new(arg.number_of_cores);
     let mut dynfed_scheduler: DynamicFederatedScheduler<
         FixedPriorityScheduler<HomogeneousProcessor>,
     > = DynamicFederatedScheduler::new(&dag_set, &homogeneous_processor);
 
-    dynfed_scheduler.schedule(PreemptiveType::NonPreemptive);
+    dynfed_scheduler.schedule_with_config(
+        &SchedulerConfig::default()
+            .hyper_period_override(arg.hyper_period)
+            .constrained_deadline(arg.constrained_deadline),
+    );
+    // The response times are compared against the end-to-end deadline, which is the period under the implicit deadline.
+    let deadline_misses = dynfed_scheduler.get_log().get_all_deadline_misses(&dag_set);
+    if arg.stdout {
+        let mut stdout = std::io::stdout().lock();
+        dump_struct_to_writer(&mut stdout, dynfed_scheduler.get_log());
+        dump_dag_set_scheduler_result_with_misses_to_writer(
+            &mut stdout,
+            &deadline_misses,
+            dag_set.len(),
+        );
+        return;
+    }
     let file_path = dynfed_scheduler.dump_log(&arg.output_dir_path, "FixedPriority");
-
-    // Check the result
-    let yaml_doc = &load_yaml(&file_path)[0];
-    let dag_set_log = &yaml_doc["dag_set_log"];
-    let mut result = true;
-    for dag in dag_set {
-        if dag_set_log[dag.get_dag_param("dag_id") as usize]["worst_response_time"]
-            .as_i64()
-            .unwrap()
-            > dag.get_head_period().unwrap() as i64
-        {
-            result = false;
-            break;
-        }
+    if arg.gantt {
+        dynfed_scheduler
+            .get_log()
+            .dump_gantt_svg(&file_path.replace(".yaml", "-gantt.svg"));
     }
 
-    dump_dag_set_scheduler_result_to_yaml(&file_path, result);
+    dump_dag_set_scheduler_result_with_misses(&file_path, &deadline_misses, dag_set.len());
 }
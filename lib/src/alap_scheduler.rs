@@ -0,0 +1,185 @@
+use crate::getset_dag_scheduler;
+use crate::{
+    dag_scheduler::{DAGSchedulerBase, ExecutionTimeMode},
+    graph_extension::{GraphExtension, NodeData},
+    log::*,
+    processor::ProcessorBase,
+};
+use log::warn;
+use petgraph::graph::{Graph, NodeIndex};
+use std::collections::VecDeque;
+
+/// As-late-as-possible scheduler for slack analysis.
+/// Each node starts at its latest start time, i.e., the latest time at which the DAG still finishes
+/// within its critical path length, instead of as soon as it is ready.
+/// When no core is idle at that time, the node starts as soon as a core becomes idle.
+/// The schedule is deterministic, so `seed` is ignored.
+#[derive(Clone, Default)]
+pub struct AlapScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    dag: Graph<NodeData, i32>,
+    processor: T,
+    log: DAGSchedulerLog,
+    seed: Option<u64>,
+    dispatch_overhead: i32,
+    execution_time_mode: ExecutionTimeMode,
+}
+
+/// Latest start time of each node, indexed by NodeIndex.
+fn get_latest_start_times(dag: &Graph<NodeData, i32>) -> Vec<i32> {
+    let mut dag = dag.clone();
+    // Calculate against a single sink, since the latest start times are relative to the first sink node.
+    dag.add_dummy_sink_node();
+    dag.calculate_latest_start_times();
+    dag.remove_dummy_sink_node();
+    dag.node_weights()
+        .map(|node| node.get_params_value("latest_start_time"))
+        .collect()
+}
+
+impl<T> DAGSchedulerBase<T> for AlapScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    fn new(dag: &Graph<NodeData, i32>, processor: &T) -> Self {
+        Self {
+            dag: dag.clone(),
+            processor: processor.clone(),
+            log: DAGSchedulerLog::new(dag, processor.get_number_of_cores()),
+            seed: None,
+            dispatch_overhead: 0,
+            execution_time_mode: ExecutionTimeMode::default(),
+        }
+    }
+
+    getset_dag_scheduler!(T);
+
+    /// Earlier latest start time first. Nodes without `latest_start_time` come last.
+    fn sort_ready_queue(&self, ready_queue: &mut VecDeque<NodeData>) {
+        ready_queue
+            .make_contiguous()
+            .sort_by_key(|node| *node.params.get("latest_start_time").unwrap_or(&i32::MAX));
+    }
+
+    /// Place the nodes one by one in the order of their latest start times instead of simulating tick by tick,
+    /// since the eager loop of the default implementation never leaves a ready node waiting on an idle core.
+    fn schedule(&mut self) -> (i32, VecDeque<NodeIndex>) {
+        let mut dag = self.get_dag();
+        let mut log = self.get_log();
+        if dag.node_count() == 0 {
+            warn!("The DAG has no nodes. Nothing is scheduled.");
+            log.calculate_utilization(0);
+            self.set_log(log);
+            return (0, VecDeque::new());
+        }
+        self.prepare_schedule(&dag);
+        self.get_execution_time_mode().apply(&mut dag);
+
+        let latest_start_times = get_latest_start_times(&dag);
+        // Sort topologically among equal latest start times, so that predecessors are always placed first.
+        let mut sorted_nodes = dag.get_topological_order();
+        sorted_nodes.sort_by_key(|node_i| latest_start_times[node_i.index()]);
+
+        let mut core_idle_times = vec![0; self.processor.get_number_of_cores()];
+        let mut finish_times = vec![0; dag.node_count()];
+        let mut start_times = Vec::with_capacity(dag.node_count());
+        for node_i in sorted_nodes {
+            let ready_time = dag
+                .get_pre_nodes(node_i)
+                .unwrap_or_default()
+                .iter()
+                .map(|pre_node_i| finish_times[pre_node_i.index()])
+                .max()
+                .unwrap_or(0);
+            let desired_start_time = latest_start_times[node_i.index()].max(ready_time);
+            let core_id = core_idle_times
+                .iter()
+                .position(|&idle_time| idle_time <= desired_start_time)
+                .unwrap_or_else(|| {
+                    (0..core_idle_times.len())
+                        .min_by_key(|&core_id| core_idle_times[core_id])
+                        .unwrap()
+                });
+            let start_time = desired_start_time.max(core_idle_times[core_id]);
+            let processing_time =
+                dag[node_i].get_params_value("execution_time") + self.get_dispatch_overhead();
+            let finish_time = start_time + processing_time;
+
+            log.write_allocating_job(&dag[node_i], core_id, start_time);
+            for _ in 0..processing_time {
+                log.write_processing_time(&[core_id]);
+            }
+            log.write_finishing_job(&dag[node_i], core_id, finish_time);
+            core_idle_times[core_id] = finish_time;
+            finish_times[node_i.index()] = finish_time;
+            start_times.push((start_time, node_i));
+        }
+
+        start_times.sort();
+        let execution_order = start_times
+            .into_iter()
+            .map(|(_, node_i)| NodeIndex::new(dag[node_i].id as usize))
+            .collect();
+        let schedule_length = finish_times.into_iter().max().unwrap();
+        log.calculate_utilization(schedule_length);
+        self.set_log(log);
+
+        (schedule_length, execution_order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::homogeneous::HomogeneousProcessor;
+
+    fn create_dag_with_slack() -> Graph<NodeData, i32> {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(0, &[("execution_time", 2)]));
+        let n1 = dag.add_node(NodeData::with_params(1, &[("execution_time", 5)]));
+        let n2 = dag.add_node(NodeData::with_params(2, &[("execution_time", 1)]));
+        let n3 = dag.add_node(NodeData::with_params(3, &[("execution_time", 1)]));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n3, 0);
+        dag
+    }
+
+    #[test]
+    fn test_alap_scheduler_starts_at_latest_start_times() {
+        let dag = create_dag_with_slack();
+        let mut scheduler = AlapScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        let (schedule_length, execution_order) = scheduler.schedule();
+
+        // The critical path is n0 -> n1 -> n3, so n2 has a slack of 4.
+        assert_eq!(schedule_length, 8);
+        let mut start_times = [0; 4];
+        for job_interval in scheduler.get_log().get_job_intervals() {
+            start_times[job_interval.node_id] = job_interval.start_time;
+        }
+        assert_eq!(start_times, [0, 2, 6, 7]);
+        assert_eq!(start_times.to_vec(), get_latest_start_times(&dag));
+        assert_eq!(
+            execution_order,
+            VecDeque::from(vec![
+                NodeIndex::new(0),
+                NodeIndex::new(1),
+                NodeIndex::new(2),
+                NodeIndex::new(3)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_alap_scheduler_delayed_by_busy_core() {
+        let dag = create_dag_with_slack();
+        let mut scheduler = AlapScheduler::new(&dag, &HomogeneousProcessor::new(1));
+        let (schedule_length, _) = scheduler.schedule();
+
+        // n2 cannot start at 6 because n1 occupies the only core until 7.
+        assert_eq!(schedule_length, 9);
+    }
+}
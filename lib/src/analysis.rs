@@ -0,0 +1,274 @@
+use crate::{
+    dag_scheduler::DAGSchedulerBase,
+    dag_set_scheduler::{DAGSetSchedulerBase, PreemptiveType},
+    fixed_priority_scheduler::FixedPriorityScheduler,
+    global_edf_scheduler::GlobalEDFScheduler,
+    graph_extension::{GraphExtension, NodeData},
+    homogeneous::HomogeneousProcessor,
+    processor::ProcessorBase,
+    rng::SeededRng,
+    util::inflate_wcet,
+};
+use petgraph::graph::Graph;
+use std::collections::BTreeMap;
+
+/// Makespan of the DAG under fixed-priority list scheduling on `num_cores` homogeneous cores.
+/// Nodes without `priority` are treated as the same priority, so ties are broken by NodeIndex.
+/// Setting the end-to-end deadline to this value makes the DAG feasible by construction.
+pub fn earliest_feasible_deadline(dag: &Graph<NodeData, i32>, num_cores: usize) -> i32 {
+    let mut dag = dag.clone();
+    for node_i in dag.node_indices() {
+        if !dag[node_i].params.contains_key("priority") {
+            dag.add_param(node_i, "priority", 0);
+        }
+    }
+    let mut scheduler = FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(num_cores));
+    let (schedule_length, _) = scheduler.schedule();
+
+    schedule_length
+}
+
+/// Makespans of `samples` runs of fixed-priority scheduling of the DAG on `num_cores` cores.
+/// In each run, the execution time of a node with `bcet` and `wcet` is drawn uniformly from [`bcet`, `wcet`],
+/// and a node without them keeps its `execution_time`. The same `seed` gives the same distribution.
+pub fn monte_carlo_makespan(
+    dag: &Graph<NodeData, i32>,
+    num_cores: usize,
+    samples: usize,
+    seed: u64,
+) -> Vec<i32> {
+    let mut rng = SeededRng::new(seed);
+    let processor = HomogeneousProcessor::new(num_cores);
+    (0..samples)
+        .map(|_| {
+            let mut sampled_dag = dag.clone();
+            for node in sampled_dag.node_weights_mut() {
+                if let (Some(&bcet), Some(&wcet)) =
+                    (node.params.get("bcet"), node.params.get("wcet"))
+                {
+                    node.params
+                        .insert("execution_time".to_string(), rng.gen_range(bcet, wcet + 1));
+                }
+            }
+            let (makespan, _) = FixedPriorityScheduler::new(&sampled_dag, &processor).schedule();
+            makespan
+        })
+        .collect()
+}
+
+/// Width of the total utilization buckets of `schedulability_sweep`.
+pub const UTILIZATION_BUCKET_WIDTH: f32 = 0.1;
+
+/// Group the DAG sets into buckets of total utilization (volume / period) and return
+/// `(utilization_bucket, schedulable_fraction)` for each non-empty bucket, in ascending order.
+/// `utilization_bucket` is the lower bound of the bucket. `scheduler` receives each set and `num_cores`
+/// and returns whether the set is schedulable, e.g., by running a scheduler and checking deadline misses.
+pub fn schedulability_sweep(
+    sets: &[Vec<Graph<NodeData, i32>>],
+    num_cores: usize,
+    scheduler: impl Fn(&[Graph<NodeData, i32>], usize) -> bool,
+) -> Vec<(f32, f32)> {
+    let mut buckets: BTreeMap<i32, (i32, i32)> = BTreeMap::new();
+    for dag_set in sets {
+        let total_utilization: f32 = dag_set
+            .iter()
+            .map(|dag| dag.get_volume() as f32 / dag.get_head_period().unwrap() as f32)
+            .sum();
+        let bucket_index = (total_utilization / UTILIZATION_BUCKET_WIDTH).floor() as i32;
+        let (num_schedulable, num_sets) = buckets.entry(bucket_index).or_insert((0, 0));
+        if scheduler(dag_set, num_cores) {
+            *num_schedulable += 1;
+        }
+        *num_sets += 1;
+    }
+    buckets
+        .into_iter()
+        .map(|(bucket_index, (num_schedulable, num_sets))| {
+            (
+                bucket_index as f32 * UTILIZATION_BUCKET_WIDTH,
+                num_schedulable as f32 / num_sets as f32,
+            )
+        })
+        .collect()
+}
+
+/// Schedule the DAG set by non-preemptive global EDF on `num_cores` cores with the WCETs inflated by each factor,
+/// and return `(wcet_factor, worst_response_time)` in the order of `wcet_factors`.
+/// `worst_response_time` is the maximum over all DAGs. The DAGs must have `dag_id`.
+pub fn response_time_envelope(
+    dag_set: &[Graph<NodeData, i32>],
+    num_cores: usize,
+    wcet_factors: &[f32],
+) -> Vec<(f32, i32)> {
+    wcet_factors
+        .iter()
+        .map(|&wcet_factor| {
+            let mut inflated_dag_set = dag_set.to_vec();
+            inflate_wcet(&mut inflated_dag_set, wcet_factor);
+            let mut scheduler =
+                GlobalEDFScheduler::new(&inflated_dag_set, &HomogeneousProcessor::new(num_cores));
+            scheduler.schedule(PreemptiveType::NonPreemptive);
+            let log = scheduler.get_log();
+            let worst_response_time = (0..inflated_dag_set.len())
+                .flat_map(|dag_id| log.get_response_times(dag_id).iter().copied())
+                .max()
+                .unwrap_or(0);
+            (wcet_factor, worst_response_time)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dag_scheduler::ExecutionTimeMode;
+    use crate::util::tests::create_dag_with_period_and_deadline;
+
+    fn create_node(id: i32, key: &str, value: i32) -> NodeData {
+        NodeData::with_params(id, &[(key, value)])
+    }
+
+    #[test]
+    fn test_earliest_feasible_deadline_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 52));
+        let n1 = dag.add_node(create_node(1, "execution_time", 40));
+        let n2 = dag.add_node(create_node(2, "execution_time", 12));
+        let n3 = dag.add_node(create_node(3, "execution_time", 10));
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n0, n2, 1);
+        dag.add_edge(n0, n3, 1);
+        dag.add_edge(n2, n3, 1);
+
+        let mut prioritized_dag = dag.clone();
+        for node_i in prioritized_dag.node_indices() {
+            prioritized_dag.add_param(node_i, "priority", 0);
+        }
+        let (makespan, _) =
+            FixedPriorityScheduler::new(&prioritized_dag, &HomogeneousProcessor::new(2)).schedule();
+
+        assert_eq!(earliest_feasible_deadline(&dag, 2), makespan);
+        assert_eq!(earliest_feasible_deadline(&dag, 2), 92);
+        assert_eq!(earliest_feasible_deadline(&dag, 1), 114);
+        assert!(!dag[n0].params.contains_key("priority"));
+    }
+
+    #[test]
+    fn test_monte_carlo_makespan_within_bcet_and_wcet() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[
+                ("execution_time", 4),
+                ("bcet", 1),
+                ("wcet", 4),
+                ("priority", 0),
+            ],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(
+            1,
+            &[
+                ("execution_time", 9),
+                ("bcet", 3),
+                ("wcet", 9),
+                ("priority", 0),
+            ],
+        ));
+        let n2 = dag.add_node(NodeData::with_params(
+            2,
+            &[
+                ("execution_time", 6),
+                ("bcet", 5),
+                ("wcet", 6),
+                ("priority", 1),
+            ],
+        ));
+        let n3 = dag.add_node(NodeData::with_params(
+            3,
+            &[("execution_time", 2), ("priority", 0)],
+        ));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n3, 0);
+        let processor = HomogeneousProcessor::new(2);
+        let mut scheduler = FixedPriorityScheduler::new(&dag, &processor);
+        scheduler.set_execution_time_mode(ExecutionTimeMode::Bcet);
+        let (bcet_makespan, _) = scheduler.schedule();
+        scheduler.set_execution_time_mode(ExecutionTimeMode::Wcet);
+        let (wcet_makespan, _) = scheduler.schedule();
+
+        let makespans = monte_carlo_makespan(&dag, 2, 100, 7);
+        assert_eq!(makespans.len(), 100);
+        assert!(makespans
+            .iter()
+            .all(|&makespan| bcet_makespan <= makespan && makespan <= wcet_makespan));
+        assert!(makespans.iter().any(|&makespan| makespan != makespans[0]));
+        assert_eq!(monte_carlo_makespan(&dag, 2, 100, 7), makespans);
+    }
+
+    #[test]
+    fn test_schedulability_sweep_global_edf() {
+        let create_dag_set = |periods_and_deadlines: &[(i32, i32)]| {
+            periods_and_deadlines
+                .iter()
+                .enumerate()
+                .map(|(dag_id, &(period, deadline))| {
+                    let mut dag = create_dag_with_period_and_deadline(period, deadline);
+                    dag.set_dag_param("dag_id", dag_id as i32);
+                    dag
+                })
+                .collect::<Vec<_>>()
+        };
+        // The volume of each DAG is 8.
+        let sets = vec![
+            create_dag_set(&[(32, 32)]),
+            create_dag_set(&[(40, 40), (160, 160)]),
+            create_dag_set(&[(10, 10), (11, 11)]),
+            // The deadline is shorter than the critical path length.
+            create_dag_set(&[(10, 5), (11, 11)]),
+        ];
+        let global_edf_schedulable = |dag_set: &[Graph<NodeData, i32>], num_cores: usize| {
+            let mut scheduler =
+                GlobalEDFScheduler::new(dag_set, &HomogeneousProcessor::new(num_cores));
+            scheduler.schedule(PreemptiveType::NonPreemptive);
+            scheduler
+                .get_log()
+                .get_all_deadline_misses(dag_set)
+                .is_empty()
+        };
+
+        let sweep = schedulability_sweep(&sets, 2, global_edf_schedulable);
+        assert_eq!(sweep.len(), 2);
+        assert!((sweep[0].0 - 0.2).abs() < 1e-4);
+        assert_eq!(sweep[0].1, 1.0);
+        assert!((sweep[1].0 - 1.5).abs() < 1e-4);
+        assert_eq!(sweep[1].1, 0.5);
+    }
+
+    #[test]
+    fn test_response_time_envelope_monotonic() {
+        let dag_set: Vec<_> = [(20, 20), (30, 30), (60, 60)]
+            .iter()
+            .enumerate()
+            .map(|(dag_id, &(period, deadline))| {
+                let mut dag = create_dag_with_period_and_deadline(period, deadline);
+                dag.set_dag_param("dag_id", dag_id as i32);
+                dag
+            })
+            .collect();
+        let wcet_factors = [1.0, 1.25, 1.5, 2.0];
+
+        let envelope = response_time_envelope(&dag_set, 3, &wcet_factors);
+        assert_eq!(
+            envelope
+                .iter()
+                .map(|&(factor, _)| factor)
+                .collect::<Vec<_>>(),
+            wcet_factors
+        );
+        // Each chain DAG has a critical path length of 8 and runs on its own core.
+        assert_eq!(envelope[0].1, 8);
+        assert!(envelope.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    }
+}
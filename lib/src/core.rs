@@ -2,6 +2,51 @@
 use crate::{core::ProcessResult::*, graph_extension::NodeData};
 use getset::{CopyGetters, Getters};
 use log::warn;
+/// Frequency at which `execution_time` of a node is measured. Core frequencies are given on the same scale.
+pub const NOMINAL_FREQUENCY: i32 = 100;
+/// Prefix of the node parameters forming the WCET table for DVFS.
+/// `wcet_at_50` is the execution time of the node on a core running at frequency 50.
+pub const WCET_TABLE_PREFIX: &str = "wcet_at_";
+
+/// Execution time of the node on a core running at `frequency`.
+/// The WCET table is used if it has an entry for `frequency`. Otherwise, `execution_time` is scaled inversely
+/// with the frequency (rounded up). The dummy nodes added by the schedulers are not scaled.
+/// Returns None if the node does not have `execution_time`.
+pub fn get_execution_time_at_frequency(node_data: &NodeData, frequency: i32) -> Option<i32> {
+    if node_data.params.contains_key("dummy") {
+        return node_data.params.get("execution_time").copied();
+    }
+    if let Some(&wcet) = node_data
+        .params
+        .get(&format!("{}{}", WCET_TABLE_PREFIX, frequency))
+    {
+        return Some(wcet);
+    }
+    let execution_time = *node_data.params.get("execution_time")?;
+    if frequency == NOMINAL_FREQUENCY {
+        return Some(execution_time);
+    }
+    Some(
+        ((execution_time as i64 * NOMINAL_FREQUENCY as i64 + frequency as i64 - 1)
+            / frequency as i64) as i32,
+    )
+}
+
+/// Unit of `execution_time` of the nodes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TimeBase {
+    /// Time ticks on a core running at `NOMINAL_FREQUENCY`.
+    #[default]
+    Time,
+    /// Clock cycles. A core runs `frequency` cycles per time tick.
+    Cycles,
+}
+
+/// Time ticks needed to run `cycles` on a core running `frequency` cycles per tick (rounded up).
+pub fn cycles_to_time(cycles: i32, frequency: i32) -> i32 {
+    ((cycles as i64 + frequency as i64 - 1) / frequency as i64) as i32
+}
+
 ///enum to represent three types of states
 ///execution not possible because not allocate, execution in progress, execution finished
 #[derive(Debug, PartialEq, Clone)]
@@ -18,6 +63,9 @@ pub struct Core {
     #[get = "pub with_prefix"]
     pub processing_node: Option<NodeData>,
     pub remain_proc_time: i32,
+    /// Current frequency of the core, on the scale of `NOMINAL_FREQUENCY`.
+    pub frequency: i32,
+    pub time_base: TimeBase,
 }
 
 impl Default for Core {
@@ -26,6 +74,8 @@ impl Default for Core {
             is_idle: true,
             processing_node: None,
             remain_proc_time: 0,
+            frequency: NOMINAL_FREQUENCY,
+            time_base: TimeBase::default(),
         }
     }
 }
@@ -39,8 +89,23 @@ impl Core {
         }
         self.is_idle = false;
         self.processing_node = Some(node_data.clone());
-        if let Some(exec_time) = node_data.params.get("execution_time") {
-            self.remain_proc_time = *exec_time;
+        // A preempted node resumes with its remaining time as is, since the time has already been scaled.
+        // A dummy node is not a workload of the DAG, so it takes its time regardless of the frequency.
+        let exec_time = if node_data.params.contains_key("is_preempted")
+            || node_data.params.contains_key("dummy")
+        {
+            node_data.params.get("execution_time").copied()
+        } else {
+            match self.time_base {
+                TimeBase::Time => get_execution_time_at_frequency(node_data, self.frequency),
+                TimeBase::Cycles => node_data
+                    .params
+                    .get("execution_time")
+                    .map(|&cycles| cycles_to_time(cycles, self.frequency)),
+            }
+        };
+        if let Some(exec_time) = exec_time {
+            self.remain_proc_time = exec_time;
             true
         } else {
             warn!("Node {} does not have execution_time", node_data.id);
@@ -96,6 +161,7 @@ mod tests {
         assert!(core.is_idle);
         assert_eq!(core.processing_node, None);
         assert_eq!(core.remain_proc_time, 0);
+        assert_eq!(core.time_base, TimeBase::Time);
     }
 
     #[test]
@@ -123,6 +189,27 @@ mod tests {
         assert!(!core.allocate(&create_node(0, "no_execution_time", 10)));
     }
 
+    #[test]
+    fn test_core_allocate_dummy_node_not_scaled() {
+        let mut core = Core {
+            frequency: NOMINAL_FREQUENCY / 2,
+            ..Default::default()
+        };
+        let mut dummy_node = create_node(0, "execution_time", 1);
+        dummy_node.params.insert("dummy".to_string(), 0);
+        assert_eq!(
+            get_execution_time_at_frequency(&dummy_node, NOMINAL_FREQUENCY / 2),
+            Some(1)
+        );
+        core.allocate(&dummy_node);
+        assert_eq!(core.remain_proc_time, 1);
+
+        core.time_base = TimeBase::Cycles;
+        core.process();
+        core.allocate(&dummy_node);
+        assert_eq!(core.remain_proc_time, 1);
+    }
+
     #[test]
     fn test_core_process_normal() {
         let mut core = Core::default();
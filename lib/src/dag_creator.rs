@@ -1,11 +1,138 @@
-//! Generate a petgraph DAG object from a yaml file
-use crate::graph_extension::{GraphExtension, NodeData};
-use crate::util::load_yaml;
+//! Generate a petgraph DAG object from a yaml or json file
+use crate::graph_extension::{EdgeData, GraphExtension, NodeData, NODE_KIND_COMPUTE, NODE_KIND_IO};
+use crate::util::has_complete_priorities;
 
 use log::warn;
-use petgraph::{graph::Graph, prelude::*};
-use std::{collections::BTreeMap, path::PathBuf};
-use yaml_rust::Yaml;
+use petgraph::{algo::toposort, graph::Graph, prelude::*};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+    path::PathBuf,
+};
+use yaml_rust::{Yaml, YamlLoader};
+
+/// Supported values of the optional top-level `time_unit` field and their length in nanoseconds.
+const TIME_UNITS: [(&str, i64); 4] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+];
+
+/// Node parameters that represent a time and are normalized according to `time_unit`.
+const TIME_PARAMS: [&str; 4] = ["execution_time", "period", "end_to_end_deadline", "offset"];
+
+const YAML_EXTENSIONS: [&str; 2] = ["yaml", "yml"];
+const JSON_EXTENSIONS: [&str; 1] = ["json"];
+const DAG_FILE_EXTENSIONS: [&str; 3] = ["yaml", "yml", "json"];
+
+/// Reason why a DAG could not be created from a file.
+#[derive(Debug)]
+pub enum DagParseError {
+    /// The file could not be read.
+    Io {
+        file_path: String,
+        error: std::io::Error,
+    },
+    /// The file does not have the expected extension or could not be parsed.
+    NotYaml { file_path: String },
+    /// The document does not have the `nodes` or `links` array.
+    MissingNodesOrLinks { file_path: String },
+    /// The value of the field cannot be converted to a parameter.
+    UnknownValueType { file_path: String, key: String },
+    /// Two nodes have the same id.
+    DuplicateNodeId { file_path: String, id: i32 },
+    /// A link references a position outside the `nodes` array.
+    LinkOutOfRange {
+        file_path: String,
+        source: usize,
+        target: usize,
+        node_count: usize,
+    },
+    /// A link connects a node to itself.
+    SelfLoop { file_path: String, node: usize },
+    /// The links form a cycle. `id` is the id of a node in the cycle.
+    Cycle { file_path: String, id: i32 },
+}
+
+impl fmt::Display for DagParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DagParseError::Io { file_path, error } => {
+                write!(f, "Failed to read the file: {}. file: {}", error, file_path)
+            }
+            DagParseError::NotYaml { file_path } => {
+                write!(f, "Not a YAML (or JSON) file: {}", file_path)
+            }
+            DagParseError::MissingNodesOrLinks { file_path } => {
+                write!(f, "YAML files are not DAG structures. file: {}", file_path)
+            }
+            DagParseError::UnknownValueType { file_path, key } => {
+                write!(
+                    f,
+                    "Unknown type of the value of {}. file: {}",
+                    key, file_path
+                )
+            }
+            DagParseError::DuplicateNodeId { file_path, id } => {
+                write!(f, "Duplicate node id: {}. file: {}", id, file_path)
+            }
+            DagParseError::LinkOutOfRange {
+                file_path,
+                source,
+                target,
+                node_count,
+            } => write!(
+                f,
+                "Link endpoint out of range: source {}, target {} (number of nodes: {}). file: {}",
+                source, target, node_count, file_path
+            ),
+            DagParseError::SelfLoop { file_path, node } => {
+                write!(f, "Self-loop found: node {}. file: {}", node, file_path)
+            }
+            DagParseError::Cycle { file_path, id } => {
+                write!(f, "Cycle found: node {}. file: {}", id, file_path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DagParseError {}
+
+fn get_time_unit_in_ns(yaml_doc: &Yaml, file_path: &str) -> Result<Option<i64>, DagParseError> {
+    yaml_doc["time_unit"]
+        .as_str()
+        .map(|time_unit| {
+            TIME_UNITS
+                .iter()
+                .find(|(unit, _)| *unit == time_unit)
+                .map(|(_, unit_in_ns)| *unit_in_ns)
+                .ok_or_else(|| DagParseError::UnknownValueType {
+                    file_path: file_path.to_string(),
+                    key: "time_unit".to_string(),
+                })
+        })
+        .transpose()
+}
+
+/// Multiply the time parameters of the nodes and the communication times by `scale`.
+fn scale_time_params(dag: &mut Graph<NodeData, i32>, scale: i32) {
+    let scale_value = |value: i32| {
+        value
+            .checked_mul(scale)
+            .unwrap_or_else(|| panic!("Overflow while normalizing the time unit: {}", value))
+    };
+    for node in dag.node_weights_mut() {
+        for key in TIME_PARAMS {
+            if let Some(value) = node.params.get_mut(key) {
+                *value = scale_value(*value);
+            }
+        }
+    }
+    for communication_time in dag.edge_weights_mut() {
+        *communication_time = scale_value(*communication_time);
+    }
+}
 
 fn get_minimum_decimal_places(yaml: &Yaml) -> usize {
     let mut minimum_decimal_places = 0;
@@ -45,6 +172,12 @@ fn get_minimum_decimal_places(yaml: &Yaml) -> usize {
 
 /// load yaml file and return a dag object (petgraph)
 ///
+/// The `source` and `target` of each link are positions in the `nodes` array (NodeIndex), not node ids.
+/// They coincide when the nodes are listed in ascending id order starting from 0.
+/// A link referencing a position outside the `nodes` array, a self-loop or a cycle causes a panic.
+/// If there are duplicate links between the same endpoints, only the first one is used.
+/// Use `try_create_dag_from_yaml` to handle these errors instead of panicking.
+///
 /// # Arguments
 ///
 /// *  `file_path` - yaml file path
@@ -68,8 +201,110 @@ fn get_minimum_decimal_places(yaml: &Yaml) -> usize {
 /// let edge_weight = dag[first_edge];
 /// ```
 pub fn create_dag_from_yaml(file_path: &str, exist_other_float_dag: bool) -> Graph<NodeData, i32> {
-    let yaml_docs = load_yaml(file_path);
-    let yaml_doc = &yaml_docs[0];
+    try_create_dag_from_yaml(file_path, exist_other_float_dag)
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// load yaml file and return a dag object (petgraph), or the reason why it is not a valid DAG
+///
+/// # Example
+///
+/// ```
+/// use lib::dag_creator::{try_create_dag_from_yaml, DagParseError};
+///
+/// let dag = try_create_dag_from_yaml("tests/sample_dags/chain_base_format.yaml", false).unwrap();
+/// assert!(matches!(
+///     try_create_dag_from_yaml("tests/sample_dags/no_yaml.tex", false),
+///     Err(DagParseError::NotYaml { .. })
+/// ));
+/// ```
+pub fn try_create_dag_from_yaml(
+    file_path: &str,
+    exist_other_float_dag: bool,
+) -> Result<Graph<NodeData, i32>, DagParseError> {
+    let yaml_doc = try_load_dag_document(file_path, &YAML_EXTENSIONS)?;
+    build_dag_from_value(&yaml_doc, file_path, exist_other_float_dag).map(|(dag, _)| dag)
+}
+
+/// load json file and return a dag object (petgraph)
+///
+/// The json file has the same `nodes`/`links` schema as the yaml file of `create_dag_from_yaml`,
+/// and the values are scaled to integers in the same way.
+///
+/// # Example
+///
+/// ```
+/// use lib::dag_creator::{create_dag_from_json, create_dag_from_yaml};
+///
+/// let dag = create_dag_from_json("tests/sample_dags/float_params.json", false);
+/// let yaml_dag = create_dag_from_yaml("tests/sample_dags/float_params.yaml", false);
+/// assert_eq!(dag.node_count(), yaml_dag.node_count());
+/// ```
+pub fn create_dag_from_json(file_path: &str, exist_other_float_dag: bool) -> Graph<NodeData, i32> {
+    try_load_dag_document(file_path, &JSON_EXTENSIONS)
+        .and_then(|dag_doc| build_dag_from_value(&dag_doc, file_path, exist_other_float_dag))
+        .unwrap_or_else(|err| panic!("{}", err))
+        .0
+}
+
+/// Load the first document of a DAG file whose extension is one of `extensions`.
+/// JSON is a subset of YAML 1.2, so a json file is parsed into the same document as the equivalent yaml file.
+fn try_load_dag_document(file_path: &str, extensions: &[&str]) -> Result<Yaml, DagParseError> {
+    let has_extension = PathBuf::from(file_path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extensions.contains(&extension));
+    if !has_extension {
+        return Err(DagParseError::NotYaml {
+            file_path: file_path.to_string(),
+        });
+    }
+    let content = std::fs::read_to_string(file_path).map_err(|error| DagParseError::Io {
+        file_path: file_path.to_string(),
+        error,
+    })?;
+    let mut docs = YamlLoader::load_from_str(&content).map_err(|_| DagParseError::NotYaml {
+        file_path: file_path.to_string(),
+    })?;
+    if docs.is_empty() {
+        return Err(DagParseError::MissingNodesOrLinks {
+            file_path: file_path.to_string(),
+        });
+    }
+    Ok(docs.swap_remove(0))
+}
+
+/// load yaml file and return a dag object (petgraph) together with the parameters of each link
+///
+/// The `i`-th element of the returned vector corresponds to `EdgeIndex::new(i)` of the dag.
+/// Every link field other than `source`, `target` and `communication_time` is stored in `EdgeData::params`,
+/// scaled in the same way as the node parameters.
+///
+/// # Example
+///
+/// ```
+/// use lib::dag_creator::create_dag_and_edge_data_from_yaml;
+///
+/// let (dag, edge_data) =
+///     create_dag_and_edge_data_from_yaml("tests/sample_dags/edge_params.yaml", false);
+/// let message_size = edge_data[0].params["message_size"];
+/// ```
+pub fn create_dag_and_edge_data_from_yaml(
+    file_path: &str,
+    exist_other_float_dag: bool,
+) -> (Graph<NodeData, i32>, Vec<EdgeData>) {
+    try_load_dag_document(file_path, &YAML_EXTENSIONS)
+        .and_then(|yaml_doc| build_dag_from_value(&yaml_doc, file_path, exist_other_float_dag))
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Build a dag object and the parameters of each link from a parsed yaml or json document.
+/// `file_path` is only used in the errors and messages.
+fn build_dag_from_value(
+    yaml_doc: &Yaml,
+    file_path: &str,
+    exist_other_float_dag: bool,
+) -> Result<(Graph<NodeData, i32>, Vec<EdgeData>), DagParseError> {
     let mut int_conversion_factor =
         10f32.powi(get_minimum_decimal_places(yaml_doc).try_into().unwrap()) as i32;
     if exist_other_float_dag || int_conversion_factor > 1 {
@@ -78,74 +313,167 @@ pub fn create_dag_from_yaml(file_path: &str, exist_other_float_dag: bool) -> Gra
         }
         int_conversion_factor = 100000;
     }
+    let unknown_value_type = |key: &str| DagParseError::UnknownValueType {
+        file_path: file_path.to_string(),
+        key: key.to_string(),
+    };
 
     // Check if nodes and links fields exist
-    if let (Some(nodes), Some(links)) = (yaml_doc["nodes"].as_vec(), yaml_doc["links"].as_vec()) {
-        let mut dag = Graph::<NodeData, i32>::new();
+    let (Some(nodes), Some(links)) = (yaml_doc["nodes"].as_vec(), yaml_doc["links"].as_vec())
+    else {
+        return Err(DagParseError::MissingNodesOrLinks {
+            file_path: file_path.to_string(),
+        });
+    };
+    let mut dag = Graph::<NodeData, i32>::new();
+    let mut edge_data = Vec::with_capacity(links.len());
+
+    // add nodes to dag
+    let mut node_ids = HashSet::with_capacity(nodes.len());
+    for node in nodes {
+        let mut params = BTreeMap::new();
+        let id = node["id"]
+            .as_i64()
+            .ok_or_else(|| unknown_value_type("id"))? as i32;
+        if !node_ids.insert(id) {
+            return Err(DagParseError::DuplicateNodeId {
+                file_path: file_path.to_string(),
+                id,
+            });
+        }
 
-        // add nodes to dag
-        for node in nodes {
-            let mut params = BTreeMap::new();
-            let id = node["id"].as_i64().unwrap() as i32;
-
-            // add node parameters to BTreeMap
-            for (key, value) in node.as_hash().unwrap() {
-                let key_str = key.as_str().unwrap();
-                if key_str != "id" {
-                    match value {
-                        Yaml::Integer(_i) => {
-                            params.insert(
-                                key_str.to_owned(),
-                                (value.as_i64().unwrap() * int_conversion_factor as i64) as i32,
-                            );
-                        }
-                        Yaml::Real(_r) => {
-                            params.insert(
-                                key_str.to_owned(),
-                                (value.as_f64().unwrap() * int_conversion_factor as f64).round()
-                                    as i32,
-                            );
-                        }
-                        _ => {
-                            panic!("Unknown type: {}", std::any::type_name::<Yaml>());
-                        }
+        // add node parameters to BTreeMap
+        for (key, value) in node.as_hash().ok_or_else(|| unknown_value_type("nodes"))? {
+            let key_str = key.as_str().ok_or_else(|| unknown_value_type("nodes"))?;
+            if key_str != "id" {
+                match value {
+                    Yaml::Integer(integer_value) => {
+                        params.insert(
+                            key_str.to_owned(),
+                            (integer_value * int_conversion_factor as i64) as i32,
+                        );
                     }
+                    Yaml::Real(_r) => {
+                        params.insert(
+                            key_str.to_owned(),
+                            (value.as_f64().unwrap() * int_conversion_factor as f64).round() as i32,
+                        );
+                    }
+                    // `kind` is an enumeration, so it is not scaled.
+                    Yaml::String(kind) if key_str == "kind" => {
+                        let kind_value = match kind.as_str() {
+                            "compute" => NODE_KIND_COMPUTE,
+                            "io" => NODE_KIND_IO,
+                            _ => return Err(unknown_value_type(key_str)),
+                        };
+                        params.insert(key_str.to_owned(), kind_value);
+                    }
+                    _ => return Err(unknown_value_type(key_str)),
                 }
             }
-            dag.add_node(NodeData { id, params });
         }
+        dag.add_node(NodeData { id, params });
+    }
 
-        // add edges to dag
-        for link in links {
-            let source = link["source"].as_i64().unwrap() as usize;
-            let target = link["target"].as_i64().unwrap() as usize;
-            let mut communication_time = 0;
+    // add edges to dag
+    for link in links {
+        let source = link["source"]
+            .as_i64()
+            .ok_or_else(|| unknown_value_type("source"))? as usize;
+        let target = link["target"]
+            .as_i64()
+            .ok_or_else(|| unknown_value_type("target"))? as usize;
+        if source >= dag.node_count() || target >= dag.node_count() {
+            return Err(DagParseError::LinkOutOfRange {
+                file_path: file_path.to_string(),
+                source,
+                target,
+                node_count: dag.node_count(),
+            });
+        }
+        if source == target {
+            return Err(DagParseError::SelfLoop {
+                file_path: file_path.to_string(),
+                node: source,
+            });
+        }
+        if dag
+            .find_edge(NodeIndex::new(source), NodeIndex::new(target))
+            .is_some()
+        {
+            warn!(
+                "Duplicate link found: source {}, target {}. Only the first link is used. file: {}",
+                source, target, file_path
+            );
+            continue;
+        }
+        let communication_time = match &link["communication_time"] {
+            Yaml::Integer(communication_time_value) => {
+                *communication_time_value as i32 * int_conversion_factor
+            }
+            Yaml::Real(communication_time_value) => {
+                (communication_time_value.parse::<f32>().unwrap() * int_conversion_factor as f32)
+                    as i32
+            }
+            Yaml::BadValue => 0,
+            _ => return Err(unknown_value_type("communication_time")),
+        };
 
-            match &link["communication_time"] {
-                Yaml::Integer(communication_time_value) => {
-                    communication_time = *communication_time_value as i32 * int_conversion_factor;
+        // add other link parameters to BTreeMap
+        let mut params = BTreeMap::new();
+        for (key, value) in link.as_hash().ok_or_else(|| unknown_value_type("links"))? {
+            let key_str = key.as_str().ok_or_else(|| unknown_value_type("links"))?;
+            if ["source", "target", "communication_time"].contains(&key_str) {
+                continue;
+            }
+            match value {
+                Yaml::Integer(integer_value) => {
+                    params.insert(
+                        key_str.to_owned(),
+                        (integer_value * int_conversion_factor as i64) as i32,
+                    );
                 }
-                Yaml::Real(communication_time_value) => {
-                    communication_time = (communication_time_value.parse::<f32>().unwrap()
-                        * int_conversion_factor as f32)
-                        as i32;
+                Yaml::Real(_r) => {
+                    params.insert(
+                        key_str.to_owned(),
+                        (value.as_f64().unwrap() * int_conversion_factor as f64).round() as i32,
+                    );
                 }
-                Yaml::BadValue => {}
-                _ => unreachable!(),
+                _ => return Err(unknown_value_type(key_str)),
             }
-            dag.add_edge(
-                NodeIndex::new(source),
-                NodeIndex::new(target),
-                communication_time,
-            );
         }
-        dag
-    } else {
-        panic!("YAML files are not DAG structures.");
+
+        dag.add_edge(
+            NodeIndex::new(source),
+            NodeIndex::new(target),
+            communication_time,
+        );
+        edge_data.push(EdgeData::new(communication_time, params));
     }
+
+    // Everything downstream assumes a DAG, so reject cycles here rather than failing later in a topological sort.
+    if let Err(cycle) = toposort(&dag, None) {
+        return Err(DagParseError::Cycle {
+            file_path: file_path.to_string(),
+            id: dag[cycle.node_id()].id,
+        });
+    }
+
+    // Priorities given in the YAML are used as they are, so a partial assignment is likely a mistake.
+    if dag
+        .node_weights()
+        .any(|node| node.params.contains_key("priority"))
+        && !has_complete_priorities(&dag)
+    {
+        warn!(
+            "Only some nodes have priority. The others are treated as the lowest priority. file: {}",
+            file_path
+        );
+    }
+    Ok((dag, edge_data))
 }
 
-fn get_yaml_paths_from_dir(dir_path: &str) -> Vec<String> {
+fn get_dag_paths_from_dir(dir_path: &str) -> Vec<String> {
     if !std::fs::metadata(dir_path).unwrap().is_dir() {
         panic!("Not a directory");
     }
@@ -153,21 +481,23 @@ fn get_yaml_paths_from_dir(dir_path: &str) -> Vec<String> {
     for dir_entry_result in PathBuf::from(dir_path).read_dir().unwrap() {
         let path = dir_entry_result.unwrap().path();
         let extension = path.extension().unwrap();
-        if extension == "yaml" || extension == "yml" {
+        if DAG_FILE_EXTENSIONS.iter().any(|ext| extension == *ext) {
             file_path_list.push(path.to_str().unwrap().to_string());
         }
     }
     if file_path_list.is_empty() {
-        panic!("No YAML file found in {}", dir_path);
+        panic!("No YAML or JSON file found in {}", dir_path);
     }
     file_path_list
 }
 
-/// load yaml files and return a DAGSet (dag list)
+/// load yaml and json files and return a DAGSet (dag list)
+///
+/// Files that are not valid DAGs are skipped with a warning.
 ///
 /// # Arguments
 ///
-/// *  `dir_path` - dir path for yaml files
+/// *  `dir_path` - dir path for yaml and json files
 ///
 /// # Returns
 ///
@@ -183,25 +513,183 @@ fn get_yaml_paths_from_dir(dir_path: &str) -> Vec<String> {
 /// let first_node_exe_time = dag_set[0][dag_set[0].node_indices().next().unwrap()].params["execution_time"];
 /// ```
 pub fn create_dag_set_from_dir(dir_path: &str) -> Vec<Graph<NodeData, i32>> {
-    let mut file_path_list = get_yaml_paths_from_dir(dir_path);
+    iter_dag_set_from_dir(dir_path).collect()
+}
+
+/// load yaml and json files lazily and return an iterator over the DAGSet
+///
+/// Only one DAG is held in memory at a time, so huge directories can be processed without loading every file at once.
+/// The DAGs are yielded in the same order and with the same `dag_id` as `create_dag_set_from_dir`.
+/// Files that are not valid DAGs are skipped with a warning, and `dag_id` is assigned to the remaining ones.
+/// Each DAG is built only when the iterator reaches it.
+/// If no file has `nodes` and `links` fields, it panics.
+///
+/// Each file may have a top-level `time_unit` field (`ns`, `us`, `ms` or `s`).
+/// When it is set, `execution_time`, `period`, `end_to_end_deadline`, `offset` and `communication_time`
+/// are normalized to the finest unit found in the directory. DAGs without `time_unit` are not scaled.
+///
+/// # Arguments
+///
+/// *  `dir_path` - dir path for yaml and json files
+///
+/// # Returns
+///
+/// *  `dag_set_iter` - iterator yielding dag objects (petgraph)
+///
+/// # Example
+///
+/// ```
+/// use lib::dag_creator::iter_dag_set_from_dir;
+/// use lib::graph_extension::GraphExtension;
+/// let total_volume: i32 = iter_dag_set_from_dir("tests/sample_dags/multiple_yaml")
+///     .map(|dag| dag.get_volume())
+///     .sum();
+/// ```
+pub fn iter_dag_set_from_dir(dir_path: &str) -> impl Iterator<Item = Graph<NodeData, i32>> {
+    let mut file_path_list = get_dag_paths_from_dir(dir_path);
     file_path_list.sort();
-    let exist_float_dag = get_yaml_paths_from_dir(dir_path).iter().any(|file_path| {
-        let yaml_doc = &load_yaml(file_path)[0];
-        get_minimum_decimal_places(yaml_doc) > 0
-    });
-    let mut dag_set: Vec<Graph<NodeData, i32>> = Vec::new();
-
-    for (dag_id, file_path) in file_path_list.iter().enumerate() {
-        let mut dag = create_dag_from_yaml(file_path, exist_float_dag);
-        dag.set_dag_param("dag_id", dag_id as i32);
-        dag_set.push(dag);
-    }
-    dag_set
+    // The pre-pass only reads the fields that affect every DAG (decimal places and time unit),
+    // so each graph is built once, lazily, and only one document is held at a time.
+    let mut exist_float_dag = false;
+    let mut candidate_files = Vec::with_capacity(file_path_list.len());
+    for file_path in file_path_list {
+        let checked = try_load_dag_document(&file_path, &DAG_FILE_EXTENSIONS).and_then(|dag_doc| {
+            if dag_doc["nodes"].as_vec().is_none() || dag_doc["links"].as_vec().is_none() {
+                return Err(DagParseError::MissingNodesOrLinks {
+                    file_path: file_path.clone(),
+                });
+            }
+            let time_unit = get_time_unit_in_ns(&dag_doc, &file_path)?;
+            Ok((get_minimum_decimal_places(&dag_doc) > 0, time_unit))
+        });
+        match checked {
+            Ok((is_float_dag, time_unit)) => {
+                exist_float_dag |= is_float_dag;
+                candidate_files.push((file_path, time_unit));
+            }
+            Err(err) => warn!("Skipped an invalid DAG file: {}", err),
+        }
+    }
+    if candidate_files.is_empty() {
+        panic!("No valid DAG file found in {}", dir_path);
+    }
+    let base_time_unit = candidate_files
+        .iter()
+        .filter_map(|(_, time_unit)| *time_unit)
+        .min();
+
+    let mut next_dag_id = 0;
+    candidate_files
+        .into_iter()
+        .filter_map(move |(file_path, time_unit)| {
+            let mut dag = match try_load_dag_document(&file_path, &DAG_FILE_EXTENSIONS)
+                .and_then(|dag_doc| build_dag_from_value(&dag_doc, &file_path, exist_float_dag))
+            {
+                Ok((dag, _)) => dag,
+                Err(err) => {
+                    warn!("Skipped an invalid DAG file: {}", err);
+                    return None;
+                }
+            };
+            if let (Some(time_unit), Some(base_time_unit)) = (time_unit, base_time_unit) {
+                scale_time_params(&mut dag, (time_unit / base_time_unit) as i32);
+            }
+            dag.set_dag_param("dag_id", next_dag_id);
+            next_dag_id += 1;
+            Some(dag)
+        })
+}
+
+/// load a yaml file that lists several DAGs and return a DAGSet (dag list)
+///
+/// The file has a top-level `dags` list, and each entry has its own `nodes` and `links` in the format of `create_dag_from_yaml`.
+/// Unlike `create_dag_set_from_dir`, the decimal places are scaled to integers per DAG,
+/// so a DAG with fractional values does not scale the others. `dag_id` is assigned in the order of the list.
+/// Note that the values of a DAG with fractional values are multiplied by 100000 while those of an integer-only DAG
+/// are kept as is, so the time values of such DAGs are in different units and are not comparable.
+/// A warning is logged when the file mixes them.
+/// An entry that is not a valid DAG causes a panic.
+///
+/// # Example
+///
+/// ```
+/// use lib::dag_creator::create_dag_set_from_yaml;
+/// let dag_set = create_dag_set_from_yaml("tests/sample_dags/multiple_dags_in_one_file.yaml");
+/// let first_node_num = dag_set[0].node_count();
+/// ```
+pub fn create_dag_set_from_yaml(file_path: &str) -> Vec<Graph<NodeData, i32>> {
+    let yaml_doc =
+        try_load_dag_document(file_path, &YAML_EXTENSIONS).unwrap_or_else(|err| panic!("{}", err));
+    let dag_docs = yaml_doc["dags"]
+        .as_vec()
+        .unwrap_or_else(|| panic!("The dags list is not found. file: {}", file_path));
+    let float_dag_ids: Vec<usize> = dag_docs
+        .iter()
+        .enumerate()
+        .filter(|(_, dag_doc)| get_minimum_decimal_places(dag_doc) > 0)
+        .map(|(dag_id, _)| dag_id)
+        .collect();
+    if !float_dag_ids.is_empty() && float_dag_ids.len() < dag_docs.len() {
+        warn!(
+            "The DAGs {:?} have fractional values and are scaled by a different factor from the other DAGs, so their time values are in different units. file: {}",
+            float_dag_ids, file_path
+        );
+    }
+    dag_docs
+        .iter()
+        .enumerate()
+        .map(|(dag_id, dag_doc)| {
+            let mut dag = build_dag_from_value(dag_doc, file_path, false)
+                .unwrap_or_else(|err| panic!("{}", err))
+                .0;
+            dag.set_dag_param("dag_id", dag_id as i32);
+            dag
+        })
+        .collect()
+}
+
+/// Overlay measured WCETs onto a DAG, e.g., one created from a structure-only yaml file.
+/// The CSV file has `node_id,wcet` rows (the header row is optional), and the `execution_time` of each node is set by its id.
+/// Rows whose id matches no node are warned and ignored.
+pub fn apply_wcet_overlay(dag: &mut Graph<NodeData, i32>, wcet_csv_path: &str) {
+    let csv = std::fs::read_to_string(wcet_csv_path)
+        .unwrap_or_else(|_| panic!("Failed to read the WCET file: {}", wcet_csv_path));
+    let id_index = dag.build_id_index();
+    let mut unmatched_node_ids = Vec::new();
+    for line in csv.lines().map(str::trim) {
+        if line.is_empty() || line == "node_id,wcet" {
+            continue;
+        }
+        let (node_id, wcet) = line
+            .split_once(',')
+            .and_then(|(node_id, wcet)| {
+                Some((
+                    node_id.trim().parse::<i32>().ok()?,
+                    wcet.trim().parse::<i32>().ok()?,
+                ))
+            })
+            .unwrap_or_else(|| panic!("Invalid row: {}. file: {}", line, wcet_csv_path));
+        match id_index.get(&node_id) {
+            Some(&node_i) => {
+                dag[node_i]
+                    .params
+                    .insert("execution_time".to_string(), wcet);
+            }
+            None => unmatched_node_ids.push(node_id),
+        }
+    }
+    if !unmatched_node_ids.is_empty() {
+        warn!(
+            "Node ids {:?} in {} do not match any node.",
+            unmatched_node_ids, wcet_csv_path
+        );
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::load_yaml;
 
     #[test]
     fn test_get_minimum_decimal_places_normal() {
@@ -255,6 +743,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_dag_set_from_yaml_normal() {
+        let dag_set = create_dag_set_from_yaml("tests/sample_dags/multiple_dags_in_one_file.yaml");
+        assert_eq!(dag_set.len(), 2);
+        assert_eq!(dag_set[0].node_count(), 3);
+        assert_eq!(dag_set[0].edge_count(), 2);
+        assert_eq!(dag_set[1].node_count(), 2);
+        assert_eq!(dag_set[1].edge_count(), 1);
+        for (dag_id, dag) in dag_set.iter().enumerate() {
+            assert_eq!(dag.get_dag_param("dag_id"), dag_id as i32);
+        }
+
+        // Only the DAG with fractional values is scaled.
+        let first_node = NodeIndex::new(0);
+        assert_eq!(dag_set[0][first_node].params["execution_time"], 3);
+        assert_eq!(dag_set[0][first_node].params["period"], 50);
+        assert_eq!(dag_set[1][first_node].params["execution_time"], 125000);
+        assert_eq!(dag_set[1][first_node].params["period"], 2000000);
+        assert_eq!(dag_set[1][EdgeIndex::new(0)], 50000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_create_dag_set_from_yaml_no_dags_list() {
+        create_dag_set_from_yaml("tests/sample_dags/chain_base_format.yaml");
+    }
+
     #[test]
     fn test_create_dag_set_from_dir_mixing_dif_ext() {
         let dag_set = create_dag_set_from_dir("tests/sample_dags/mixing_different_extensions");
@@ -262,9 +777,32 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    fn test_create_dag_set_from_dir_mixing_json_yaml() {
+        // The same DAGs as multiple_yaml, with dag_1 in json.
+        let dag_set = create_dag_set_from_dir("tests/sample_dags/mixing_json_yaml");
+        let yaml_dag_set = create_dag_set_from_dir("tests/sample_dags/multiple_yaml");
+        assert_eq!(dag_set.len(), 2);
+        for (dag, yaml_dag) in dag_set.iter().zip(yaml_dag_set.iter()) {
+            assert_eq!(
+                dag.node_weights().collect::<Vec<_>>(),
+                yaml_dag.node_weights().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                dag.edge_weights().collect::<Vec<_>>(),
+                yaml_dag.edge_weights().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
     fn test_create_dag_set_from_dir_mixing_not_dag_yaml() {
-        create_dag_set_from_dir("tests/sample_dags/mixing_not_dag_yaml");
+        let dag_set = create_dag_set_from_dir("tests/sample_dags/mixing_not_dag_yaml");
+        assert_eq!(
+            dag_set.len(),
+            1,
+            "the file that is not a DAG is expected to be skipped"
+        );
+        assert_eq!(dag_set[0].get_dag_param("dag_id"), 0);
     }
 
     #[test]
@@ -279,13 +817,57 @@ mod tests {
         create_dag_set_from_dir("tests/sample_dags/gnp_format.yaml");
     }
 
+    #[test]
+    fn test_create_dag_set_from_dir_normalize_time_unit() {
+        let dag_set = create_dag_set_from_dir("tests/sample_dags/multiple_time_unit_yaml");
+        // dag_0 is in ms and dag_1 is in us, so dag_0 is scaled by 1000.
+        assert_eq!(dag_set[0].get_head_period(), Some(10000));
+        assert_eq!(dag_set[1].get_head_period(), Some(5000));
+        assert_eq!(dag_set[0][NodeIndex::new(0)].params["execution_time"], 2000);
+        assert_eq!(dag_set[0].get_end_to_end_deadline(), Some(10000));
+        assert_eq!(dag_set[0][EdgeIndex::new(0)], 1000);
+        assert_eq!(dag_set[1][NodeIndex::new(0)].params["execution_time"], 300);
+    }
+
+    #[test]
+    fn test_iter_dag_set_from_dir_same_as_eager_loader() {
+        for dir_path in [
+            "tests/sample_dags/multiple_yaml",
+            "tests/sample_dags/multiple_float_yaml",
+            "tests/sample_dags/multiple_int_float_yaml",
+        ] {
+            let dag_set = create_dag_set_from_dir(dir_path);
+            let dag_set_iter = iter_dag_set_from_dir(dir_path);
+            let mut count = 0;
+            for (dag, iter_dag) in dag_set.iter().zip(dag_set_iter) {
+                assert_eq!(
+                    dag.node_weights().collect::<Vec<_>>(),
+                    iter_dag.node_weights().collect::<Vec<_>>()
+                );
+                assert_eq!(
+                    dag.raw_edges()
+                        .iter()
+                        .map(|edge| (edge.source(), edge.target(), edge.weight))
+                        .collect::<Vec<_>>(),
+                    iter_dag
+                        .raw_edges()
+                        .iter()
+                        .map(|edge| (edge.source(), edge.target(), edge.weight))
+                        .collect::<Vec<_>>()
+                );
+                count += 1;
+            }
+            assert_eq!(count, dag_set.len());
+        }
+    }
+
     #[test]
     fn test_create_dag_from_yaml_chain_base() {
         let dag = create_dag_from_yaml("tests/sample_dags/chain_base_format.yaml", false);
         let first_node = dag.node_indices().next().unwrap();
-        let last_node = dag.node_indices().last().unwrap();
+        let last_node = dag.node_indices().next_back().unwrap();
         let first_edge = dag.edge_indices().next().unwrap();
-        let last_edge = dag.edge_indices().last().unwrap();
+        let last_edge = dag.edge_indices().next_back().unwrap();
 
         assert_eq!(dag.node_count(), 22, "number of nodes is expected to be 22");
         assert_eq!(
@@ -334,9 +916,9 @@ mod tests {
     fn test_create_dag_from_yaml_fan_in_fan_out() {
         let dag = create_dag_from_yaml("tests/sample_dags/fan_in_fan_out_format.yaml", false);
         let first_node = dag.node_indices().next().unwrap();
-        let last_node = dag.node_indices().last().unwrap();
+        let last_node = dag.node_indices().next_back().unwrap();
         let first_edge = dag.edge_indices().next().unwrap();
-        let last_edge = dag.edge_indices().last().unwrap();
+        let last_edge = dag.edge_indices().next_back().unwrap();
 
         assert_eq!(dag.node_count(), 20, "number of nodes is expected to be 20");
         assert_eq!(
@@ -391,9 +973,9 @@ mod tests {
     fn test_create_dag_from_yaml_gnp() {
         let dag = create_dag_from_yaml("tests/sample_dags/gnp_format.yaml", false);
         let first_node = dag.node_indices().next().unwrap();
-        let last_node = dag.node_indices().last().unwrap();
+        let last_node = dag.node_indices().next_back().unwrap();
         let first_edge = dag.edge_indices().next().unwrap();
-        let last_edge = dag.edge_indices().last().unwrap();
+        let last_edge = dag.edge_indices().next_back().unwrap();
 
         assert_eq!(dag.node_count(), 70, "number of nodes is expected to be 70");
         assert_eq!(dag[first_node].id, 0, "first node id is expected to be 0");
@@ -471,9 +1053,9 @@ mod tests {
     fn test_create_dag_from_yaml_float_params() {
         let dag = create_dag_from_yaml("tests/sample_dags/float_params.yaml", false);
         let first_node = dag.node_indices().next().unwrap();
-        let last_node = dag.node_indices().last().unwrap();
+        let last_node = dag.node_indices().next_back().unwrap();
         let first_edge = dag.edge_indices().next().unwrap();
-        let last_edge = dag.edge_indices().last().unwrap();
+        let last_edge = dag.edge_indices().next_back().unwrap();
 
         assert_eq!(dag.node_count(), 3, "number of nodes is expected to be 3");
         assert_eq!(
@@ -531,9 +1113,9 @@ mod tests {
     fn test_create_dag_from_dag_int_when_other_dag_float() {
         let dag = create_dag_from_yaml("tests/sample_dags/gnp_format.yaml", true);
         let first_node = dag.node_indices().next().unwrap();
-        let last_node = dag.node_indices().last().unwrap();
+        let last_node = dag.node_indices().next_back().unwrap();
         let first_edge = dag.edge_indices().next().unwrap();
-        let last_edge = dag.edge_indices().last().unwrap();
+        let last_edge = dag.edge_indices().next_back().unwrap();
 
         assert_eq!(dag.node_count(), 70, "number of nodes is expected to be 70");
         assert_eq!(dag[first_node].id, 0, "first node id is expected to be 0");
@@ -607,6 +1189,49 @@ mod tests {
         assert_eq!(dag[last_edge], 0, "last edge weight is expected to be 0");
     }
 
+    #[test]
+    fn test_create_dag_and_edge_data_from_yaml_extra_link_field() {
+        let (dag, edge_data) =
+            create_dag_and_edge_data_from_yaml("tests/sample_dags/edge_params.yaml", false);
+        assert_eq!(edge_data.len(), dag.edge_count());
+        assert_eq!(edge_data[0].communication_time, 2);
+        assert_eq!(edge_data[0].params["message_size"], 64);
+        assert_eq!(edge_data[0].communication_time, dag[EdgeIndex::new(0)]);
+        assert_eq!(edge_data[1].communication_time, 0);
+        assert!(edge_data[1].params.is_empty());
+    }
+
+    #[test]
+    fn test_create_dag_from_json_same_as_yaml() {
+        for exist_other_float_dag in [false, true] {
+            let dag =
+                create_dag_from_json("tests/sample_dags/float_params.json", exist_other_float_dag);
+            let yaml_dag =
+                create_dag_from_yaml("tests/sample_dags/float_params.yaml", exist_other_float_dag);
+            assert_eq!(
+                dag.node_weights().collect::<Vec<_>>(),
+                yaml_dag.node_weights().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                dag.raw_edges()
+                    .iter()
+                    .map(|edge| (edge.source(), edge.target(), edge.weight))
+                    .collect::<Vec<_>>(),
+                yaml_dag
+                    .raw_edges()
+                    .iter()
+                    .map(|edge| (edge.source(), edge.target(), edge.weight))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_create_dag_from_json_not_json() {
+        create_dag_from_json("tests/sample_dags/float_params.yaml", false);
+    }
+
     #[test]
     #[should_panic]
     fn test_create_dag_from_yaml_path() {
@@ -620,8 +1245,143 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Link endpoint out of range: source 100, target 1")]
     fn test_create_dag_from_yaml_broken_link() {
         create_dag_from_yaml("tests/sample_dags/broken_link.yaml", false);
     }
+
+    #[test]
+    #[should_panic(expected = "Link endpoint out of range: source 0, target 2")]
+    fn test_create_dag_from_yaml_out_of_range_target() {
+        create_dag_from_yaml("tests/sample_dags/out_of_range_target_link.yaml", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Self-loop found: node 1")]
+    fn test_create_dag_from_yaml_self_loop() {
+        create_dag_from_yaml("tests/sample_dags/self_loop_link.yaml", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cycle found: node")]
+    fn test_create_dag_from_yaml_cycle() {
+        create_dag_from_yaml("tests/sample_dags/cycle_link.yaml", false);
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_cycle() {
+        match try_create_dag_from_yaml("tests/sample_dags/cycle_link.yaml", false) {
+            // Node 0 feeds the cycle 1 -> 2 -> 3 -> 1 but is not part of it.
+            Err(DagParseError::Cycle { id, .. }) => assert!([1, 2, 3].contains(&id)),
+            result => panic!(
+                "Unexpected result: {:?}",
+                result.map(|dag| dag.node_count())
+            ),
+        }
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_normal() {
+        let dag =
+            try_create_dag_from_yaml("tests/sample_dags/chain_base_format.yaml", false).unwrap();
+        assert_eq!(
+            dag.node_count(),
+            create_dag_from_yaml("tests/sample_dags/chain_base_format.yaml", false).node_count()
+        );
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_not_yaml() {
+        assert!(matches!(
+            try_create_dag_from_yaml("tests/sample_dags/no_yaml.tex", false),
+            Err(DagParseError::NotYaml { .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_io() {
+        assert!(matches!(
+            try_create_dag_from_yaml("tests/sample_dags/disable_path.yaml", false),
+            Err(DagParseError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_missing_nodes_or_links() {
+        assert!(matches!(
+            try_create_dag_from_yaml(
+                "tests/sample_dags/mixing_not_dag_yaml/combination_log.yaml",
+                false
+            ),
+            Err(DagParseError::MissingNodesOrLinks { .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_unknown_value_type() {
+        match try_create_dag_from_yaml("tests/sample_dags/unknown_value_type.yaml", false) {
+            Err(DagParseError::UnknownValueType { key, .. }) => assert_eq!(key, "execution_time"),
+            result => panic!(
+                "Unexpected result: {:?}",
+                result.map(|dag| dag.node_count())
+            ),
+        }
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_duplicate_node_id() {
+        assert!(matches!(
+            try_create_dag_from_yaml("tests/sample_dags/duplicate_node_id.yaml", false),
+            Err(DagParseError::DuplicateNodeId { id: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_create_dag_from_yaml_self_loop() {
+        assert!(matches!(
+            try_create_dag_from_yaml("tests/sample_dags/self_loop_link.yaml", false),
+            Err(DagParseError::SelfLoop { node: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_create_dag_from_yaml_duplicate_link() {
+        let (dag, edge_data) =
+            create_dag_and_edge_data_from_yaml("tests/sample_dags/duplicate_link.yaml", false);
+        assert_eq!(dag.edge_count(), 2);
+        assert_eq!(edge_data.len(), 2);
+        assert_eq!(dag.get_communication_volume(), 2 + 1);
+        let edge_i = dag.find_edge(NodeIndex::new(0), NodeIndex::new(1)).unwrap();
+        assert_eq!(dag[edge_i], 2);
+    }
+
+    #[test]
+    fn test_apply_wcet_overlay_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(0, &[]));
+        let n3 = dag.add_node(NodeData::with_params(3, &[]));
+        let n7 = dag.add_node(NodeData::with_params(7, &[]));
+        dag.add_edge(n0, n3, 0);
+        dag.add_edge(n0, n7, 0);
+
+        let file_path = "tests/wcet_overlay_test.csv";
+        std::fs::write(file_path, "node_id,wcet\n0,5\n3,12\n7,8\n9,4\n").unwrap();
+        apply_wcet_overlay(&mut dag, file_path);
+        std::fs::remove_file(file_path).unwrap();
+
+        assert_eq!(dag[n0].params["execution_time"], 5);
+        assert_eq!(dag[n3].params["execution_time"], 12);
+        assert_eq!(dag[n7].params["execution_time"], 8);
+        assert_eq!(dag.get_volume(), 5 + 12 + 8);
+    }
+
+    #[test]
+    fn test_create_dag_from_yaml_node_kind() {
+        let dag = create_dag_from_yaml("tests/sample_dags/node_kind.yaml", false);
+        assert!(!dag[NodeIndex::new(0)].is_io());
+        assert_eq!(dag[NodeIndex::new(0)].params["kind"], NODE_KIND_COMPUTE);
+        assert!(dag[NodeIndex::new(1)].is_io());
+        // A node without kind is a compute node.
+        assert!(!dag[NodeIndex::new(2)].is_io());
+    }
 }
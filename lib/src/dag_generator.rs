@@ -0,0 +1,195 @@
+//! Random DAG set generators for reproducible experiments.
+use crate::{
+    graph_extension::{GraphExtension, NodeData},
+    rng::SeededRng,
+};
+use petgraph::graph::{Graph, NodeIndex};
+use std::ops::RangeInclusive;
+
+const MIN_EXECUTION_TIME: i32 = 1;
+const MAX_EXECUTION_TIME: i32 = 100;
+/// Probability of an extra edge between two nodes in addition to the one that keeps the DAG connected.
+const EDGE_PROBABILITY: f64 = 0.2;
+
+/// Split `total_util` into `num_tasks` utilizations uniformly at random (UUniFast, Bini and Buttazzo).
+pub fn uunifast(num_tasks: usize, total_util: f32, rng: &mut SeededRng) -> Vec<f32> {
+    let mut utilizations = Vec::with_capacity(num_tasks);
+    let mut sum_util = total_util as f64;
+    for i in 1..num_tasks {
+        let next_sum_util = sum_util * rng.gen_f64().powf(1.0 / (num_tasks - i) as f64);
+        utilizations.push((sum_util - next_sum_util) as f32);
+        sum_util = next_sum_util;
+    }
+    if num_tasks > 0 {
+        utilizations.push(sum_util as f32);
+    }
+    utilizations
+}
+
+/// Generate a connected DAG with a single source node (node 0).
+/// Each other node has a random predecessor among the preceding nodes, plus extra edges with `EDGE_PROBABILITY`.
+fn generate_random_dag(num_nodes: usize, rng: &mut SeededRng) -> Graph<NodeData, i32> {
+    let mut dag = Graph::<NodeData, i32>::new();
+    for id in 0..num_nodes {
+        dag.add_node(NodeData::with_params(
+            id as i32,
+            &[(
+                "execution_time",
+                rng.gen_range(MIN_EXECUTION_TIME, MAX_EXECUTION_TIME + 1),
+            )],
+        ));
+    }
+    for target in 1..num_nodes {
+        let first_source = rng.gen_range(0, target as i32) as usize;
+        for source in 0..target {
+            if source == first_source || rng.gen_f64() < EDGE_PROBABILITY {
+                dag.add_edge(NodeIndex::new(source), NodeIndex::new(target), 0);
+            }
+        }
+    }
+    dag
+}
+
+/// Generate an Erdős–Rényi style DAG of `n` nodes, with `execution_time` drawn from 1 to 100.
+/// See `generate_gnp_dag_with_execution_time_range`.
+pub fn generate_gnp_dag(n: usize, p: f64, seed: u64) -> Graph<NodeData, i32> {
+    generate_gnp_dag_with_execution_time_range(n, p, seed, MIN_EXECUTION_TIME..=MAX_EXECUTION_TIME)
+}
+
+/// Generate an Erdős–Rényi style DAG of `n` nodes with `execution_time` drawn uniformly from `execution_time_range`.
+/// Each edge from node i to node j (i < j) is added with probability `p`, so the DAG is acyclic,
+/// but it may have several source and sink nodes. The period is set to the volume on node 0 (utilization 1.0),
+/// which can be changed with `set_period_for_utilization`.
+pub fn generate_gnp_dag_with_execution_time_range(
+    n: usize,
+    p: f64,
+    seed: u64,
+    execution_time_range: RangeInclusive<i32>,
+) -> Graph<NodeData, i32> {
+    if n == 0 {
+        panic!("The DAG must have at least one node.");
+    }
+    if !(0.0..=1.0).contains(&p) {
+        panic!("The edge probability must be in [0, 1]: {}", p);
+    }
+    let mut rng = SeededRng::new(seed);
+    let mut dag = Graph::<NodeData, i32>::new();
+    for id in 0..n {
+        dag.add_node(NodeData::with_params(
+            id as i32,
+            &[(
+                "execution_time",
+                rng.gen_range(
+                    *execution_time_range.start(),
+                    *execution_time_range.end() + 1,
+                ),
+            )],
+        ));
+    }
+    for source in 0..n {
+        for target in source + 1..n {
+            if rng.gen_f64() < p {
+                dag.add_edge(NodeIndex::new(source), NodeIndex::new(target), 0);
+            }
+        }
+    }
+    dag.set_period_for_utilization(1.0);
+    dag
+}
+
+/// Generate `num_dags` random DAGs of `num_nodes_each` nodes whose utilizations (volume / period) sum to `total_util`.
+/// The utilization of each DAG is drawn by UUniFast and achieved by setting its period, so the sum is
+/// `total_util` up to the rounding of the periods. Only the period is set (implicit deadline), and `dag_id` is assigned in order.
+pub fn generate_dag_set_uunifast(
+    num_dags: usize,
+    total_util: f32,
+    num_nodes_each: usize,
+    seed: u64,
+) -> Vec<Graph<NodeData, i32>> {
+    if num_nodes_each == 0 {
+        panic!("Each DAG must have at least one node.");
+    }
+    let mut rng = SeededRng::new(seed);
+    uunifast(num_dags, total_util, &mut rng)
+        .into_iter()
+        .enumerate()
+        .map(|(dag_id, utilization)| {
+            let mut dag = generate_random_dag(num_nodes_each, &mut rng);
+            dag.set_period_for_utilization(utilization);
+            dag.set_dag_param("dag_id", dag_id as i32);
+            dag
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_utilization(dag: &Graph<NodeData, i32>) -> f32 {
+        dag.get_volume() as f32 / dag.get_head_period().unwrap() as f32
+    }
+
+    #[test]
+    fn test_uunifast_sum() {
+        let mut rng = SeededRng::new(0);
+        let utilizations = uunifast(10, 3.5, &mut rng);
+        assert_eq!(utilizations.len(), 10);
+        assert!(utilizations.iter().all(|&utilization| utilization > 0.0));
+        assert!((utilizations.iter().sum::<f32>() - 3.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_generate_dag_set_uunifast_total_utilization() {
+        let dag_set = generate_dag_set_uunifast(5, 2.0, 20, 7);
+        assert_eq!(dag_set.len(), 5);
+        for (dag_id, dag) in dag_set.iter().enumerate() {
+            assert_eq!(dag.node_count(), 20);
+            assert_eq!(dag.get_source_nodes().len(), 1);
+            assert!(!petgraph::algo::is_cyclic_directed(dag));
+            assert_eq!(dag.get_dag_param("dag_id"), dag_id as i32);
+        }
+        let total_utilization: f32 = dag_set.iter().map(get_utilization).sum();
+        assert!((total_utilization - 2.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_generate_gnp_dag_normal() {
+        let dag = generate_gnp_dag_with_execution_time_range(30, 0.3, 3, 5..=10);
+        assert_eq!(dag.node_count(), 30);
+        assert!(!petgraph::algo::is_cyclic_directed(&dag));
+        assert!(dag
+            .node_weights()
+            .all(|node| { (5..=10).contains(&node.get_params_value("execution_time")) }));
+        assert!(dag.edge_indices().all(|edge_i| {
+            let (source, target) = dag.edge_endpoints(edge_i).unwrap();
+            source < target
+        }));
+        assert_eq!(dag.get_head_period(), Some(dag.get_volume()));
+
+        let same_seed_dag = generate_gnp_dag_with_execution_time_range(30, 0.3, 3, 5..=10);
+        assert_eq!(dag.dag_fingerprint(), same_seed_dag.dag_fingerprint());
+    }
+
+    #[test]
+    fn test_generate_gnp_dag_edge_probability_bounds() {
+        assert_eq!(generate_gnp_dag(10, 0.0, 0).edge_count(), 0);
+        assert_eq!(generate_gnp_dag(10, 1.0, 0).edge_count(), 10 * 9 / 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_generate_gnp_dag_invalid_probability() {
+        generate_gnp_dag(10, 1.5, 0);
+    }
+
+    #[test]
+    fn test_generate_dag_set_uunifast_same_seed() {
+        let dag_set_a = generate_dag_set_uunifast(3, 1.5, 10, 42);
+        let dag_set_b = generate_dag_set_uunifast(3, 1.5, 10, 42);
+        for (dag_a, dag_b) in dag_set_a.iter().zip(dag_set_b.iter()) {
+            assert_eq!(dag_a.get_head_period(), dag_b.get_head_period());
+            assert_eq!(dag_a.edge_count(), dag_b.edge_count());
+        }
+    }
+}
@@ -1,15 +1,53 @@
 use crate::{
     core::ProcessResult,
     graph_extension::{GraphExtension, NodeData},
-    log::DAGSchedulerLog,
+    log::{DAGSchedulerLog, LogVerbosity},
     processor::ProcessorBase,
+    rng::SeededRng,
     util::{create_scheduler_log_yaml, get_process_core_indices},
 };
+use log::warn;
 use petgraph::graph::{Graph, NodeIndex};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 const DUMMY_EXECUTION_TIME: i32 = 1;
 
+/// Which execution time of the nodes is used for scheduling.
+/// Scheduling the same DAG with `Bcet` and `Wcet` brackets the range of the response time.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ExecutionTimeMode {
+    /// Use `execution_time` as is.
+    #[default]
+    ExecutionTime,
+    /// Use `bcet` (best-case execution time).
+    Bcet,
+    /// Use `wcet` (worst-case execution time).
+    Wcet,
+}
+
+impl ExecutionTimeMode {
+    fn get_key(&self) -> Option<&str> {
+        match self {
+            ExecutionTimeMode::ExecutionTime => None,
+            ExecutionTimeMode::Bcet => Some("bcet"),
+            ExecutionTimeMode::Wcet => Some("wcet"),
+        }
+    }
+
+    /// Overwrite `execution_time` of each node with its execution time of the mode, if it has one.
+    pub(crate) fn apply(&self, dag: &mut Graph<NodeData, i32>) {
+        let Some(key) = self.get_key() else {
+            return;
+        };
+        for node in dag.node_weights_mut() {
+            if let Some(&execution_time) = node.params.get(key) {
+                node.params
+                    .insert("execution_time".to_string(), execution_time);
+            }
+        }
+    }
+}
+
 pub trait DAGSchedulerBase<T>
 where
     T: ProcessorBase + Clone,
@@ -18,22 +56,64 @@ where
     fn set_dag(&mut self, dag: &Graph<NodeData, i32>);
     fn set_processor(&mut self, processor: &T);
     fn set_log(&mut self, log: DAGSchedulerLog);
+    /// When a seed is set, ready nodes with the same priority are randomly permuted instead of deterministically tie-broken.
+    fn set_seed(&mut self, seed: Option<u64>);
+    /// Processor time charged on the core each time a node is dispatched, in addition to its execution time.
+    fn set_dispatch_overhead(&mut self, dispatch_overhead: i32);
+    /// Nodes without the execution time of the mode (`bcet` or `wcet`) use `execution_time`.
+    /// The default `ExecutionTimeMode::ExecutionTime` uses `execution_time` of every node.
+    fn set_execution_time_mode(&mut self, execution_time_mode: ExecutionTimeMode);
     fn get_dag(&self) -> Graph<NodeData, i32>;
     fn get_processor(&self) -> T;
     fn get_log(&self) -> DAGSchedulerLog;
+    fn get_seed(&self) -> Option<u64>;
+    fn get_dispatch_overhead(&self) -> i32;
+    fn get_execution_time_mode(&self) -> ExecutionTimeMode;
     // method definition
     fn new(dag: &Graph<NodeData, i32>, processor: &T) -> Self
     where
         Self: Sized;
-    fn sort_ready_queue(ready_queue: &mut VecDeque<NodeData>);
+    fn sort_ready_queue(&self, ready_queue: &mut VecDeque<NodeData>);
     // method implementation
+    /// Called once at the start of `schedule` with the DAG to be scheduled, e.g., to validate it.
+    fn prepare_schedule(&mut self, _dag: &Graph<NodeData, i32>) {}
+    /// Whether the node may be allocated to the core. A node is skipped for the core while this returns false.
+    fn can_allocate_to_core(&self, _node_data: &NodeData, _core_index: usize) -> bool {
+        true
+    }
+    /// Called before a ready node is allocated. A node is skipped while this returns false.
+    fn try_acquire_resource(&mut self, _node_data: &NodeData) -> bool {
+        true
+    }
+    /// Called when a node finishes, to return what was taken in `try_acquire_resource`.
+    fn release_resource(&mut self, _node_data: &NodeData) {}
+    /// Whether a node waits for the communication time (edge weight) from each predecessor on another core.
+    /// A node on the same core as its predecessor does not wait. Communication is ignored by default.
+    fn is_communication_aware(&self) -> bool {
+        false
+    }
+
     fn schedule(&mut self) -> (i32, VecDeque<NodeIndex>) {
         {
             let mut dag = self.get_dag(); //To avoid adding pre_node_count to the original DAG
+            if dag.node_count() == 0 {
+                warn!("The DAG has no nodes. Nothing is scheduled.");
+                let mut log = self.get_log();
+                log.calculate_utilization(0);
+                self.set_log(log);
+                return (0, VecDeque::new());
+            }
+            self.prepare_schedule(&dag);
+            self.get_execution_time_mode().apply(&mut dag);
             let mut processor = self.get_processor();
             let mut ready_queue = VecDeque::new();
             let mut log = self.get_log();
             let mut execution_order = VecDeque::new();
+            let mut rng = self.get_seed().map(SeededRng::new);
+            let is_communication_aware = self.is_communication_aware();
+            // Core and finish time of each finished node, and the ready time of each ready node on each core.
+            let mut finished_nodes: HashMap<NodeIndex, (usize, i32)> = HashMap::new();
+            let mut ready_times_on_cores: HashMap<i32, Vec<i32>> = HashMap::new();
             let source_node_i = dag.add_dummy_source_node();
 
             dag[source_node_i]
@@ -48,14 +128,50 @@ where
 
             let mut current_time = 0;
             loop {
-                Self::sort_ready_queue(&mut ready_queue);
+                // The sort is stable, so shuffling beforehand randomizes the order among ties.
+                if let Some(rng) = rng.as_mut() {
+                    rng.shuffle(ready_queue.make_contiguous());
+                }
+                self.sort_ready_queue(&mut ready_queue);
 
                 // Assign the highest priority task first to the first idle core found.
-                while let Some(core_index) = processor.get_idle_core_index() {
-                    if let Some(node_d) = ready_queue.pop_front() {
-                        processor.allocate_specific_core(core_index, &node_d);
+                for core_index in 0..processor.get_number_of_cores() {
+                    if processor
+                        .get_processing_node_and_remain_time(core_index)
+                        .is_some()
+                    {
+                        continue;
+                    }
+                    // The first node that can run on the core and whose resource can be acquired is allocated.
+                    // A node whose resource is not available is skipped, so a later node may run first.
+                    let mut allocated_position = None;
+                    for (position, node_d) in ready_queue.iter().enumerate() {
+                        let is_ready_on_core = self.can_allocate_to_core(node_d, core_index)
+                            && ready_times_on_cores
+                                .get(&node_d.id)
+                                .is_none_or(|ready_times| ready_times[core_index] <= current_time);
+                        if is_ready_on_core && self.try_acquire_resource(node_d) {
+                            allocated_position = Some(position);
+                            break;
+                        }
+                    }
+                    if let Some(position) = allocated_position {
+                        let node_d = ready_queue.remove(position).unwrap();
+                        let is_dummy =
+                            node_d.id == dag[source_node_i].id || node_d.id == dag[sink_node_i].id;
+                        if is_dummy || self.get_dispatch_overhead() == 0 {
+                            processor.allocate_specific_core(core_index, &node_d);
+                        } else {
+                            let mut dispatched_node_d = node_d.clone();
+                            dispatched_node_d.params.insert(
+                                "execution_time".to_string(),
+                                node_d.get_params_value("execution_time")
+                                    + self.get_dispatch_overhead(),
+                            );
+                            processor.allocate_specific_core(core_index, &dispatched_node_d);
+                        }
 
-                        if node_d.id != dag[source_node_i].id && node_d.id != dag[sink_node_i].id {
+                        if !is_dummy {
                             log.write_allocating_job(
                                 &node_d,
                                 core_index,
@@ -63,8 +179,6 @@ where
                             );
                         }
                         execution_order.push_back(NodeIndex::new(node_d.id as usize));
-                    } else {
-                        break;
                     }
                 }
 
@@ -76,10 +190,18 @@ where
                 let indices: Vec<usize> = get_process_core_indices(&process_result);
                 log.write_processing_time(&indices);
 
-                // Process until there is a task finished.
+                // Process until there is a task finished, or a node waiting for communication becomes ready on a core.
+                let next_ready_time = ready_queue
+                    .iter()
+                    .filter_map(|node_d| ready_times_on_cores.get(&node_d.id))
+                    .flatten()
+                    .copied()
+                    .filter(|&ready_time| ready_time > current_time)
+                    .min();
                 while !process_result
                     .iter()
                     .any(|result| matches!(result, ProcessResult::Done(_)))
+                    && next_ready_time.is_none_or(|ready_time| current_time < ready_time)
                 {
                     process_result = processor.process();
                     current_time += 1;
@@ -95,8 +217,10 @@ where
                     .enumerate()
                     .filter_map(|(core_id, result)| {
                         if let ProcessResult::Done(node_data) = result {
+                            self.release_resource(node_data);
                             let node_id = node_data.id as usize;
                             let node_i = NodeIndex::new(node_id);
+                            finished_nodes.insert(node_i, (core_id, current_time));
                             if node_i != source_node_i && node_i != sink_node_i {
                                 log.write_finishing_job(
                                     node_data,
@@ -129,6 +253,30 @@ where
                             dag.add_param(suc_node, "pre_done_count", 1);
                         }
                         if dag.is_node_ready(suc_node) {
+                            if is_communication_aware {
+                                let ready_times = (0..processor.get_number_of_cores())
+                                    .map(|core_index| {
+                                        dag.get_pre_nodes(suc_node)
+                                            .unwrap()
+                                            .into_iter()
+                                            .map(|pre_node| {
+                                                let (pre_core_index, finish_time) =
+                                                    finished_nodes[&pre_node];
+                                                if pre_core_index == core_index {
+                                                    finish_time
+                                                } else {
+                                                    finish_time
+                                                        + dag[dag
+                                                            .find_edge(pre_node, suc_node)
+                                                            .unwrap()]
+                                                }
+                                            })
+                                            .max()
+                                            .unwrap()
+                                    })
+                                    .collect();
+                                ready_times_on_cores.insert(dag[suc_node].id, ready_times);
+                            }
                             ready_queue.push_back(dag[suc_node].clone());
                         }
                     }
@@ -145,6 +293,7 @@ where
 
             let schedule_length = current_time - DUMMY_EXECUTION_TIME * 2;
             log.calculate_utilization(schedule_length);
+            log.write_total_energy(processor.get_consumed_energy());
 
             self.set_log(log);
 
@@ -154,9 +303,61 @@ where
     }
 
     fn dump_log(&self, dir_path: &str, alg_name: &str) -> String {
+        self.dump_log_with_verbosity(dir_path, alg_name, LogVerbosity::Full)
+    }
+
+    fn dump_log_with_verbosity(
+        &self,
+        dir_path: &str,
+        alg_name: &str,
+        verbosity: LogVerbosity,
+    ) -> String {
         let file_path = create_scheduler_log_yaml(dir_path, alg_name);
-        self.get_log().dump_log_to_yaml(&file_path);
+        self.get_log()
+            .dump_log_to_yaml_with_verbosity(&file_path, verbosity);
 
         file_path
     }
 }
+
+#[macro_export]
+macro_rules! getset_dag_scheduler {
+    { $t:ty } => {
+        fn set_dag(&mut self, dag: &Graph<NodeData, i32>) {
+            self.dag = dag.clone();
+        }
+        fn set_processor(&mut self, processor: &$t) {
+            self.processor = processor.clone();
+        }
+        fn set_log(&mut self, log: $crate::log::DAGSchedulerLog) {
+            self.log = log;
+        }
+        fn set_seed(&mut self, seed: Option<u64>) {
+            self.seed = seed;
+        }
+        fn set_dispatch_overhead(&mut self, dispatch_overhead: i32) {
+            self.dispatch_overhead = dispatch_overhead;
+        }
+        fn set_execution_time_mode(&mut self, execution_time_mode: $crate::dag_scheduler::ExecutionTimeMode) {
+            self.execution_time_mode = execution_time_mode;
+        }
+        fn get_dag(&self) -> Graph<NodeData, i32> {
+            self.dag.clone()
+        }
+        fn get_processor(&self) -> $t {
+            self.processor.clone()
+        }
+        fn get_log(&self) -> $crate::log::DAGSchedulerLog {
+            self.log.clone()
+        }
+        fn get_seed(&self) -> Option<u64> {
+            self.seed
+        }
+        fn get_dispatch_overhead(&self) -> i32 {
+            self.dispatch_overhead
+        }
+        fn get_execution_time_mode(&self) -> $crate::dag_scheduler::ExecutionTimeMode {
+            self.execution_time_mode
+        }
+    };
+}
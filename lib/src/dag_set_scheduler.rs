@@ -1,17 +1,25 @@
 use crate::{
     core::ProcessResult,
     graph_extension::{GraphExtension, NodeData},
-    log::{DAGSetSchedulerLog, JobEventTimes},
+    log::{DAGSetSchedulerLog, JobEventTimes, LogVerbosity, SimulationStats, StarvedNode},
     processor::ProcessorBase,
     util::{create_scheduler_log_yaml, get_hyper_period, get_process_core_indices},
 };
+use log::warn;
 use petgraph::graph::{Graph, NodeIndex};
-use std::{cmp::Ordering, collections::BTreeSet};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+    time::Instant,
+};
 
 // Define a new wrapper type
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NodeDataWrapper {
     pub node_data: NodeData,
+    /// Comparison key set by `get_dynamic_priority` when the node is inserted into the ready queue (smaller first).
+    /// If it is None, the nodes are compared by their absolute deadline.
+    pub dynamic_priority: Option<i32>,
 }
 
 impl Ord for NodeDataWrapper {
@@ -79,9 +87,163 @@ impl DAGStateManagerBase for DAGStateManager {
     getset_dag_state_manager!();
 }
 
+/// Number of releases of the DAG job that `node_data` belongs to.
+/// A node of a multi-rate DAG carries its own `release_count` because it is released independently of the DAG.
+fn get_release_count(node_data: &NodeData, managers: &[impl DAGStateManagerBase]) -> i32 {
+    node_data
+        .params
+        .get("release_count")
+        .copied()
+        .unwrap_or_else(|| {
+            managers[node_data.get_params_value("dag_id") as usize].get_release_count()
+        })
+}
+
+/// Release the nodes of a multi-rate DAG whose own `offset` + `period` * `release_count` is `current_time`.
+/// Each released job has an implicit deadline, i.e., its absolute deadline is the next release time.
+fn release_multi_rate_nodes(dag: &mut Graph<NodeData, i32>, current_time: i32) -> Vec<NodeData> {
+    let mut ready_nodes = Vec::new();
+    for node_i in dag.node_indices() {
+        let params = &mut dag[node_i].params;
+        let Some(&period) = params.get("period") else {
+            continue;
+        };
+        let offset = *params.get("offset").unwrap_or(&0);
+        let release_count = *params.get("release_count").unwrap_or(&0);
+        if current_time == offset + period * release_count {
+            params.insert("release_count".to_string(), release_count + 1);
+            params.insert("node_absolute_deadline".to_string(), current_time + period);
+            ready_nodes.push(dag[node_i].clone());
+        }
+    }
+    ready_nodes
+}
+
+/// Release the successors of `node` in a multi-rate DAG that are triggered by its completion.
+/// Successors with `period` are released only by their own timer.
+/// The others are released each time their predecessors have completed as many times as there are predecessors,
+/// and inherit the absolute deadline of the completed node.
+fn trigger_multi_rate_successors(dag: &mut Graph<NodeData, i32>, node: &NodeData) -> Vec<NodeData> {
+    let mut ready_nodes = Vec::new();
+    for suc_node in dag
+        .get_suc_nodes(NodeIndex::new(node.get_id() as usize))
+        .unwrap_or_default()
+    {
+        if dag[suc_node].params.contains_key("period") {
+            continue;
+        }
+        let pre_done_count = *dag[suc_node].params.get("pre_done_count").unwrap_or(&0) + 1;
+        dag[suc_node]
+            .params
+            .insert("pre_done_count".to_string(), pre_done_count);
+        if dag.is_node_ready(suc_node) {
+            let params = &mut dag[suc_node].params;
+            let release_count = *params.get("release_count").unwrap_or(&0);
+            params.insert("pre_done_count".to_string(), 0);
+            params.insert("release_count".to_string(), release_count + 1);
+            params.insert(
+                "node_absolute_deadline".to_string(),
+                node.get_params_value("node_absolute_deadline"),
+            );
+            ready_nodes.push(dag[suc_node].clone());
+        }
+    }
+    ready_nodes
+}
+
+/// State of a scheduling run captured by `snapshot` and resumed by `restore`.
+#[derive(Clone)]
+pub struct SchedulerState<T: ProcessorBase + Clone> {
+    pub current_time: i32,
+    pub dag_set: Vec<Graph<NodeData, i32>>,
+    pub processor: T,
+    pub ready_queue: BTreeSet<NodeDataWrapper>,
+    pub managers: Vec<DAGStateManager>,
+    pub remaining_times: BTreeMap<(usize, i32), i32>,
+    pub log: DAGSetSchedulerLog,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum PreemptiveType {
+    #[default]
     NonPreemptive,
-    Preemptive { key: String },
+    Preemptive {
+        key: String,
+    },
+}
+
+/// How a DAG instance that is still running at its absolute deadline is handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DeadlineMissPolicy {
+    /// The instance runs to completion.
+    #[default]
+    Continue,
+    /// The instance is aborted at its deadline: its nodes are removed from the cores and the ready queue,
+    /// and it is recorded as aborted instead of finished.
+    Abort,
+}
+
+/// Options of a scheduler, collected in one place so that adding an option does not change the call sites.
+/// Each scheduler holds one, which `schedule_with_config` replaces and `get_config` returns.
+/// Start from `SchedulerConfig::default()` (the same as a newly created scheduler) and chain the setters, e.g.,
+/// `SchedulerConfig::default().single_instance(true).constrained_deadline(true)`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchedulerConfig {
+    pub preemptive_type: PreemptiveType,
+    /// Cache-related preemption delay added to the remaining execution time each time a node is preempted.
+    pub crpd: i32,
+    /// When set, `schedule` stops at this time instead of the hyper-period computed from the DAG set.
+    pub hyper_period_override: Option<i32>,
+    /// Applied by `schedule_until`. Schedulers with their own scheduling loop may ignore it.
+    pub deadline_miss_policy: DeadlineMissPolicy,
+    /// When set, each DAG is released only once, and `schedule` runs until every instance has finished
+    /// instead of until the hyper-period. Multi-rate DAGs are released as usual.
+    pub single_instance: bool,
+    /// When set, the period and the end-to-end deadline are independent (constrained deadline):
+    /// the absolute deadline of an instance is its release time + the end-to-end deadline.
+    /// Otherwise, the deadline is assumed to be equal to the period (implicit deadline).
+    pub constrained_deadline: bool,
+    /// Cores of each cluster, indexed by cluster id. A DAG with the `cluster` parameter runs only on the cores of its cluster.
+    /// Empty (the default) for global scheduling. Applied by `schedule_until`.
+    pub clusters: Vec<Vec<usize>>,
+}
+
+impl SchedulerConfig {
+    pub fn preemptive_type(mut self, preemptive_type: PreemptiveType) -> Self {
+        self.preemptive_type = preemptive_type;
+        self
+    }
+
+    pub fn crpd(mut self, crpd: i32) -> Self {
+        self.crpd = crpd;
+        self
+    }
+
+    /// None clears the override.
+    pub fn hyper_period_override(mut self, hyper_period_override: Option<i32>) -> Self {
+        self.hyper_period_override = hyper_period_override;
+        self
+    }
+
+    pub fn deadline_miss_policy(mut self, deadline_miss_policy: DeadlineMissPolicy) -> Self {
+        self.deadline_miss_policy = deadline_miss_policy;
+        self
+    }
+
+    pub fn single_instance(mut self, single_instance: bool) -> Self {
+        self.single_instance = single_instance;
+        self
+    }
+
+    pub fn constrained_deadline(mut self, constrained_deadline: bool) -> Self {
+        self.constrained_deadline = constrained_deadline;
+        self
+    }
+
+    pub fn clusters(mut self, clusters: Vec<Vec<usize>>) -> Self {
+        self.clusters = clusters;
+        self
+    }
 }
 
 pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
@@ -90,9 +252,41 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
     fn set_dag_set(&mut self, dag_set: Vec<Graph<NodeData, i32>>);
     fn get_processor_mut(&mut self) -> &mut T;
     fn get_processor(&self) -> &T;
+    fn get_log(&self) -> &DAGSetSchedulerLog;
     fn get_log_mut(&mut self) -> &mut DAGSetSchedulerLog;
     fn get_current_time(&self) -> i32;
     fn set_current_time(&mut self, current_time: i32);
+    fn get_ready_queue(&self) -> &BTreeSet<NodeDataWrapper>;
+    fn get_ready_queue_mut(&mut self) -> &mut BTreeSet<NodeDataWrapper>;
+    fn get_managers(&self) -> &Vec<DAGStateManager>;
+    fn get_managers_mut(&mut self) -> &mut Vec<DAGStateManager>;
+    /// Remaining execution time of the preempted nodes, keyed by (dag_id, node_id).
+    fn get_remaining_times(&self) -> &BTreeMap<(usize, i32), i32>;
+    fn get_remaining_times_mut(&mut self) -> &mut BTreeMap<(usize, i32), i32>;
+    fn get_config(&self) -> &SchedulerConfig;
+    fn get_config_mut(&mut self) -> &mut SchedulerConfig;
+    /// Replace the config. Panics if the clusters overlap or refer to a core that the processor does not have.
+    fn set_config(&mut self, config: SchedulerConfig) {
+        let number_of_cores = self.get_processor().get_number_of_cores();
+        let mut cluster_of_core = vec![None; number_of_cores];
+        for (cluster_id, cluster_cores) in config.clusters.iter().enumerate() {
+            for &core_id in cluster_cores {
+                if core_id >= number_of_cores {
+                    panic!(
+                        "Core {} of cluster {} is out of range. The number of cores is {}.",
+                        core_id, cluster_id, number_of_cores
+                    );
+                }
+                if let Some(other_cluster_id) = cluster_of_core[core_id].replace(cluster_id) {
+                    panic!(
+                        "Core {} belongs to both cluster {} and cluster {}.",
+                        core_id, other_cluster_id, cluster_id
+                    );
+                }
+            }
+        }
+        *self.get_config_mut() = config;
+    }
     // method definition
     fn new(dag_set: &[Graph<NodeData, i32>], processor: &T) -> Self;
     // method implementation
@@ -103,11 +297,36 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
 
         for dag in dag_set.iter_mut() {
             let dag_id = dag.get_dag_param("dag_id") as usize;
+            if dag.is_multi_rate() {
+                ready_nodes.extend(release_multi_rate_nodes(dag, current_time));
+                continue;
+            }
+            if self.get_config().single_instance && managers[dag_id].get_release_count() > 0 {
+                continue;
+            }
+            let nominal_release_time = dag.get_head_offset()
+                + dag.get_head_period().unwrap() * managers[dag_id].get_release_count();
+            // A release is delayed until `min_separation` has passed since the previous instance finished.
+            let min_separation = dag[NodeIndex::new(0)].params.get("min_separation").copied();
+            // An aborted instance has no finish time (i32::MAX) and does not constrain the next release.
+            let last_finish_time = self
+                .get_log()
+                .get_finish_times(dag_id)
+                .last()
+                .filter(|&&finish_time| finish_time != i32::MAX);
+            let release_time = match (min_separation, last_finish_time) {
+                (Some(min_separation), Some(last_finish_time)) => {
+                    nominal_release_time.max(last_finish_time + min_separation)
+                }
+                _ => nominal_release_time,
+            };
             if (managers[dag_id].get_dag_state() == DAGState::Waiting)
-                && (current_time
-                    == dag.get_head_offset()
-                        + dag.get_head_period().unwrap() * managers[dag_id].get_release_count())
+                && (current_time == release_time)
             {
+                if min_separation.is_some() {
+                    self.get_log_mut()
+                        .write_separation_delay(dag_id, release_time - nominal_release_time);
+                }
                 managers[dag_id].release();
                 // If Node does not have individual deadlines, use DAG deadline.
                 if dag[NodeIndex::new(0)]
@@ -122,6 +341,11 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
                             node_relative_deadline * managers[dag_id].get_release_count(),
                         );
                     }
+                } else if self.get_config().constrained_deadline {
+                    dag.set_dag_param(
+                        "node_absolute_deadline",
+                        current_time + dag.get_end_to_end_deadline().unwrap(),
+                    );
                 } else {
                     dag.set_dag_param(
                         "node_absolute_deadline",
@@ -138,7 +362,76 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
         ready_nodes
     }
 
+    /// Under `DeadlineMissPolicy::Abort`, abort the DAG instances that have not finished by their absolute deadline
+    /// (release time + end-to-end deadline, or the period if it is not set). Multi-rate DAGs are not aborted.
+    fn abort_overrunning_dags(
+        &mut self,
+        managers: &mut [impl DAGStateManagerBase],
+        ready_queue: &mut BTreeSet<NodeDataWrapper>,
+    ) {
+        if self.get_config().deadline_miss_policy != DeadlineMissPolicy::Abort {
+            return;
+        }
+        let current_time = self.get_current_time();
+        let mut dag_set = self.get_dag_set();
+
+        for dag in dag_set.iter_mut() {
+            let dag_id = dag.get_dag_param("dag_id") as usize;
+            if dag.is_multi_rate() || managers[dag_id].get_dag_state() == DAGState::Waiting {
+                continue;
+            }
+            let relative_deadline = dag
+                .get_end_to_end_deadline()
+                .or_else(|| dag.get_head_period())
+                .unwrap();
+            let release_time = *self.get_log().get_release_times(dag_id).last().unwrap();
+            if current_time < release_time + relative_deadline {
+                continue;
+            }
+
+            let job_id = (managers[dag_id].get_release_count() - 1) as usize;
+            for core_id in 0..self.get_processor().get_number_of_cores() {
+                let is_running_dag = self
+                    .get_processor()
+                    .get_processing_node_and_remain_time(core_id)
+                    .is_some_and(|(node_data, _)| {
+                        node_data.get_params_value("dag_id") as usize == dag_id
+                    });
+                if is_running_dag {
+                    let aborted_node_data = self.get_processor_mut().preempt(core_id).unwrap();
+                    self.get_log_mut().write_job_event(
+                        &aborted_node_data,
+                        core_id,
+                        job_id,
+                        JobEventTimes::AbortedTime(current_time),
+                    );
+                }
+            }
+            ready_queue.retain(|node_data_wrapper| {
+                node_data_wrapper.node_data.get_params_value("dag_id") as usize != dag_id
+            });
+            self.get_remaining_times_mut()
+                .retain(|&(remaining_dag_id, _), _| remaining_dag_id != dag_id);
+            dag.set_dag_param("pre_done_count", 0);
+            self.get_log_mut().write_dag_abort(dag_id, job_id);
+            managers[dag_id].complete_execution();
+        }
+        self.set_dag_set(dag_set);
+    }
+
     fn allocate_node(&mut self, node_data: &NodeData, core_id: usize, job_id: usize) {
+        // A preempted node resumes with exactly its remaining execution time.
+        let mut node_data = node_data.clone();
+        let key = (
+            node_data.get_params_value("dag_id") as usize,
+            node_data.get_id(),
+        );
+        if let Some(remaining_time) = self.get_remaining_times_mut().remove(&key) {
+            node_data
+                .params
+                .insert("execution_time".to_string(), remaining_time);
+        }
+        let node_data = &node_data;
         self.get_processor_mut()
             .allocate_specific_core(core_id, node_data);
         let current_time = self.get_current_time();
@@ -164,14 +457,16 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
         log.write_job_event(
             node,
             core_id,
-            (managers[node.get_params_value("dag_id") as usize].get_release_count() - 1) as usize,
+            (get_release_count(node, managers) - 1) as usize,
             JobEventTimes::FinishTime(current_time),
         );
         let dag_id = node.get_params_value("dag_id") as usize;
         let dag = &mut dag_set[dag_id];
 
         let mut ready_nodes = Vec::new();
-        if let Some(suc_nodes) = dag.get_suc_nodes(NodeIndex::new(node.get_id() as usize)) {
+        if dag.is_multi_rate() {
+            ready_nodes = trigger_multi_rate_successors(dag, node);
+        } else if let Some(suc_nodes) = dag.get_suc_nodes(NodeIndex::new(node.get_id() as usize)) {
             for suc_node in suc_nodes {
                 if dag[suc_node].params.contains_key("pre_done_count") {
                     dag.update_param(
@@ -197,26 +492,100 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
         ready_nodes
     }
 
+    /// Nodes in the ready queue that have never been dispatched in their current job.
+    /// Preempted nodes waiting to resume have run and are not included.
+    fn get_starved_nodes(&self) -> Vec<StarvedNode> {
+        let managers = self.get_managers();
+        self.get_ready_queue()
+            .iter()
+            .map(|node_data_wrapper| &node_data_wrapper.node_data)
+            .filter(|node_data| {
+                !self.get_remaining_times().contains_key(&(
+                    node_data.get_params_value("dag_id") as usize,
+                    node_data.get_id(),
+                ))
+            })
+            .map(|node_data| StarvedNode {
+                dag_id: node_data.get_params_value("dag_id") as usize,
+                node_id: node_data.get_id() as usize,
+                job_id: (get_release_count(node_data, managers) - 1) as usize,
+            })
+            .collect()
+    }
+
     fn calculate_log(&mut self) {
         let current_time = self.get_current_time();
+        let starved_nodes = self.get_starved_nodes();
         let log = self.get_log_mut();
+        log.write_starved_nodes(starved_nodes);
+        log.set_simulated_time(current_time);
         log.calculate_utilization(current_time);
         log.calculate_response_time();
     }
 
-    fn can_preempt(
-        &self,
-        preemptive_type: &PreemptiveType,
-        ready_head_node: &NodeDataWrapper,
-    ) -> Option<usize> {
+    /// Cores of the cluster of the node, or None if the node may run on any core.
+    fn get_cluster_cores(&self, node_data: &NodeData) -> Option<&[usize]> {
+        let clusters = &self.get_config().clusters;
+        if clusters.is_empty() {
+            return None;
+        }
+        let cluster_id = *node_data.params.get("cluster")?;
+        let cluster_cores = clusters
+            .get(cluster_id as usize)
+            .unwrap_or_else(|| panic!("Cluster {} is not defined.", cluster_id));
+        Some(cluster_cores)
+    }
+
+    /// An idle core on which the node may run, respecting its cluster.
+    fn get_idle_core_index_for(&self, node_data: &NodeData) -> Option<usize> {
+        match self.get_cluster_cores(node_data) {
+            Some(cluster_cores) => cluster_cores.iter().copied().find(|&core_id| {
+                self.get_processor()
+                    .get_processing_node_and_remain_time(core_id)
+                    .is_none()
+            }),
+            None => self.get_processor().get_idle_core_index(),
+        }
+    }
+
+    /// Priority of a node that is inserted into the ready queue at `current_time`, for priorities that change over time.
+    /// It is computed for every node on insertion and again for every waiting node each tick,
+    /// so a scheduler that returns Some must return it for all nodes. The node may record the priority as a parameter
+    /// so that `PreemptiveType::Preemptive` can compare it. The default returns None, i.e., deadline order.
+    fn get_dynamic_priority(&self, _node_data: &mut NodeData, _current_time: i32) -> Option<i32> {
+        None
+    }
+
+    fn wrap_ready_node(&self, mut node_data: NodeData) -> NodeDataWrapper {
+        let dynamic_priority = self.get_dynamic_priority(&mut node_data, self.get_current_time());
+        NodeDataWrapper {
+            node_data,
+            dynamic_priority,
+        }
+    }
+
+    fn can_preempt(&self, ready_head_node: &NodeDataWrapper) -> Option<usize> {
         if let PreemptiveType::Preemptive {
             key: preemptive_key,
-        } = &preemptive_type
+        } = &self.get_config().preemptive_type
         {
-            let (max_value, core_i) = self
-                .get_processor()
-                .get_max_value_and_index(preemptive_key)
-                .unwrap();
+            let (max_value, core_i) = match self.get_cluster_cores(&ready_head_node.node_data) {
+                Some(cluster_cores) => {
+                    let processor = self.get_processor();
+                    cluster_cores
+                        .iter()
+                        .filter_map(|&core_id| {
+                            let (node_data, _) =
+                                processor.get_processing_node_and_remain_time(core_id)?;
+                            Some((*node_data.params.get(preemptive_key)?, core_id))
+                        })
+                        .max_by_key(|&(value, _)| value)?
+                }
+                None => self
+                    .get_processor()
+                    .get_max_value_and_index(preemptive_key)
+                    .unwrap(),
+            };
 
             if max_value
                 > ready_head_node
@@ -230,63 +599,154 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
         None
     }
 
-    fn schedule(&mut self, preemptive_type: PreemptiveType) -> i32 {
-        // Start scheduling
-        let mut managers = vec![DAGStateManager::default(); self.get_dag_set().len()];
-        let mut ready_queue = BTreeSet::new();
+    /// Time at which `schedule` stops: the hyper-period of the DAG set, or its override if set.
+    fn get_simulation_end_time(&self) -> i32 {
         let hyper_period = get_hyper_period(&self.get_dag_set());
-        while self.get_current_time() < hyper_period {
+        match self.get_config().hyper_period_override {
+            Some(hyper_period_override) => {
+                if hyper_period_override < hyper_period {
+                    warn!(
+                        "The simulation is truncated at {} before the hyper-period {}. The results are partial.",
+                        hyper_period_override, hyper_period
+                    );
+                }
+                hyper_period_override
+            }
+            None => hyper_period,
+        }
+    }
+
+    /// Whether every DAG other than multi-rate ones has been released once and finished (or been aborted).
+    fn is_single_instance_finished(&self) -> bool {
+        let managers = self.get_managers();
+        self.get_dag_set()
+            .iter()
+            .filter(|dag| !dag.is_multi_rate())
+            .all(|dag| {
+                managers
+                    .get(dag.get_dag_param("dag_id") as usize)
+                    .is_some_and(|manager| {
+                        manager.get_release_count() == 1
+                            && manager.get_dag_state() == DAGState::Waiting
+                    })
+            })
+    }
+
+    /// Shorthand for `schedule_with_config` that replaces only the `preemptive_type` of the current config.
+    fn schedule(&mut self, preemptive_type: PreemptiveType) -> i32 {
+        let config = SchedulerConfig {
+            preemptive_type,
+            ..self.get_config().clone()
+        };
+        self.schedule_with_config(&config)
+    }
+
+    /// Replace the config of the scheduler with `config` and run the scheduling.
+    fn schedule_with_config(&mut self, config: &SchedulerConfig) -> i32 {
+        self.set_config(config.clone());
+        let start_instant = Instant::now();
+        if self.get_config().single_instance {
+            while !self.is_single_instance_finished() {
+                let next_time = self.get_current_time() + 1;
+                self.schedule_until(next_time);
+            }
+        } else {
+            let end_time = self.get_simulation_end_time();
+            self.schedule_until(end_time);
+        }
+
+        self.calculate_log();
+        self.get_log_mut()
+            .set_wall_clock_time(start_instant.elapsed());
+        self.get_current_time()
+    }
+
+    fn get_simulation_stats(&self) -> SimulationStats {
+        self.get_log().get_simulation_stats().clone()
+    }
+
+    /// Run the scheduling loop with the current config until `end_time` without finalizing the log.
+    /// The run can be continued by calling `schedule_until` or `schedule` again.
+    fn schedule_until(&mut self, end_time: i32) {
+        if self.get_managers().is_empty() {
+            *self.get_managers_mut() = vec![DAGStateManager::default(); self.get_dag_set().len()];
+        }
+        let mut managers = std::mem::take(self.get_managers_mut());
+        let mut ready_queue = std::mem::take(self.get_ready_queue_mut());
+        while self.get_current_time() < end_time {
+            // Abort DAG instances that overran their deadline so that their cores are freed for the release below.
+            self.abort_overrunning_dags(&mut managers, &mut ready_queue);
+
             // Release DAGs
             let ready_nodes = self.release_dags(&mut managers);
             for ready_node in ready_nodes {
-                ready_queue.insert(NodeDataWrapper {
-                    node_data: ready_node,
-                });
+                ready_queue.insert(self.wrap_ready_node(ready_node));
+            }
+            // Dynamic priorities of the waiting nodes change every tick, so rebuild the ready queue.
+            if ready_queue
+                .iter()
+                .any(|node_data_wrapper| node_data_wrapper.dynamic_priority.is_some())
+            {
+                ready_queue = std::mem::take(&mut ready_queue)
+                    .into_iter()
+                    .map(|node_data_wrapper| self.wrap_ready_node(node_data_wrapper.node_data))
+                    .collect();
             }
 
             // Allocate nodes as long as there are idle cores, and attempt to preempt when all cores are busy.
-            while !ready_queue.is_empty() {
-                if let Some(idle_core_i) = self.get_processor().get_idle_core_index() {
+            // Under clustered scheduling, a node whose cluster is full waits while the other nodes are allocated.
+            let mut blocked_nodes = Vec::new();
+            while let Some(ready_head_node) = ready_queue.first() {
+                if let Some(idle_core_i) = self.get_idle_core_index_for(&ready_head_node.node_data)
+                {
                     // Allocate the node to the idle core
                     let node_data = ready_queue.pop_first().unwrap().convert_node_data();
                     self.allocate_node(
                         &node_data,
                         idle_core_i,
-                        managers[node_data.get_params_value("dag_id") as usize].get_release_count()
-                            as usize,
+                        get_release_count(&node_data, &managers) as usize,
                     );
-                } else if let Some(core_i) =
-                    self.can_preempt(&preemptive_type, ready_queue.first().unwrap())
-                {
+                } else if let Some(core_i) = self.can_preempt(ready_queue.first().unwrap()) {
                     // Preempt the node with the lowest priority
                     let current_time = self.get_current_time();
                     let processor = self.get_processor_mut();
                     // Preempted node data
-                    let preempted_node_data = processor.preempt(core_i).unwrap();
+                    let mut preempted_node_data = processor.preempt(core_i).unwrap();
                     self.get_log_mut().write_job_event(
                         &preempted_node_data,
                         core_i,
-                        (managers[preempted_node_data.get_params_value("dag_id") as usize]
-                            .get_release_count() as usize)
-                            - 1,
+                        (get_release_count(&preempted_node_data, &managers) - 1) as usize,
                         JobEventTimes::PreemptedTime(current_time),
                     );
+                    // Record the remaining time, including the cache-related preemption delay to be paid when the node resumes.
+                    let remaining_time = preempted_node_data.get_params_value("execution_time")
+                        + self.get_config().crpd;
+                    preempted_node_data
+                        .params
+                        .insert("execution_time".to_string(), remaining_time);
+                    self.get_remaining_times_mut().insert(
+                        (
+                            preempted_node_data.get_params_value("dag_id") as usize,
+                            preempted_node_data.get_id(),
+                        ),
+                        remaining_time,
+                    );
                     // Allocate the preempted node
                     let allocate_node_data = &ready_queue.pop_first().unwrap().convert_node_data();
                     self.allocate_node(
                         allocate_node_data,
                         core_i,
-                        managers[allocate_node_data.get_params_value("dag_id") as usize]
-                            .get_release_count() as usize,
+                        get_release_count(allocate_node_data, &managers) as usize,
                     );
                     // Insert the preempted node into the ready queue
-                    ready_queue.insert(NodeDataWrapper {
-                        node_data: preempted_node_data,
-                    });
+                    ready_queue.insert(self.wrap_ready_node(preempted_node_data));
+                } else if !self.get_config().clusters.is_empty() {
+                    blocked_nodes.push(ready_queue.pop_first().unwrap());
                 } else {
                     break; // No core is idle and can not preempt. Exit the loop.
                 }
             }
+            ready_queue.extend(blocked_nodes);
 
             // Process unit time
             let process_result = self.process_unit_time();
@@ -302,21 +762,74 @@ pub trait DAGSetSchedulerBase<T: ProcessorBase + Clone> {
                     let ready_nodes =
                         self.post_process_on_node_completion(node_data, core_id, &mut managers);
                     for ready_node in ready_nodes {
-                        ready_queue.insert(NodeDataWrapper {
-                            node_data: ready_node,
-                        });
+                        ready_queue.insert(self.wrap_ready_node(ready_node));
                     }
                 }
             }
         }
 
-        self.calculate_log();
-        self.get_current_time()
+        *self.get_managers_mut() = managers;
+        *self.get_ready_queue_mut() = ready_queue;
+    }
+
+    /// Fraction of the execution time of `node` that has been completed in its current job.
+    /// Returns 0.0 for a node that is neither running nor preempted.
+    fn get_completed_fraction(&self, node: &NodeData) -> f32 {
+        let key = (node.get_params_value("dag_id") as usize, node.get_id());
+        let execution_time = self.get_dag_set()[key.0][NodeIndex::new(key.1 as usize)]
+            .get_params_value("execution_time");
+        let processor = self.get_processor();
+        let remaining_time = (0..processor.get_number_of_cores())
+            .filter_map(|core_id| processor.get_processing_node_and_remain_time(core_id))
+            .find(|(node_data, _)| {
+                (
+                    node_data.get_params_value("dag_id") as usize,
+                    node_data.get_id(),
+                ) == key
+            })
+            .map(|(_, remain_time)| remain_time)
+            .or_else(|| self.get_remaining_times().get(&key).copied())
+            .unwrap_or(execution_time);
+        ((execution_time - remaining_time) as f32 / execution_time as f32).max(0.0)
+    }
+
+    /// Capture the current state of the run so that it can be inspected or resumed later.
+    fn snapshot(&self) -> SchedulerState<T> {
+        SchedulerState {
+            current_time: self.get_current_time(),
+            dag_set: self.get_dag_set(),
+            processor: self.get_processor().clone(),
+            ready_queue: self.get_ready_queue().clone(),
+            managers: self.get_managers().clone(),
+            remaining_times: self.get_remaining_times().clone(),
+            log: self.get_log().clone(),
+        }
+    }
+
+    /// Restore a state captured by `snapshot`. Subsequent scheduling resumes from it.
+    fn restore(&mut self, state: SchedulerState<T>) {
+        self.set_current_time(state.current_time);
+        self.set_dag_set(state.dag_set);
+        *self.get_processor_mut() = state.processor;
+        *self.get_ready_queue_mut() = state.ready_queue;
+        *self.get_managers_mut() = state.managers;
+        *self.get_remaining_times_mut() = state.remaining_times;
+        *self.get_log_mut() = state.log;
     }
 
     fn dump_log(&mut self, dir_path: &str, alg_name: &str) -> String {
+        self.dump_log_with_verbosity(dir_path, alg_name, LogVerbosity::Full)
+    }
+
+    fn dump_log_with_verbosity(
+        &mut self,
+        dir_path: &str,
+        alg_name: &str,
+        verbosity: LogVerbosity,
+    ) -> String {
         let file_path = create_scheduler_log_yaml(dir_path, alg_name);
-        self.get_log_mut().dump_log_to_yaml(&file_path);
+        self.get_log_mut()
+            .dump_log_to_yaml_with_verbosity(&file_path, verbosity);
 
         file_path
     }
@@ -337,6 +850,9 @@ macro_rules! getset_dag_set_scheduler {
         fn get_processor(&self) -> &$t{
             &self.processor
         }
+        fn get_log(&self) -> &DAGSetSchedulerLog{
+            &self.log
+        }
         fn get_log_mut(&mut self) -> &mut DAGSetSchedulerLog{
             &mut self.log
         }
@@ -346,5 +862,29 @@ macro_rules! getset_dag_set_scheduler {
         fn set_current_time(&mut self, current_time: i32){
             self.current_time = current_time;
         }
+        fn get_ready_queue(&self) -> &std::collections::BTreeSet<$crate::dag_set_scheduler::NodeDataWrapper>{
+            &self.ready_queue
+        }
+        fn get_ready_queue_mut(&mut self) -> &mut std::collections::BTreeSet<$crate::dag_set_scheduler::NodeDataWrapper>{
+            &mut self.ready_queue
+        }
+        fn get_managers(&self) -> &Vec<$crate::dag_set_scheduler::DAGStateManager>{
+            &self.managers
+        }
+        fn get_managers_mut(&mut self) -> &mut Vec<$crate::dag_set_scheduler::DAGStateManager>{
+            &mut self.managers
+        }
+        fn get_remaining_times(&self) -> &std::collections::BTreeMap<(usize, i32), i32>{
+            &self.remaining_times
+        }
+        fn get_remaining_times_mut(&mut self) -> &mut std::collections::BTreeMap<(usize, i32), i32>{
+            &mut self.remaining_times
+        }
+        fn get_config(&self) -> &$crate::dag_set_scheduler::SchedulerConfig{
+            &self.config
+        }
+        fn get_config_mut(&mut self) -> &mut $crate::dag_set_scheduler::SchedulerConfig{
+            &mut self.config
+        }
     }
 }
@@ -0,0 +1,195 @@
+use crate::getset_dag_scheduler;
+use crate::{
+    dag_scheduler::{DAGSchedulerBase, ExecutionTimeMode},
+    graph_extension::{GraphExtension, NodeData},
+    log::*,
+    processor::ProcessorBase,
+};
+use log::warn;
+use petgraph::Graph;
+use std::collections::{HashMap, VecDeque};
+
+/// Absolute deadline of each node, keyed by node id.
+/// A node must finish by its latest finish time shifted so that the sink nodes finish at the end-to-end deadline
+/// (or the period if it is not set) after the release time, i.e., the `offset` of the head.
+/// If neither the deadline nor the period is set, the critical path length is used as the relative deadline.
+pub fn get_node_absolute_deadlines(dag: &Graph<NodeData, i32>) -> HashMap<i32, i32> {
+    let mut dag = dag.clone();
+    dag.add_dummy_sink_node();
+    dag.calculate_latest_finish_times();
+    dag.remove_dummy_sink_node();
+    let latest_finish_times: Vec<(i32, i32)> = dag
+        .node_weights()
+        .map(|node| (node.id, node.get_params_value("latest_finish_time")))
+        .collect();
+    let critical_path_length = latest_finish_times
+        .iter()
+        .map(|&(_, latest_finish_time)| latest_finish_time)
+        .max()
+        .unwrap_or(0);
+
+    let release_time = if dag
+        .node_weights()
+        .any(|node| node.params.contains_key("offset"))
+    {
+        dag.get_head_offset()
+    } else {
+        0
+    };
+    let relative_deadline = dag
+        .get_end_to_end_deadline()
+        .or_else(|| dag.get_head_period())
+        .unwrap_or_else(|| {
+            warn!("Neither end_to_end_deadline nor period is set. The critical path length is used as the deadline.");
+            critical_path_length
+        });
+
+    latest_finish_times
+        .into_iter()
+        .map(|(id, latest_finish_time)| {
+            (
+                id,
+                release_time + relative_deadline - critical_path_length + latest_finish_time,
+            )
+        })
+        .collect()
+}
+
+/// Earliest deadline first scheduler for a single DAG.
+/// The ready queue is sorted by the absolute deadline of each node (see `get_node_absolute_deadlines`)
+/// instead of a static `priority`.
+#[derive(Clone, Default)]
+pub struct EarliestDeadlineFirstScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    dag: Graph<NodeData, i32>,
+    processor: T,
+    log: DAGSchedulerLog,
+    seed: Option<u64>,
+    dispatch_overhead: i32,
+    execution_time_mode: ExecutionTimeMode,
+    node_absolute_deadlines: HashMap<i32, i32>,
+}
+
+impl<T> DAGSchedulerBase<T> for EarliestDeadlineFirstScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    fn new(dag: &Graph<NodeData, i32>, processor: &T) -> Self {
+        Self {
+            dag: dag.clone(),
+            processor: processor.clone(),
+            log: DAGSchedulerLog::new(dag, processor.get_number_of_cores()),
+            seed: None,
+            dispatch_overhead: 0,
+            execution_time_mode: ExecutionTimeMode::default(),
+            node_absolute_deadlines: HashMap::new(),
+        }
+    }
+
+    getset_dag_scheduler!(T);
+
+    /// Earlier absolute deadline first. The dummy nodes have no deadline and come last.
+    fn sort_ready_queue(&self, ready_queue: &mut VecDeque<NodeData>) {
+        ready_queue.make_contiguous().sort_by_key(|node| {
+            *self
+                .node_absolute_deadlines
+                .get(&node.id)
+                .unwrap_or(&i32::MAX)
+        });
+    }
+
+    fn prepare_schedule(&mut self, dag: &Graph<NodeData, i32>) {
+        self.node_absolute_deadlines = get_node_absolute_deadlines(dag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixed_priority_scheduler::FixedPriorityScheduler;
+    use crate::homogeneous::HomogeneousProcessor;
+    use petgraph::graph::NodeIndex;
+
+    #[test]
+    fn test_get_node_absolute_deadlines_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 2), ("period", 20), ("offset", 5)],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(1, &[("execution_time", 5)]));
+        let n2 = dag.add_node(NodeData::with_params(2, &[("execution_time", 1)]));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+
+        let node_absolute_deadlines = get_node_absolute_deadlines(&dag);
+        // The critical path length is 7, so every node has a slack of 13 against the period.
+        assert_eq!(node_absolute_deadlines[&0], 5 + 13 + 2);
+        assert_eq!(node_absolute_deadlines[&1], 5 + 13 + 7);
+        assert_eq!(node_absolute_deadlines[&2], 5 + 13 + 7);
+    }
+
+    #[test]
+    fn test_edf_scheduler_schedule_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        //cX is the Xth critical node.
+        let c0 = dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 52), ("priority", 0), ("period", 100)],
+        ));
+        let c1 = dag.add_node(NodeData::with_params(
+            1,
+            &[("execution_time", 40), ("priority", 0)],
+        ));
+        //nY_X is the Yth suc node of cX.
+        let n0_0 = dag.add_node(NodeData::with_params(
+            2,
+            &[("execution_time", 12), ("priority", 2)],
+        ));
+        let n1_0 = dag.add_node(NodeData::with_params(
+            3,
+            &[("execution_time", 10), ("priority", 1)],
+        ));
+        let n0_1 = dag.add_node(NodeData::with_params(
+            4,
+            &[("execution_time", 20), ("priority", 3)],
+        ));
+        let c2 = dag.add_node(NodeData::with_params(
+            5,
+            &[("execution_time", 8), ("priority", 0)],
+        ));
+
+        //create critical path edges
+        dag.add_edge(c0, c1, 1);
+        dag.add_edge(c1, c2, 1);
+
+        //create non-critical path edges
+        dag.add_edge(c0, n0_0, 1);
+        dag.add_edge(c0, n1_0, 1);
+        dag.add_edge(n0_0, n0_1, 1);
+
+        let processor = HomogeneousProcessor::new(2);
+        let mut edf_scheduler = EarliestDeadlineFirstScheduler::new(&dag, &processor);
+        let (schedule_length, execution_order) = edf_scheduler.schedule();
+        let mut fixed_priority_scheduler = FixedPriorityScheduler::new(&dag, &processor);
+        let (_, fixed_priority_order) = fixed_priority_scheduler.schedule();
+
+        // n0_0 has a successor, so its deadline (80) is earlier than those of c1 (92) and n1_0 (100)
+        // despite its lowest priority.
+        assert_eq!(schedule_length, 100);
+        assert_eq!(
+            execution_order,
+            vec![
+                NodeIndex::new(0),
+                NodeIndex::new(2),
+                NodeIndex::new(1),
+                NodeIndex::new(3),
+                NodeIndex::new(4),
+                NodeIndex::new(5)
+            ]
+        );
+        assert_ne!(execution_order, fixed_priority_order);
+    }
+}
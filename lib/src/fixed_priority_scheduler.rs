@@ -1,11 +1,41 @@
+use crate::getset_dag_scheduler;
 use crate::{
-    dag_scheduler::DAGSchedulerBase, graph_extension::NodeData, log::*, processor::ProcessorBase,
+    dag_scheduler::{DAGSchedulerBase, ExecutionTimeMode},
+    graph_extension::NodeData,
+    log::*,
+    processor::ProcessorBase,
 };
 use log::warn;
 use petgraph::Graph;
 use std::collections::VecDeque;
 
-#[derive(Clone, Default)]
+/// Priority of a node without `priority`. The largest value, i.e., the lowest priority.
+pub const DEFAULT_FALLBACK_PRIORITY: i32 = i32::MAX;
+
+/// Sort the ready queue in ascending order of `priority` (a smaller value is a higher priority).
+/// Nodes without `priority` are treated as `fallback_priority`. The sort is stable.
+pub fn sort_by_priority(ready_queue: &mut VecDeque<NodeData>, fallback_priority: i32) {
+    ready_queue
+        .make_contiguous()
+        .sort_by_key(|node| *node.params.get("priority").unwrap_or(&fallback_priority));
+}
+
+/// Warn once if the DAG has nodes without `priority`, which are scheduled with `fallback_priority`.
+pub fn warn_missing_priorities(dag: &Graph<NodeData, i32>, fallback_priority: i32) {
+    let missing_node_ids: Vec<i32> = dag
+        .node_weights()
+        .filter(|node| !node.params.contains_key("priority"))
+        .map(|node| node.id)
+        .collect();
+    if !missing_node_ids.is_empty() {
+        warn!(
+            "'priority' parameter not found for nodes {:?}. They are treated as priority {}.",
+            missing_node_ids, fallback_priority
+        );
+    }
+}
+
+#[derive(Clone)]
 pub struct FixedPriorityScheduler<T>
 where
     T: ProcessorBase + Clone,
@@ -13,54 +43,80 @@ where
     dag: Graph<NodeData, i32>,
     processor: T,
     log: DAGSchedulerLog,
+    seed: Option<u64>,
+    dispatch_overhead: i32,
+    execution_time_mode: ExecutionTimeMode,
+    fallback_priority: i32,
+    communication_aware: bool,
 }
 
-impl<T> DAGSchedulerBase<T> for FixedPriorityScheduler<T>
+impl<T> Default for FixedPriorityScheduler<T>
 where
-    T: ProcessorBase + Clone,
+    T: ProcessorBase + Clone + Default,
 {
-    fn new(dag: &Graph<NodeData, i32>, processor: &T) -> Self {
+    fn default() -> Self {
         Self {
-            dag: dag.clone(),
-            processor: processor.clone(),
-            log: DAGSchedulerLog::new(dag, processor.get_number_of_cores()),
+            dag: Default::default(),
+            processor: Default::default(),
+            log: Default::default(),
+            seed: None,
+            dispatch_overhead: 0,
+            execution_time_mode: ExecutionTimeMode::default(),
+            fallback_priority: DEFAULT_FALLBACK_PRIORITY,
+            communication_aware: false,
         }
     }
+}
 
-    fn set_dag(&mut self, dag: &Graph<NodeData, i32>) {
-        self.dag = dag.clone();
+impl<T> FixedPriorityScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    /// Priority used for nodes without `priority`. `DEFAULT_FALLBACK_PRIORITY` (the lowest) by default.
+    pub fn set_fallback_priority(&mut self, fallback_priority: i32) {
+        self.fallback_priority = fallback_priority;
     }
 
-    fn set_processor(&mut self, processor: &T) {
-        self.processor = processor.clone();
+    pub fn get_fallback_priority(&self) -> i32 {
+        self.fallback_priority
     }
 
-    fn set_log(&mut self, log: DAGSchedulerLog) {
-        self.log = log;
+    /// When set, a successor on another core than its predecessor is released after the communication time
+    /// (edge weight). Off by default, i.e., a successor is released as soon as its predecessors finish.
+    pub fn set_communication_aware(&mut self, communication_aware: bool) {
+        self.communication_aware = communication_aware;
     }
+}
 
-    fn get_dag(&self) -> Graph<NodeData, i32> {
-        self.dag.clone()
+impl<T> DAGSchedulerBase<T> for FixedPriorityScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    fn new(dag: &Graph<NodeData, i32>, processor: &T) -> Self {
+        Self {
+            dag: dag.clone(),
+            processor: processor.clone(),
+            log: DAGSchedulerLog::new(dag, processor.get_number_of_cores()),
+            seed: None,
+            dispatch_overhead: 0,
+            execution_time_mode: ExecutionTimeMode::default(),
+            fallback_priority: DEFAULT_FALLBACK_PRIORITY,
+            communication_aware: false,
+        }
     }
 
-    fn get_processor(&self) -> T {
-        self.processor.clone()
+    getset_dag_scheduler!(T);
+
+    fn sort_ready_queue(&self, ready_queue: &mut VecDeque<NodeData>) {
+        sort_by_priority(ready_queue, self.fallback_priority);
     }
 
-    fn get_log(&self) -> DAGSchedulerLog {
-        self.log.clone()
+    fn prepare_schedule(&mut self, dag: &Graph<NodeData, i32>) {
+        warn_missing_priorities(dag, self.fallback_priority);
     }
 
-    fn sort_ready_queue(ready_queue: &mut VecDeque<NodeData>) {
-        ready_queue.make_contiguous().sort_by_key(|node| {
-            *node.params.get("priority").unwrap_or_else(|| {
-                warn!(
-                    "Warning: 'priority' parameter not found for node {:?}",
-                    node
-                );
-                &999 // Because sorting cannot be done well without a priority
-            })
-        });
+    fn is_communication_aware(&self) -> bool {
+        self.communication_aware
     }
 }
 
@@ -72,7 +128,7 @@ mod tests {
     use crate::graph_extension::GraphExtension;
     use crate::homogeneous::HomogeneousProcessor;
     use crate::processor::ProcessorBase;
-    use crate::util::load_yaml;
+    use crate::util::{is_valid_topological_order, load_yaml};
     use petgraph::graph::{Graph, NodeIndex};
 
     fn create_node(id: i32, key: &str, value: i32) -> NodeData {
@@ -177,6 +233,260 @@ mod tests {
         assert_eq!(result.1, vec![NodeIndex::new(0)]);
     }
 
+    #[test]
+    fn test_fixed_priority_scheduler_schedule_with_seed() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 1));
+        dag.add_param(n0, "priority", 0);
+        dag.add_param(n0, "period", 100);
+        for id in 1..7 {
+            let n = dag.add_node(create_node(id, "execution_time", 1));
+            dag.add_param(n, "priority", 1);
+            dag.add_edge(n0, n, 1);
+        }
+
+        let mut deterministic_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(1));
+        let deterministic_order = deterministic_scheduler.schedule().1;
+        assert_eq!(deterministic_scheduler.schedule().1, deterministic_order);
+
+        let mut execution_orders = Vec::new();
+        for seed in 0..10 {
+            let mut fixed_priority_scheduler =
+                FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(1));
+            fixed_priority_scheduler.set_seed(Some(seed));
+            let (schedule_length, execution_order) = fixed_priority_scheduler.schedule();
+
+            // Precedence is always respected.
+            assert_eq!(schedule_length, 7);
+            assert_eq!(execution_order[0], n0);
+            assert!(is_valid_topological_order(
+                &execution_order.iter().copied().collect::<Vec<_>>(),
+                &dag
+            ));
+            let mut sorted_order = execution_order.iter().copied().collect::<Vec<_>>();
+            sorted_order.sort();
+            assert_eq!(sorted_order, (0..7).map(NodeIndex::new).collect::<Vec<_>>());
+
+            // The same seed reproduces the same order.
+            fixed_priority_scheduler.set_seed(Some(seed));
+            assert_eq!(fixed_priority_scheduler.schedule().1, execution_order);
+
+            if !execution_orders.contains(&execution_order) {
+                execution_orders.push(execution_order);
+            }
+        }
+        assert!(execution_orders.len() > 1);
+    }
+
+    #[test]
+    fn test_fixed_priority_scheduler_schedule_with_dispatch_overhead() {
+        // A fork-join DAG of 10 small nodes on 2 cores.
+        let mut dag = Graph::<NodeData, i32>::new();
+        let source = dag.add_node(create_node(0, "execution_time", 1));
+        let sink = dag.add_node(create_node(1, "execution_time", 1));
+        dag.add_param(source, "period", 100);
+        for id in 2..10 {
+            let node_i = dag.add_node(create_node(id, "execution_time", 1));
+            dag.add_edge(source, node_i, 0);
+            dag.add_edge(node_i, sink, 0);
+        }
+        for node_i in dag.node_indices() {
+            dag.add_param(node_i, "priority", 0);
+        }
+
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        let (schedule_length, _) = fixed_priority_scheduler.schedule();
+        assert_eq!(schedule_length, 1 + 4 + 1);
+
+        fixed_priority_scheduler.set_dispatch_overhead(1);
+        let (schedule_length_with_overhead, _) = fixed_priority_scheduler.schedule();
+        // Every node takes twice as long.
+        assert_eq!(schedule_length_with_overhead, 2 * schedule_length);
+    }
+
+    #[test]
+    fn test_fixed_priority_scheduler_schedule_fallback_priority() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 1), ("priority", 0)],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(
+            1,
+            &[("execution_time", 5), ("priority", 1000)],
+        ));
+        let n2 = dag.add_node(create_node(2, "execution_time", 3));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+
+        // The node without priority is the lowest priority even against a priority above 999.
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(1));
+        let (_, execution_order) = fixed_priority_scheduler.schedule();
+        assert_eq!(execution_order, vec![n0, n1, n2]);
+
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(1));
+        fixed_priority_scheduler.set_fallback_priority(500);
+        let (_, execution_order) = fixed_priority_scheduler.schedule();
+        assert_eq!(execution_order, vec![n0, n2, n1]);
+    }
+
+    #[test]
+    fn test_fixed_priority_scheduler_schedule_execution_time_mode() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[
+                ("execution_time", 4),
+                ("bcet", 2),
+                ("wcet", 4),
+                ("priority", 0),
+            ],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(
+            1,
+            &[
+                ("execution_time", 6),
+                ("bcet", 3),
+                ("wcet", 9),
+                ("priority", 0),
+            ],
+        ));
+        let n2 = dag.add_node(NodeData::with_params(
+            2,
+            &[
+                ("execution_time", 5),
+                ("bcet", 1),
+                ("wcet", 5),
+                ("priority", 1),
+            ],
+        ));
+        // Without bcet and wcet, execution_time is used in both modes.
+        let n3 = dag.add_node(NodeData::with_params(
+            3,
+            &[("execution_time", 2), ("priority", 0)],
+        ));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n3, 0);
+
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        assert_eq!(
+            fixed_priority_scheduler.get_execution_time_mode(),
+            ExecutionTimeMode::ExecutionTime
+        );
+        // By default, execution_time is used as is even if the node has bcet and wcet.
+        let (makespan, _) = fixed_priority_scheduler.schedule();
+        assert_eq!(makespan, 4 + 6 + 2);
+
+        fixed_priority_scheduler.set_execution_time_mode(ExecutionTimeMode::Wcet);
+        let (wcet_makespan, _) = fixed_priority_scheduler.schedule();
+        assert_eq!(wcet_makespan, 4 + 9 + 2);
+
+        fixed_priority_scheduler.set_execution_time_mode(ExecutionTimeMode::Bcet);
+        let (bcet_makespan, _) = fixed_priority_scheduler.schedule();
+        assert_eq!(bcet_makespan, 2 + 3 + 2);
+        assert!(bcet_makespan <= wcet_makespan);
+    }
+
+    #[test]
+    fn test_fixed_priority_scheduler_schedule_communication_aware() {
+        // Fork-join DAG whose edges from the fork node take 5 to communicate across cores.
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 2), ("priority", 0)],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(
+            1,
+            &[("execution_time", 3), ("priority", 1)],
+        ));
+        let n2 = dag.add_node(NodeData::with_params(
+            2,
+            &[("execution_time", 3), ("priority", 2)],
+        ));
+        let n3 = dag.add_node(NodeData::with_params(
+            3,
+            &[("execution_time", 1), ("priority", 3)],
+        ));
+        dag.add_edge(n0, n1, 5);
+        dag.add_edge(n0, n2, 5);
+        dag.add_edge(n1, n3, 5);
+        dag.add_edge(n2, n3, 5);
+
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        let (schedule_length, _) = fixed_priority_scheduler.schedule();
+        assert_eq!(schedule_length, 6);
+
+        // n2 waits until 7 to run on the other core, so it runs after n1 on the same core from 5 instead.
+        // n3 then follows on the same core without waiting.
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        fixed_priority_scheduler.set_communication_aware(true);
+        let (schedule_length, execution_order) = fixed_priority_scheduler.schedule();
+        assert_eq!(schedule_length, 9);
+        assert_eq!(execution_order, VecDeque::from(vec![n0, n1, n2, n3]));
+        let job_intervals = fixed_priority_scheduler.get_log().get_job_intervals();
+        assert!(job_intervals
+            .iter()
+            .all(|job_interval| job_interval.core_id == job_intervals[0].core_id));
+    }
+
+    #[test]
+    fn test_fixed_priority_scheduler_log_peak_concurrency() {
+        // A fork-join DAG with two parallel branches.
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 2));
+        let n1 = dag.add_node(create_node(1, "execution_time", 5));
+        let n2 = dag.add_node(create_node(2, "execution_time", 3));
+        let n3 = dag.add_node(create_node(3, "execution_time", 2));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n3, 0);
+        for node_i in dag.node_indices() {
+            dag.add_param(node_i, "priority", 0);
+        }
+
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(4));
+        fixed_priority_scheduler.schedule();
+
+        assert_eq!(fixed_priority_scheduler.get_log().peak_concurrency(), 2);
+    }
+
+    #[test]
+    fn test_fixed_priority_scheduler_schedule_empty_dag() {
+        let dag = Graph::<NodeData, i32>::new();
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        let (schedule_length, execution_order) = fixed_priority_scheduler.schedule();
+
+        assert_eq!(schedule_length, 0);
+        assert!(execution_order.is_empty());
+
+        let file_path = fixed_priority_scheduler.dump_log("tests", "empty_dag");
+        let yaml_docs = load_yaml(&file_path);
+        let yaml_doc = &yaml_docs[0];
+        assert_eq!(
+            yaml_doc["processor_log"]["average_utilization"]
+                .as_f64()
+                .unwrap(),
+            0.0
+        );
+        let log_content = std::fs::read_to_string(&file_path).unwrap();
+        assert!(!log_content.contains(".nan"));
+        assert!(!log_content.contains(".inf"));
+
+        remove_file(file_path).unwrap();
+    }
+
     #[test]
     fn test_fixed_priority_scheduler_log_normal() {
         let mut dag = Graph::<NodeData, i32>::new();
@@ -222,6 +532,16 @@ mod tests {
             0
         );
         assert_eq!(yaml_doc["dag_info"]["volume"].as_i64().unwrap(), 114);
+        assert_eq!(
+            yaml_doc["dag_info"]["communication_volume"]
+                .as_i64()
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            yaml_doc["dag_info"]["bottleneck_node_id"].as_i64().unwrap(),
+            0
+        );
         assert_eq!(
             yaml_doc["dag_info"]["utilization"].as_f64().unwrap(),
             0.877193
@@ -1,33 +1,44 @@
-use crate::dag_set_scheduler::{DAGSetSchedulerBase, NodeDataWrapper};
+use crate::dag_set_scheduler::{
+    DAGSetSchedulerBase, DAGStateManager, NodeDataWrapper, SchedulerConfig,
+};
 use crate::getset_dag_set_scheduler;
 use crate::{
     graph_extension::NodeData, homogeneous::HomogeneousProcessor, log::DAGSetSchedulerLog,
     processor::ProcessorBase,
 };
 use petgraph::graph::Graph;
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for NodeDataWrapper {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        // Compare by absolute_deadline or int_scaled_absolute_deadline.
-        let mut comparison_metric = "node_absolute_deadline";
-        if self
-            .node_data
-            .params
-            .contains_key("int_scaled_node_absolute_deadline")
-            && other
+        // Compare by dynamic_priority, absolute_deadline or int_scaled_absolute_deadline.
+        let ordering = if let (Some(self_priority), Some(other_priority)) =
+            (self.dynamic_priority, other.dynamic_priority)
+        {
+            self_priority.cmp(&other_priority) // e.g., least-laxity-first
+        } else {
+            let mut comparison_metric = "node_absolute_deadline";
+            if self
                 .node_data
                 .params
                 .contains_key("int_scaled_node_absolute_deadline")
-        {
-            comparison_metric = "int_scaled_node_absolute_deadline"; // decomposition-based algorithm
-        }
-
-        match self
-            .node_data
-            .get_params_value(comparison_metric)
-            .cmp(&other.node_data.get_params_value(comparison_metric))
-        {
+                && other
+                    .node_data
+                    .params
+                    .contains_key("int_scaled_node_absolute_deadline")
+            {
+                comparison_metric = "int_scaled_node_absolute_deadline"; // decomposition-based algorithm
+            }
+            self.node_data
+                .get_params_value(comparison_metric)
+                .cmp(&other.node_data.get_params_value(comparison_metric))
+        };
+
+        match ordering {
             // If the keys are equal, compare by id
             Ordering::Equal => match self.node_data.id.partial_cmp(&other.node_data.id) {
                 // If the ids are also equal, compare by dag_id
@@ -48,6 +59,10 @@ pub struct GlobalEDFScheduler {
     processor: HomogeneousProcessor,
     log: DAGSetSchedulerLog,
     current_time: i32,
+    ready_queue: BTreeSet<NodeDataWrapper>,
+    managers: Vec<DAGStateManager>,
+    remaining_times: BTreeMap<(usize, i32), i32>,
+    config: SchedulerConfig,
 }
 
 impl DAGSetSchedulerBase<HomogeneousProcessor> for GlobalEDFScheduler {
@@ -57,6 +72,10 @@ impl DAGSetSchedulerBase<HomogeneousProcessor> for GlobalEDFScheduler {
             processor: processor.clone(),
             log: DAGSetSchedulerLog::new(dag_set, processor.get_number_of_cores()),
             current_time: 0,
+            ready_queue: BTreeSet::new(),
+            managers: Vec::new(),
+            remaining_times: BTreeMap::new(),
+            config: SchedulerConfig::default(),
         }
     }
 
@@ -64,10 +83,13 @@ impl DAGSetSchedulerBase<HomogeneousProcessor> for GlobalEDFScheduler {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use crate::dag_set_scheduler::{DeadlineMissPolicy, PreemptiveType};
     use crate::graph_extension::GraphExtension;
-    use crate::{dag_set_scheduler::PreemptiveType, util::load_yaml};
+    use crate::log::{DeadlineMissCause, StarvedNode};
+    use crate::util::{expected_job_count, get_hyper_period, load_yaml};
+    use petgraph::graph::NodeIndex;
     use std::{collections::BTreeMap, fs::remove_file};
 
     fn create_node(id: i32, key: &str, value: i32) -> NodeData {
@@ -148,6 +170,74 @@ mod tests {
         dag
     }
 
+    pub(crate) fn create_single_node_dag(
+        execution_time: i32,
+        period: i32,
+        end_to_end_deadline: i32,
+    ) -> Graph<NodeData, i32> {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", execution_time));
+        dag.add_param(n0, "period", period);
+        dag.add_param(n0, "end_to_end_deadline", end_to_end_deadline);
+
+        dag
+    }
+
+    fn get_first_finish_time_with_crpd(crpd: i32) -> i64 {
+        let mut long_dag = create_single_node_dag(40, 100, 100);
+        let mut short_dag = create_single_node_dag(5, 20, 20);
+        long_dag.set_dag_param("dag_id", 0);
+        short_dag.set_dag_param("dag_id", 1);
+        let dag_set = vec![long_dag, short_dag];
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        global_edf_scheduler.schedule_with_config(
+            &SchedulerConfig::default()
+                .preemptive_type(PreemptiveType::Preemptive {
+                    key: "node_absolute_deadline".to_string(),
+                })
+                .crpd(crpd),
+        );
+
+        let file_path = global_edf_scheduler.dump_log("../lib/tests", "edf_crpd_test");
+        let yaml_docs = load_yaml(&file_path);
+        remove_file(file_path).unwrap();
+        yaml_docs[0]["dag_set_log"][0]["finish_time"][0]
+            .as_i64()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_global_edf_preemptive_crpd() {
+        // The long DAG is preempted twice (at 20 and 40) by the short DAG.
+        let finish_time_without_crpd = get_first_finish_time_with_crpd(0);
+        let finish_time_with_crpd = get_first_finish_time_with_crpd(2);
+
+        assert_eq!(finish_time_without_crpd, 55);
+        assert_eq!(finish_time_with_crpd, finish_time_without_crpd + 2 * 2);
+    }
+
+    #[test]
+    fn test_global_edf_preemptive_preemption_count() {
+        let mut long_dag = create_single_node_dag(40, 100, 100);
+        let mut short_dag = create_single_node_dag(5, 20, 20);
+        long_dag.set_dag_param("dag_id", 0);
+        short_dag.set_dag_param("dag_id", 1);
+        let dag_set = vec![long_dag, short_dag];
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        global_edf_scheduler.schedule(PreemptiveType::Preemptive {
+            key: "node_absolute_deadline".to_string(),
+        });
+
+        // The long DAG is preempted at 20 and 40 by the short DAG.
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.total_preemptions(), 2);
+        assert_eq!(log.get_preemptions_per_dag(), vec![2, 0]);
+    }
+
     #[test]
     fn test_global_edf_normal() {
         let mut dag = create_sample_dag();
@@ -316,4 +406,495 @@ mod tests {
 
         remove_file(file_path).unwrap();
     }
+
+    #[test]
+    fn test_global_edf_snapshot_and_restore() {
+        let mut dag = create_sample_dag();
+        let mut dag3 = create_sample_dag3();
+        dag.set_dag_param("dag_id", 0);
+        dag3.set_dag_param("dag_id", 1);
+        let dag_set = vec![dag, dag3];
+        let processor = HomogeneousProcessor::new(2);
+        let preemptive_type = || PreemptiveType::Preemptive {
+            key: "node_absolute_deadline".to_string(),
+        };
+
+        let mut uninterrupted_scheduler = GlobalEDFScheduler::new(&dag_set, &processor);
+        let uninterrupted_time = uninterrupted_scheduler.schedule(preemptive_type());
+
+        // Pause at the midpoint of the hyper-period (150) and resume on a new scheduler.
+        let mut paused_scheduler = GlobalEDFScheduler::new(&dag_set, &processor);
+        paused_scheduler.get_config_mut().preemptive_type = preemptive_type();
+        paused_scheduler.schedule_until(75);
+        let state = paused_scheduler.snapshot();
+        assert_eq!(state.current_time, 75);
+
+        let mut resumed_scheduler = GlobalEDFScheduler::new(&dag_set, &processor);
+        resumed_scheduler.restore(state);
+        let resumed_time = resumed_scheduler.schedule(preemptive_type());
+
+        assert_eq!(resumed_time, uninterrupted_time);
+        assert_eq!(
+            serde_yaml::to_string(resumed_scheduler.get_log()).unwrap(),
+            serde_yaml::to_string(uninterrupted_scheduler.get_log()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_global_edf_resume_with_remaining_time() {
+        let mut long_dag = create_single_node_dag(10, 100, 100);
+        let mut short_dag = create_single_node_dag(3, 20, 20);
+        long_dag.set_dag_param("dag_id", 0);
+        short_dag.set_dag_param("dag_id", 1);
+        short_dag.add_param(NodeIndex::new(0), "offset", 4);
+        let dag_set = vec![long_dag, short_dag];
+        let preemptive_type = PreemptiveType::Preemptive {
+            key: "node_absolute_deadline".to_string(),
+        };
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        let long_node = global_edf_scheduler.get_dag_set()[0][NodeIndex::new(0)].clone();
+        // The long node is preempted at 4 by the short node, which runs until 7.
+        global_edf_scheduler.get_config_mut().preemptive_type = preemptive_type.clone();
+        global_edf_scheduler.schedule_until(5);
+        assert_eq!(global_edf_scheduler.get_completed_fraction(&long_node), 0.4);
+        global_edf_scheduler.schedule_until(9);
+        assert_eq!(global_edf_scheduler.get_completed_fraction(&long_node), 0.6);
+        global_edf_scheduler.schedule(preemptive_type);
+
+        let job_intervals: Vec<_> = global_edf_scheduler
+            .get_log()
+            .get_job_intervals()
+            .into_iter()
+            .filter(|job_interval| job_interval.dag_id == 0 && job_interval.job_id == 0)
+            .map(|job_interval| (job_interval.start_time, job_interval.end_time))
+            .collect();
+        assert_eq!(job_intervals, vec![(0, 4), (7, 13)]);
+    }
+
+    #[test]
+    fn test_global_edf_released_job_count() {
+        let mut dag = create_sample_dag();
+        let mut dag2 = create_sample_dag2();
+        dag.set_dag_param("dag_id", 0);
+        dag2.set_dag_param("dag_id", 1);
+        let dag_set = vec![dag, dag2];
+        let hyper_period = get_hyper_period(&dag_set);
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(4));
+        global_edf_scheduler.schedule(PreemptiveType::NonPreemptive);
+
+        let log = global_edf_scheduler.get_log();
+        for (dag_id, dag) in dag_set.iter().enumerate() {
+            assert_eq!(
+                log.get_released_job_count(dag_id),
+                expected_job_count(dag, hyper_period)
+            );
+        }
+        assert_eq!(log.get_released_job_count(1), 3);
+    }
+
+    #[test]
+    fn test_global_edf_multi_rate_release() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 2));
+        let n1 = dag.add_node(create_node(1, "execution_time", 3));
+        let n2 = dag.add_node(create_node(2, "execution_time", 1));
+        dag.add_param(n0, "period", 10);
+        dag.add_param(n1, "period", 20);
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n1, n2, 1);
+        dag.set_dag_param("dag_id", 0);
+        dag.set_dag_param("multi_rate", 1);
+        let dag_set = vec![dag];
+        assert_eq!(get_hyper_period(&dag_set), 20);
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(2));
+        global_edf_scheduler.schedule(PreemptiveType::NonPreemptive);
+
+        let job_intervals = global_edf_scheduler.get_log().get_job_intervals();
+        let get_release_times = |node_id: usize| -> Vec<i32> {
+            job_intervals
+                .iter()
+                .filter(|job_interval| job_interval.node_id == node_id)
+                .map(|job_interval| job_interval.start_time)
+                .collect()
+        };
+        // n0 and n1 are released by their own periods, and n2 is triggered by n1.
+        assert_eq!(get_release_times(0), vec![0, 10]);
+        assert_eq!(get_release_times(1), vec![0]);
+        assert_eq!(get_release_times(2), vec![3]);
+        assert_eq!(
+            job_intervals
+                .iter()
+                .filter(|job_interval| job_interval.node_id == 0)
+                .map(|job_interval| job_interval.job_id)
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn test_global_edf_simulation_stats() {
+        let mut dag = create_sample_dag();
+        let mut dag2 = create_sample_dag2();
+        dag.set_dag_param("dag_id", 0);
+        dag2.set_dag_param("dag_id", 1);
+        let dag_set = vec![dag, dag2];
+        let hyper_period = get_hyper_period(&dag_set);
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(4));
+        global_edf_scheduler.schedule(PreemptiveType::NonPreemptive);
+
+        let simulation_stats = global_edf_scheduler.get_simulation_stats();
+        assert_eq!(simulation_stats.simulated_time, hyper_period);
+        assert_eq!(simulation_stats.ticks, hyper_period);
+        // Every job of the DAG set is started and finished without preemption.
+        assert_eq!(
+            simulation_stats.events as usize,
+            global_edf_scheduler.get_log().get_job_intervals().len() * 2
+        );
+        assert!(simulation_stats.wall_clock_time.is_some());
+    }
+
+    #[test]
+    fn test_global_edf_hyper_period_override() {
+        let mut dag = create_sample_dag();
+        let mut dag2 = create_sample_dag2();
+        dag.set_dag_param("dag_id", 0);
+        dag2.set_dag_param("dag_id", 1);
+        let dag_set = vec![dag, dag2];
+        assert_eq!(get_hyper_period(&dag_set), 300);
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(4));
+        let end_time = global_edf_scheduler
+            .schedule_with_config(&SchedulerConfig::default().hyper_period_override(Some(120)));
+
+        assert_eq!(end_time, 120);
+        assert_eq!(global_edf_scheduler.get_simulation_stats().ticks, 120);
+        let log = global_edf_scheduler.get_log();
+        // Only the jobs released before 120 appear.
+        assert_eq!(log.get_released_job_count(0), 1);
+        assert_eq!(log.get_released_job_count(1), 2);
+        assert!(log
+            .get_job_intervals()
+            .iter()
+            .all(|job_interval| job_interval.end_time <= 120));
+    }
+
+    #[test]
+    fn test_global_edf_min_separation() {
+        let schedule_with_separation = |min_separation: Option<i32>| {
+            let mut dag = create_single_node_dag(3, 10, 10);
+            dag.set_dag_param("dag_id", 0);
+            if let Some(min_separation) = min_separation {
+                dag.set_dag_param("min_separation", min_separation);
+            }
+            // DAG 1 has the earlier deadline and delays the first job of DAG 0.
+            let mut early_deadline_dag = create_single_node_dag(6, 20, 7);
+            early_deadline_dag.set_dag_param("dag_id", 1);
+            let dag_set = vec![dag, early_deadline_dag];
+            let mut global_edf_scheduler =
+                GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+            global_edf_scheduler
+                .schedule_with_config(&SchedulerConfig::default().hyper_period_override(Some(40)));
+            global_edf_scheduler
+        };
+
+        // Without the constraint, the first two jobs finish at 9 and 13.
+        let global_edf_scheduler = schedule_with_separation(None);
+        assert_eq!(
+            global_edf_scheduler.get_log().get_finish_times(0)[..2],
+            [9, 13]
+        );
+        assert!(global_edf_scheduler
+            .get_log()
+            .get_separation_delays(0)
+            .is_empty());
+
+        let global_edf_scheduler = schedule_with_separation(Some(8));
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.get_finish_times(0)[..3], [9, 20, 31]);
+        for finish_times in log.get_finish_times(0)[..3].windows(2) {
+            assert!(finish_times[1] - finish_times[0] >= 8);
+        }
+        // Released at 0, 17, 28 and 39 instead of 0, 10, 20 and 30.
+        assert_eq!(log.get_separation_delays(0), &[0, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_global_edf_deadline_miss_policy_abort() {
+        let schedule_with_policy = |deadline_miss_policy: DeadlineMissPolicy| {
+            // The overrunning DAG has the earlier deadline and runs first. The other DAG waits for the core.
+            let mut overrunning_dag = create_single_node_dag(50, 100, 30);
+            let mut waiting_dag = create_single_node_dag(5, 100, 40);
+            overrunning_dag.set_dag_param("dag_id", 0);
+            waiting_dag.set_dag_param("dag_id", 1);
+            let dag_set = vec![overrunning_dag, waiting_dag];
+
+            let mut global_edf_scheduler =
+                GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+            global_edf_scheduler.schedule_with_config(
+                &SchedulerConfig::default().deadline_miss_policy(deadline_miss_policy),
+            );
+            (global_edf_scheduler, dag_set)
+        };
+
+        let (global_edf_scheduler, dag_set) = schedule_with_policy(DeadlineMissPolicy::Continue);
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.get_finish_times(0), &[50]);
+        assert_eq!(log.get_finish_times(1)[0], 55);
+        assert_eq!(log.get_all_deadline_misses(&dag_set).len(), 2);
+
+        let (global_edf_scheduler, dag_set) = schedule_with_policy(DeadlineMissPolicy::Abort);
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.get_aborted_job_ids(0), &[0]);
+        assert!(log.get_aborted_job_ids(1).is_empty());
+        // The waiting DAG starts as soon as the overrunning DAG is aborted.
+        assert_eq!(log.get_finish_times(1)[0], 35);
+        let deadline_misses = log.get_all_deadline_misses(&dag_set);
+        assert_eq!(deadline_misses.len(), 1);
+        assert_eq!(deadline_misses[0].dag_id, 0);
+        assert_eq!(deadline_misses[0].finish_time, None);
+        let overrunning_intervals: Vec<(i32, i32)> = log
+            .get_job_intervals()
+            .iter()
+            .filter(|job_interval| job_interval.dag_id == 0)
+            .map(|job_interval| (job_interval.start_time, job_interval.end_time))
+            .collect();
+        assert_eq!(overrunning_intervals, vec![(0, 30)]);
+    }
+
+    #[test]
+    fn test_global_edf_response_time_excludes_aborted_instances() {
+        // Every instance overruns its deadline of 30 and is aborted.
+        let mut overrunning_dag = create_single_node_dag(50, 100, 30);
+        overrunning_dag.set_dag_param("dag_id", 0);
+        let dag_set = vec![overrunning_dag];
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        global_edf_scheduler.schedule_with_config(
+            &SchedulerConfig::default()
+                .deadline_miss_policy(DeadlineMissPolicy::Abort)
+                .hyper_period_override(Some(300)),
+        );
+        assert_eq!(
+            global_edf_scheduler.get_log().get_aborted_job_ids(0),
+            &[0, 1, 2]
+        );
+
+        let file_path = global_edf_scheduler.dump_log("../lib/tests", "edf_abort_response_test");
+        let yaml_docs = load_yaml(&file_path);
+        remove_file(file_path).unwrap();
+        let dag_log = &yaml_docs[0]["dag_set_log"][0];
+        assert_eq!(dag_log["average_response_time"].as_f64().unwrap(), 0.0);
+        assert_eq!(dag_log["worst_response_time"].as_i64().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_global_edf_single_instance() {
+        let mut short_dag = create_single_node_dag(3, 10, 10);
+        // The response time exceeds the hyper-period of 10.
+        let mut long_dag = create_single_node_dag(15, 10, 20);
+        short_dag.set_dag_param("dag_id", 0);
+        long_dag.set_dag_param("dag_id", 1);
+        let dag_set = vec![short_dag, long_dag];
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        let time = global_edf_scheduler
+            .schedule_with_config(&SchedulerConfig::default().single_instance(true));
+
+        assert_eq!(time, 18);
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.get_release_times(0), &[0]);
+        assert_eq!(log.get_release_times(1), &[0]);
+        assert_eq!(log.get_response_times(0), &[3]);
+        assert_eq!(log.get_response_times(1), &[18]);
+    }
+
+    #[test]
+    fn test_global_edf_constrained_deadline() {
+        let mut constrained_dag = create_single_node_dag(17, 20, 15);
+        constrained_dag.set_dag_param("dag_id", 0);
+        // Makes the hyper-period 40, so that the constrained DAG is released twice.
+        let mut other_dag = create_single_node_dag(5, 40, 40);
+        other_dag.set_dag_param("dag_id", 1);
+        let dag_set = vec![constrained_dag, other_dag];
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(2));
+        global_edf_scheduler
+            .schedule_with_config(&SchedulerConfig::default().constrained_deadline(true));
+
+        // The first instance finishes at 17, within the period of 20 but after the deadline of 15.
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.get_release_times(0), &[0, 20]);
+        assert_eq!(log.get_finish_times(0), &[17, 37]);
+        let deadline_misses = log.get_all_deadline_misses(&dag_set);
+        assert_eq!(deadline_misses.len(), 2);
+        assert_eq!(
+            (deadline_misses[0].deadline, deadline_misses[0].overrun),
+            (15, Some(2))
+        );
+        assert_eq!(
+            (deadline_misses[1].deadline, deadline_misses[1].overrun),
+            (35, Some(2))
+        );
+        // The absolute deadline of the second instance is counted from its release at 20.
+        assert_eq!(
+            global_edf_scheduler.get_dag_set()[0][NodeIndex::new(0)]
+                .get_params_value("node_absolute_deadline"),
+            35
+        );
+    }
+
+    #[test]
+    fn test_global_edf_schedule_with_config() {
+        let mut constrained_dag = create_single_node_dag(17, 20, 15);
+        constrained_dag.set_dag_param("dag_id", 0);
+        let mut other_dag = create_single_node_dag(5, 40, 40);
+        other_dag.set_dag_param("dag_id", 1);
+        let dag_set = vec![constrained_dag, other_dag];
+
+        let config = SchedulerConfig::default()
+            .preemptive_type(PreemptiveType::Preemptive {
+                key: "node_absolute_deadline".to_string(),
+            })
+            .hyper_period_override(Some(20))
+            .deadline_miss_policy(DeadlineMissPolicy::Abort)
+            .constrained_deadline(true);
+        assert_ne!(config, SchedulerConfig::default());
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(2));
+        let current_time = global_edf_scheduler.schedule_with_config(&config);
+
+        assert_eq!(current_time, 20);
+        assert_eq!(global_edf_scheduler.get_config(), &config);
+        assert_eq!(
+            config
+                .clone()
+                .hyper_period_override(None)
+                .hyper_period_override,
+            None
+        );
+        // The instance is aborted at its constrained deadline of 15 instead of finishing at 17.
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.get_release_times(0), &[0]);
+        assert_eq!(log.get_aborted_job_ids(0), &[0]);
+        assert_eq!(log.get_finish_times(1), &[5]);
+    }
+
+    #[test]
+    fn test_global_edf_clustered() {
+        // DAGs 0 and 1 are pinned to cluster 0 (core 0), and DAG 2 to cluster 1 (cores 1 and 2).
+        let dag_set: Vec<_> = [(0, 5), (0, 5), (1, 3)]
+            .iter()
+            .enumerate()
+            .map(|(dag_id, &(cluster, execution_time))| {
+                let mut dag = create_single_node_dag(execution_time, 20, 20);
+                dag.set_dag_param("dag_id", dag_id as i32);
+                dag.set_dag_param("cluster", cluster);
+                dag
+            })
+            .collect();
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(3));
+        global_edf_scheduler
+            .schedule_with_config(&SchedulerConfig::default().clusters(vec![vec![0], vec![1, 2]]));
+
+        let job_intervals = global_edf_scheduler.get_log().get_job_intervals();
+        assert_eq!(job_intervals.len(), 3);
+        for job_interval in job_intervals.iter() {
+            let expected_cores = if job_interval.dag_id == 2 {
+                vec![1, 2]
+            } else {
+                vec![0]
+            };
+            assert!(expected_cores.contains(&job_interval.core_id));
+        }
+        // DAG 2 is not blocked by the DAG waiting for cluster 0.
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(log.get_finish_times(2), &[3]);
+        let mut cluster0_finish_times = [log.get_finish_times(0)[0], log.get_finish_times(1)[0]];
+        cluster0_finish_times.sort();
+        assert_eq!(cluster0_finish_times, [5, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Core 0 belongs to both cluster 0 and cluster 1.")]
+    fn test_global_edf_clusters_overlap() {
+        let mut global_edf_scheduler = GlobalEDFScheduler::new(
+            &[create_single_node_dag(5, 20, 20)],
+            &HomogeneousProcessor::new(2),
+        );
+        global_edf_scheduler
+            .set_config(SchedulerConfig::default().clusters(vec![vec![0, 1], vec![0]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Core 2 of cluster 1 is out of range. The number of cores is 2.")]
+    fn test_global_edf_clusters_out_of_range() {
+        let mut global_edf_scheduler = GlobalEDFScheduler::new(
+            &[create_single_node_dag(5, 20, 20)],
+            &HomogeneousProcessor::new(2),
+        );
+        global_edf_scheduler
+            .set_config(SchedulerConfig::default().clusters(vec![vec![0], vec![1, 2]]));
+    }
+
+    #[test]
+    fn test_global_edf_starved_node() {
+        // Over-subscribed on one core: DAG 0 always has an earlier deadline than DAG 1.
+        let mut busy_dag = create_single_node_dag(10, 10, 10);
+        let mut starving_dag = create_single_node_dag(5, 100, 100);
+        busy_dag.set_dag_param("dag_id", 0);
+        starving_dag.set_dag_param("dag_id", 1);
+        let dag_set = vec![busy_dag, starving_dag];
+
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        global_edf_scheduler.schedule(PreemptiveType::NonPreemptive);
+
+        let log = global_edf_scheduler.get_log();
+        assert_eq!(
+            log.get_starved_nodes(),
+            &[StarvedNode {
+                dag_id: 1,
+                node_id: 0,
+                job_id: 0,
+            }]
+        );
+        let deadline_misses = log.get_all_deadline_misses(&dag_set);
+        assert_eq!(deadline_misses.len(), 1);
+        assert_eq!(
+            log.get_deadline_miss_cause(&deadline_misses[0]),
+            DeadlineMissCause::Starved
+        );
+
+        // A node still running at the end of the simulation ran late, not starved.
+        let mut long_dag = create_single_node_dag(12, 10, 10);
+        long_dag.set_dag_param("dag_id", 0);
+        let dag_set = vec![long_dag];
+        let mut global_edf_scheduler =
+            GlobalEDFScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        global_edf_scheduler.schedule(PreemptiveType::NonPreemptive);
+
+        let log = global_edf_scheduler.get_log();
+        assert!(log.get_starved_nodes().is_empty());
+        let deadline_misses = log.get_all_deadline_misses(&dag_set);
+        assert_eq!(deadline_misses.len(), 1);
+        assert_eq!(
+            log.get_deadline_miss_cause(&deadline_misses[0]),
+            DeadlineMissCause::RanLate
+        );
+    }
 }
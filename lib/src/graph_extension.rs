@@ -1,15 +1,22 @@
 use log::warn;
 use petgraph::{
     algo::toposort,
-    graph::{Graph, NodeIndex},
+    graph::{EdgeIndex, Graph, NodeIndex},
     visit::EdgeRef,
     Direction::{Incoming, Outgoing},
 };
+use std::cell::OnceCell;
 use std::cmp::Ord;
-use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::collections::{hash_map::DefaultHasher, BTreeMap, HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
 
 const DUMMY_SOURCE_NODE_FLAG: i32 = -1;
 const DUMMY_SINK_NODE_FLAG: i32 = -2;
+/// Scale of `int_scaled_node_relative_deadline`, which holds a fractional deadline as an integer.
+pub const INT_SCALED_DEADLINE_FACTOR: i32 = 100000;
+/// Values of the `kind` parameter. A node without `kind` is a compute node.
+pub const NODE_KIND_COMPUTE: i32 = 0;
+pub const NODE_KIND_IO: i32 = 1;
 
 /// custom node data structure for dag nodes (petgraph)
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -23,10 +30,35 @@ impl NodeData {
         NodeData { id, params }
     }
 
+    /// Create a node from a list of key/value pairs in one call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lib::graph_extension::NodeData;
+    ///
+    /// let node = NodeData::with_params(0, &[("execution_time", 10), ("period", 100)]);
+    /// assert_eq!(node.get_params_value("period"), 100);
+    /// ```
+    pub fn with_params(id: i32, params: &[(&str, i32)]) -> NodeData {
+        NodeData {
+            id,
+            params: params
+                .iter()
+                .map(|(key, value)| (key.to_string(), *value))
+                .collect(),
+        }
+    }
+
     pub fn get_id(&self) -> i32 {
         self.id
     }
 
+    /// Whether the node is an I/O node (`kind` is `NODE_KIND_IO`).
+    pub fn is_io(&self) -> bool {
+        self.params.get("kind") == Some(&NODE_KIND_IO)
+    }
+
     pub fn get_params_value(&self, key: &str) -> i32 {
         *self
             .params
@@ -35,6 +67,23 @@ impl NodeData {
     }
 }
 
+/// custom edge data structure holding the parameters of a link other than the communication time
+/// `communication_time` is the same value as the edge weight of the dag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdgeData {
+    pub communication_time: i32,
+    pub params: BTreeMap<String, i32>,
+}
+
+impl EdgeData {
+    pub fn new(communication_time: i32, params: BTreeMap<String, i32>) -> EdgeData {
+        EdgeData {
+            communication_time,
+            params,
+        }
+    }
+}
+
 pub trait GraphExtension {
     fn add_param(&mut self, node_i: NodeIndex, key: &str, value: i32);
     fn update_param(&mut self, node_i: NodeIndex, key: &str, value: i32);
@@ -43,18 +92,36 @@ pub trait GraphExtension {
     fn remove_dummy_source_node(&mut self);
     fn remove_dummy_sink_node(&mut self);
     fn remove_nodes(&mut self, node_indices: &[NodeIndex]);
+    fn prune_beyond_deadline(&mut self, deadline: i32) -> Vec<NodeData>;
+    fn transitive_reduction(&mut self);
     fn calculate_earliest_start_times(&mut self);
     fn calculate_earliest_finish_times(&mut self);
     fn calculate_latest_start_times(&mut self);
     fn calculate_latest_finish_times(&mut self);
     fn get_critical_path(&mut self) -> Vec<NodeIndex>;
+    fn get_critical_paths(&mut self) -> Vec<Vec<NodeIndex>>;
+    fn get_critical_path_with_comm(&self) -> Vec<NodeIndex>;
+    fn critical_path_common_prefix(&mut self) -> Vec<NodeIndex>;
+    fn get_zero_slack_chains(&mut self) -> Vec<Vec<NodeIndex>>;
+    fn find_infeasible_node_deadlines(&mut self) -> Vec<NodeIndex>;
+    fn bottleneck_node(&mut self) -> NodeIndex;
+    fn get_bottleneck_contribution(&mut self) -> f32;
+    fn get_density(&mut self) -> f32;
     fn get_non_critical_nodes(&self, critical_path: &[NodeIndex]) -> Option<Vec<NodeIndex>>;
     fn get_source_nodes(&self) -> Vec<NodeIndex>;
     fn get_sink_nodes(&self) -> Vec<NodeIndex>;
+    fn get_topological_order(&self) -> Vec<NodeIndex>;
+    fn build_id_index(&self) -> HashMap<i32, NodeIndex>;
+    fn validate_required_params(&self, required: &[&str]) -> Result<(), Vec<(i32, String)>>;
+    fn dag_fingerprint(&self) -> u64;
     fn get_volume(&self) -> i32;
+    fn get_communication_volume(&self) -> i32;
+    fn sequential_makespan(&self) -> i32;
+    fn sequential_makespan_with_comm(&self) -> i32;
     fn get_total_wcet_from_nodes(&self, nodes: &[NodeIndex]) -> i32;
     fn get_end_to_end_deadline(&self) -> Option<i32>;
     fn get_head_period(&self) -> Option<i32>;
+    fn set_period_for_utilization(&mut self, target_util: f32);
     fn get_all_periods(&self) -> Option<HashMap<NodeIndex, i32>>;
     fn get_head_offset(&self) -> i32;
     fn get_pre_nodes(&self, node_i: NodeIndex) -> Option<Vec<NodeIndex>>;
@@ -64,8 +131,10 @@ pub trait GraphExtension {
     fn get_parallel_process_nodes(&self, node_i: NodeIndex) -> Option<Vec<NodeIndex>>;
     fn get_dag_param(&self, key: &str) -> i32;
     fn set_dag_param(&mut self, key: &str, value: i32);
+    fn is_multi_rate(&self) -> bool;
     fn add_node_with_id_consistency(&mut self, node: NodeData) -> NodeIndex;
     fn is_node_ready(&self, node_i: NodeIndex) -> bool;
+    fn expand_sub_dags(&mut self, registry: &HashMap<String, Graph<NodeData, i32>>);
 }
 
 impl GraphExtension for Graph<NodeData, i32> {
@@ -92,7 +161,7 @@ impl GraphExtension for Graph<NodeData, i32> {
             self[i]
                 .params
                 .get("dummy")
-                .map_or(false, |&v| v == DUMMY_SOURCE_NODE_FLAG)
+                .is_some_and(|&v| v == DUMMY_SOURCE_NODE_FLAG)
         }) {
             panic!(
                 "The dummy source node has already been added. NodeIndex: {:?}",
@@ -118,7 +187,7 @@ impl GraphExtension for Graph<NodeData, i32> {
             self[i]
                 .params
                 .get("dummy")
-                .map_or(false, |&v| v == DUMMY_SINK_NODE_FLAG)
+                .is_some_and(|&v| v == DUMMY_SINK_NODE_FLAG)
         }) {
             panic!(
                 "The dummy sink node has already been added. NodeIndex: {:?}",
@@ -144,7 +213,7 @@ impl GraphExtension for Graph<NodeData, i32> {
             self[i]
                 .params
                 .get("dummy")
-                .map_or(false, |&v| v == DUMMY_SOURCE_NODE_FLAG)
+                .is_some_and(|&v| v == DUMMY_SOURCE_NODE_FLAG)
         }) {
             self.remove_node(dummy_source_node);
         } else {
@@ -157,7 +226,7 @@ impl GraphExtension for Graph<NodeData, i32> {
             self[i]
                 .params
                 .get("dummy")
-                .map_or(false, |&v| v == DUMMY_SINK_NODE_FLAG)
+                .is_some_and(|&v| v == DUMMY_SINK_NODE_FLAG)
         }) {
             self.remove_node(dummy_sink_node);
         } else {
@@ -171,33 +240,65 @@ impl GraphExtension for Graph<NodeData, i32> {
         }
     }
 
-    /// Calculate the earliest start times for each node in the DAG.
-    fn calculate_earliest_start_times(&mut self) {
-        let mut earliest_start_times = vec![0; self.node_count()];
-
-        let sorted_nodes = toposort(&*self, None).unwrap();
-        for node_i in sorted_nodes {
-            let max_earliest_start_time = self
-                .edges_directed(node_i, Incoming)
-                .map(|edge| {
-                    let source_node = edge.source();
-                    let exe_time = self[source_node].params["execution_time"];
-                    earliest_start_times[source_node.index()] + exe_time
-                })
-                .max_by(|a, b| a.partial_cmp(b).unwrap())
-                .unwrap_or(0);
-
-            earliest_start_times[node_i.index()] = max_earliest_start_time;
-            if self[node_i].params.contains_key("earliest_start_time") {
-                self.update_param(node_i, "earliest_start_time", max_earliest_start_time);
-            } else {
-                self.add_param(node_i, "earliest_start_time", max_earliest_start_time);
+    /// Remove every edge A -> C for which another path A -> ... -> C exists, e.g., A -> C of A -> B -> C.
+    /// Reachability between the nodes is preserved, while such shortcuts no longer distort the critical path and the segments.
+    /// The communication time of a removed edge is lost, so a warning is emitted for a nonzero one.
+    fn transitive_reduction(&mut self) {
+        let mut redundant_edges: Vec<_> = self
+            .edge_indices()
+            .filter(|&edge_i| {
+                let (source, target) = self.edge_endpoints(edge_i).unwrap();
+                self.get_suc_nodes(source)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .any(|suc_node| {
+                        suc_node != target
+                            && self
+                                .get_des_nodes(suc_node)
+                                .unwrap_or_default()
+                                .contains(&target)
+                    })
+            })
+            .collect();
+        // Removing an edge moves the last edge into its index, so remove them from the last one.
+        redundant_edges.sort_unstable_by(|a, b| b.cmp(a));
+        for edge_i in redundant_edges {
+            let (source, target) = self.edge_endpoints(edge_i).unwrap();
+            if self[edge_i] != 0 {
+                warn!(
+                    "The redundant edge from node {} to node {} is removed with its communication time {}.",
+                    self[source].id, self[target].id, self[edge_i]
+                );
             }
+            self.remove_edge(edge_i);
         }
-        assert!(
-            !earliest_start_times.iter().any(|&time| time < 0),
-            "The earliest start times should be non-negative."
-        );
+    }
+
+    /// Remove the nodes whose earliest start time exceeds `deadline` and return them.
+    /// Such nodes can never meet the deadline, and neither can their descendants, which are removed as well.
+    ///
+    /// This is a diagnostic for quick feasibility screening, not a transform to schedule:
+    /// the pruned DAG no longer has the same schedulability as the original one.
+    /// The ids of the remaining nodes are kept, so they may no longer match their NodeIndex.
+    fn prune_beyond_deadline(&mut self, deadline: i32) -> Vec<NodeData> {
+        self.calculate_earliest_start_times();
+        let pruned_nodes: Vec<NodeIndex> = self
+            .node_indices()
+            .filter(|&node_i| self[node_i].get_params_value("earliest_start_time") > deadline)
+            .collect();
+        let pruned_node_data = pruned_nodes
+            .iter()
+            .map(|&node_i| self[node_i].clone())
+            .collect();
+        self.remove_nodes(&pruned_nodes);
+
+        pruned_node_data
+    }
+
+    /// Calculate the earliest start times for each node in the DAG.
+    fn calculate_earliest_start_times(&mut self) {
+        let sorted_nodes = self.get_topological_order();
+        calculate_earliest_start_times_in_order(self, &sorted_nodes);
     }
 
     fn calculate_earliest_finish_times(&mut self) {
@@ -215,37 +316,11 @@ impl GraphExtension for Graph<NodeData, i32> {
     }
 
     /// Calculate the latest start times for each node in the DAG.
+    /// The earliest start times are calculated as well, in the same topological order.
     fn calculate_latest_start_times(&mut self) {
-        self.calculate_earliest_start_times();
-        let sorted_nodes = toposort(&*self, None).unwrap();
-        let mut latest_start_times = vec![i32::MAX; self.node_count()];
-        let sink_node_index = self.get_sink_nodes();
-        latest_start_times[sink_node_index[0].index()] =
-            self[sink_node_index[0]].params["earliest_start_time"];
-
-        for &node_i in sorted_nodes.iter().rev() {
-            let min_latest_start_time = self
-                .edges_directed(node_i, Outgoing)
-                .map(|edge| {
-                    let target_node = edge.target();
-                    let pre_exe_time = self[node_i].params["execution_time"];
-                    latest_start_times[target_node.index()] - pre_exe_time
-                })
-                .min_by(|a, b| a.partial_cmp(b).unwrap())
-                .unwrap_or(self[sink_node_index[0]].params["earliest_start_time"]);
-
-            latest_start_times[node_i.index()] = min_latest_start_time;
-            if self[node_i].params.contains_key("latest_start_time") {
-                self.update_param(node_i, "latest_start_time", min_latest_start_time);
-            } else {
-                self.add_param(node_i, "latest_start_time", min_latest_start_time);
-            }
-        }
-
-        assert!(
-            !latest_start_times.iter().any(|&time| time < 0),
-            "The latest start times should be non-negative."
-        );
+        let sorted_nodes = self.get_topological_order();
+        calculate_earliest_start_times_in_order(self, &sorted_nodes);
+        calculate_latest_start_times_in_order(self, &sorted_nodes);
     }
 
     fn calculate_latest_finish_times(&mut self) {
@@ -264,6 +339,8 @@ impl GraphExtension for Graph<NodeData, i32> {
 
     /// Returns the critical path of a DAG
     /// Multiple critical paths are obtained using Breadth-First Search, BFS
+    /// If there are multiple critical paths, the one whose sequence of node ids is lexicographically smallest is returned,
+    /// so the result does not depend on the order in which the nodes and edges were added.
     ///
     /// # Arguments
     ///
@@ -291,9 +368,25 @@ impl GraphExtension for Graph<NodeData, i32> {
     /// println!("The critical path is: {:?}", critical_path);
     /// ```
     fn get_critical_path(&mut self) -> Vec<NodeIndex> {
+        let critical_paths = self.get_critical_paths();
+        if critical_paths.len() > 1 {
+            warn!("There are more than one critical paths.");
+        }
+        critical_paths
+            .into_iter()
+            .min_by_key(|critical_path| {
+                critical_path
+                    .iter()
+                    .map(|&node_i| self[node_i].id)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap()
+    }
+
+    /// Every critical path of the DAG, found in the same way as `get_critical_path`.
+    fn get_critical_paths(&mut self) -> Vec<Vec<NodeIndex>> {
         self.add_dummy_sink_node();
         let start_node = self.add_dummy_source_node();
-        self.calculate_earliest_start_times();
         self.calculate_latest_start_times();
         let mut path_search_queue = VecDeque::new();
         path_search_queue.push_back((start_node, vec![start_node]));
@@ -322,10 +415,165 @@ impl GraphExtension for Graph<NodeData, i32> {
 
         self.remove_dummy_source_node();
         self.remove_dummy_sink_node();
-        if critical_path.len() > 1 {
-            warn!("There are more than one critical paths.");
+        critical_path
+    }
+
+    /// Critical path whose length includes the communication time (edge weight) of every edge on the path,
+    /// i.e., the path when no two adjacent nodes share a core. `get_critical_path` ignores the edge weights.
+    /// Ties are broken in the same way as `get_critical_path`.
+    fn get_critical_path_with_comm(&self) -> Vec<NodeIndex> {
+        // The longest path ending at each node, with its length.
+        let mut longest_paths: HashMap<NodeIndex, (i32, Vec<NodeIndex>)> = HashMap::new();
+        let path_ids = |path: &[NodeIndex]| path.iter().map(|&i| self[i].id).collect::<Vec<_>>();
+        let longer_or_smaller_ids =
+            |(a_length, a_path): &(i32, Vec<NodeIndex>),
+             (b_length, b_path): &(i32, Vec<NodeIndex>)| {
+                a_length
+                    .cmp(b_length)
+                    .then_with(|| path_ids(b_path).cmp(&path_ids(a_path)))
+            };
+        for node_i in self.get_topological_order() {
+            let execution_time = self[node_i].params["execution_time"];
+            let longest_path = self
+                .edges_directed(node_i, Incoming)
+                .map(|edge| {
+                    let (pre_length, pre_path) = &longest_paths[&edge.source()];
+                    let mut path = pre_path.clone();
+                    path.push(node_i);
+                    (pre_length + edge.weight() + execution_time, path)
+                })
+                .max_by(longer_or_smaller_ids)
+                .unwrap_or((execution_time, vec![node_i]));
+            longest_paths.insert(node_i, longest_path);
+        }
+        self.get_sink_nodes()
+            .into_iter()
+            .map(|sink_i| longest_paths.remove(&sink_i).unwrap())
+            .max_by(longer_or_smaller_ids)
+            .map(|(_, path)| path)
+            .unwrap_or_default()
+    }
+
+    /// Longest initial node sequence shared by all critical paths, i.e., the head of the DAG that is forced to run serially.
+    fn critical_path_common_prefix(&mut self) -> Vec<NodeIndex> {
+        let critical_paths = self.get_critical_paths();
+        let mut common_prefix = critical_paths[0].clone();
+        for critical_path in critical_paths.iter().skip(1) {
+            let common_len = common_prefix
+                .iter()
+                .zip(critical_path)
+                .take_while(|(a, b)| a == b)
+                .count();
+            common_prefix.truncate(common_len);
+        }
+        common_prefix
+    }
+
+    /// Returns the maximal groups of connected zero-slack nodes, i.e., nodes whose latest start time equals the earliest.
+    /// Unlike `get_critical_path`, a group is not split where the critical segment branches.
+    /// Two zero-slack nodes are connected if the edge between them is tight (the target starts as soon as the source finishes).
+    /// Each group is sorted by the earliest start time, and the groups are sorted by their first node.
+    fn get_zero_slack_chains(&mut self) -> Vec<Vec<NodeIndex>> {
+        self.add_dummy_sink_node();
+        self.add_dummy_source_node();
+        self.calculate_latest_start_times();
+        self.remove_dummy_source_node();
+        self.remove_dummy_sink_node();
+
+        let is_zero_slack = |node_i: NodeIndex| {
+            self[node_i].params["earliest_start_time"] == self[node_i].params["latest_start_time"]
+        };
+        let is_tight = |source: NodeIndex, target: NodeIndex| {
+            self[source].params["earliest_start_time"] + self[source].params["execution_time"]
+                == self[target].params["earliest_start_time"]
+        };
+
+        let mut visited = vec![false; self.node_count()];
+        let mut zero_slack_chains = Vec::new();
+        for start_node in self.node_indices() {
+            if visited[start_node.index()] || !is_zero_slack(start_node) {
+                continue;
+            }
+            // Collect the connected component regardless of the edge direction.
+            visited[start_node.index()] = true;
+            let mut chain = vec![start_node];
+            let mut search_queue = VecDeque::from([start_node]);
+            while let Some(node_i) = search_queue.pop_front() {
+                let suc_nodes = self
+                    .edges_directed(node_i, Outgoing)
+                    .map(|edge| edge.target())
+                    .filter(|&target| is_tight(node_i, target));
+                let pre_nodes = self
+                    .edges_directed(node_i, Incoming)
+                    .map(|edge| edge.source())
+                    .filter(|&source| is_tight(source, node_i));
+                for neighbor in suc_nodes.chain(pre_nodes).collect::<Vec<_>>() {
+                    if !visited[neighbor.index()] && is_zero_slack(neighbor) {
+                        visited[neighbor.index()] = true;
+                        chain.push(neighbor);
+                        search_queue.push_back(neighbor);
+                    }
+                }
+            }
+            chain.sort_by_key(|&node_i| (self[node_i].params["earliest_start_time"], node_i));
+            zero_slack_chains.push(chain);
         }
-        critical_path[0].clone()
+        zero_slack_chains.sort_by_key(|chain| chain[0]);
+
+        zero_slack_chains
+    }
+
+    /// Returns the nodes whose `int_scaled_node_relative_deadline` (relative to the release of the DAG, see
+    /// `INT_SCALED_DEADLINE_FACTOR`) is earlier than their earliest start time. Such node deadlines cannot be met.
+    /// Nodes without the deadline are ignored.
+    fn find_infeasible_node_deadlines(&mut self) -> Vec<NodeIndex> {
+        self.calculate_earliest_start_times();
+        self.node_indices()
+            .filter(|&node_i| {
+                self[node_i]
+                    .params
+                    .get("int_scaled_node_relative_deadline")
+                    .is_some_and(|&deadline| {
+                        (deadline as i64)
+                            < self[node_i].params["earliest_start_time"] as i64
+                                * INT_SCALED_DEADLINE_FACTOR as i64
+                    })
+            })
+            .collect()
+    }
+
+    /// The node on the critical path with the largest execution time.
+    /// If several nodes have the same execution time, the earliest one on the critical path is returned.
+    fn bottleneck_node(&mut self) -> NodeIndex {
+        let critical_path = self.get_critical_path();
+        let mut bottleneck_node = critical_path[0];
+        for &node_i in critical_path.iter().skip(1) {
+            if self[node_i].get_params_value("execution_time")
+                > self[bottleneck_node].get_params_value("execution_time")
+            {
+                bottleneck_node = node_i;
+            }
+        }
+        bottleneck_node
+    }
+
+    /// Fraction of the critical path length occupied by the bottleneck node.
+    fn get_bottleneck_contribution(&mut self) -> f32 {
+        let bottleneck_node = self.bottleneck_node();
+        let critical_path = self.get_critical_path();
+        self[bottleneck_node].get_params_value("execution_time") as f32
+            / self.get_total_wcet_from_nodes(&critical_path) as f32
+    }
+
+    /// Critical path length divided by the end-to-end deadline, or by the period if the deadline is implicit.
+    /// A DAG with a density greater than 1 cannot meet its deadline on any number of cores.
+    fn get_density(&mut self) -> f32 {
+        let deadline = self
+            .get_end_to_end_deadline()
+            .or_else(|| self.get_head_period())
+            .expect("Either a period or end-to-end deadline is required to calculate the density.");
+        let critical_path = self.get_critical_path();
+        self.get_total_wcet_from_nodes(&critical_path) as f32 / deadline as f32
     }
 
     fn get_non_critical_nodes(&self, critical_path: &[NodeIndex]) -> Option<Vec<NodeIndex>> {
@@ -355,6 +603,60 @@ impl GraphExtension for Graph<NodeData, i32> {
             .collect::<Vec<_>>()
     }
 
+    /// Topological order of the nodes. Panics if the graph has a cycle.
+    /// The order is recomputed on each call. Use `CachedOrderDag` to compute it once for repeated use.
+    fn get_topological_order(&self) -> Vec<NodeIndex> {
+        toposort(self, None).unwrap_or_else(|cycle| {
+            panic!("The graph has a cycle. NodeIndex: {:?}", cycle.node_id())
+        })
+    }
+
+    /// Map the id of each node to its NodeIndex.
+    /// Use this instead of scanning the nodes when the ids no longer match the NodeIndex (e.g., after node removal).
+    fn build_id_index(&self) -> HashMap<i32, NodeIndex> {
+        self.node_indices()
+            .map(|node_i| (self[node_i].id, node_i))
+            .collect()
+    }
+
+    /// Check that every node has all of the `required` parameters.
+    /// Returns the `(node id, missing key)` pairs in the order of the nodes and `required` otherwise.
+    fn validate_required_params(&self, required: &[&str]) -> Result<(), Vec<(i32, String)>> {
+        let missing_params: Vec<(i32, String)> = self
+            .node_weights()
+            .flat_map(|node| {
+                required
+                    .iter()
+                    .filter(|key| !node.params.contains_key(**key))
+                    .map(|key| (node.id, key.to_string()))
+            })
+            .collect();
+        if missing_params.is_empty() {
+            Ok(())
+        } else {
+            Err(missing_params)
+        }
+    }
+
+    /// Hash of the structure and the execution times of the DAG, i.e., the node ids, `execution_time` and the edges.
+    /// Other parameters such as `priority` are ignored, so the fingerprint is the same before and after prioritization.
+    fn dag_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for node in self.node_weights() {
+            node.id.hash(&mut hasher);
+            node.params.get("execution_time").hash(&mut hasher);
+        }
+        for edge in self.raw_edges() {
+            self[edge.source()].id.hash(&mut hasher);
+            self[edge.target()].id.hash(&mut hasher);
+            edge.weight.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Sum of `execution_time` of all nodes, i.e., the workload of one job of the DAG.
+    /// Communication times are not included (see `get_communication_volume`).
+    /// Panics if a node does not have `execution_time`.
     fn get_volume(&self) -> i32 {
         self.node_indices()
             .map(|node| {
@@ -366,6 +668,25 @@ impl GraphExtension for Graph<NodeData, i32> {
             .sum()
     }
 
+    /// Sum of the communication times (edge weights) of all edges.
+    fn get_communication_volume(&self) -> i32 {
+        self.edge_weights().sum()
+    }
+
+    /// Makespan when all nodes are executed sequentially on a single core.
+    /// Equal to `get_volume`, but used as the baseline for the speedup.
+    fn sequential_makespan(&self) -> i32 {
+        self.get_volume()
+    }
+
+    /// Sequential makespan including the communication that occurs on a single core.
+    /// Since all nodes are on the same core, no communication time is added.
+    fn sequential_makespan_with_comm(&self) -> i32 {
+        self.sequential_makespan()
+    }
+
+    /// Sum of `execution_time` of the given nodes, e.g., the length of a critical path without communication.
+    /// A node given more than once is counted each time. Panics if a node does not have `execution_time`.
     fn get_total_wcet_from_nodes(&self, nodes: &[NodeIndex]) -> i32 {
         nodes
             .iter()
@@ -409,6 +730,18 @@ impl GraphExtension for Graph<NodeData, i32> {
         Some(*periods[0])
     }
 
+    /// Set the period of the source node to `volume / target_util` (rounded) so that the DAG has the target utilization.
+    fn set_period_for_utilization(&mut self, target_util: f32) {
+        if target_util <= 0.0 {
+            panic!("The target utilization must be positive: {}", target_util);
+        }
+        let period = (self.get_volume() as f32 / target_util).round() as i32;
+        let source_node = self.get_source_nodes()[0];
+        self[source_node]
+            .params
+            .insert("period".to_string(), period);
+    }
+
     fn get_all_periods(&self) -> Option<HashMap<NodeIndex, i32>> {
         let mut period_map = HashMap::new();
         for node in self.node_indices() {
@@ -559,6 +892,16 @@ impl GraphExtension for Graph<NodeData, i32> {
         }
     }
 
+    /// Whether the DAG is flagged with a non-zero `multi_rate` DAG param.
+    /// In a multi-rate DAG, every node with `period` is released at its own rate.
+    fn is_multi_rate(&self) -> bool {
+        self.node_count() > 0
+            && self[NodeIndex::new(0)]
+                .params
+                .get("multi_rate")
+                .is_some_and(|&multi_rate| multi_rate != 0)
+    }
+
     fn add_node_with_id_consistency(&mut self, node: NodeData) -> NodeIndex {
         let node_index = self.add_node(node);
 
@@ -576,6 +919,231 @@ impl GraphExtension for Graph<NodeData, i32> {
         let pre_done_nodes_count = self[node_i].params.get("pre_done_count").unwrap_or(&0);
         pre_nodes_count == *pre_done_nodes_count
     }
+
+    /// Inline the sub-DAGs referenced by the `sub_dag_ref` param of placeholder nodes.
+    ///
+    /// `registry` is looked up with the decimal string of `sub_dag_ref`. The source nodes of the sub-DAG are connected
+    /// from the predecessors of the placeholder and the sink nodes to its successors, with the communication times of
+    /// the placeholder's edges. Nested references are expanded until no placeholder remains, and all node ids are
+    /// renumbered to match their NodeIndex. The `period` of the sub-DAG is dropped, and its `end_to_end_deadline`
+    /// is kept as `sub_dag_end_to_end_deadline` so that it is not mistaken for the deadline of the whole DAG.
+    fn expand_sub_dags(&mut self, registry: &HashMap<String, Graph<NodeData, i32>>) {
+        // Each expansion resolves one level of nesting, so more levels than entries means a cyclic reference.
+        for _ in 0..=registry.len() {
+            if !self
+                .node_weights()
+                .any(|node| node.params.contains_key("sub_dag_ref"))
+            {
+                return;
+            }
+
+            let mut expanded_dag = Graph::<NodeData, i32>::new();
+            // Entry and exit nodes in the expanded dag of each node in the current dag.
+            let mut endpoints: Vec<(Vec<NodeIndex>, Vec<NodeIndex>)> =
+                Vec::with_capacity(self.node_count());
+            for node in self.node_weights() {
+                if let Some(sub_dag_ref) = node.params.get("sub_dag_ref") {
+                    let sub_dag = registry.get(&sub_dag_ref.to_string()).unwrap_or_else(|| {
+                        panic!("Sub-DAG not found in the registry: {}", sub_dag_ref)
+                    });
+                    let offset = expanded_dag.node_count();
+                    for sub_node in sub_dag.node_weights() {
+                        let mut sub_node = sub_node.clone();
+                        sub_node.params.remove("period");
+                        if let Some(deadline) = sub_node.params.remove("end_to_end_deadline") {
+                            sub_node
+                                .params
+                                .insert("sub_dag_end_to_end_deadline".to_string(), deadline);
+                        }
+                        expanded_dag.add_node(sub_node);
+                    }
+                    let to_expanded_index =
+                        |node_i: NodeIndex| NodeIndex::new(offset + node_i.index());
+                    for edge in sub_dag.edge_references() {
+                        expanded_dag.add_edge(
+                            to_expanded_index(edge.source()),
+                            to_expanded_index(edge.target()),
+                            *edge.weight(),
+                        );
+                    }
+                    endpoints.push((
+                        sub_dag
+                            .get_source_nodes()
+                            .into_iter()
+                            .map(to_expanded_index)
+                            .collect(),
+                        sub_dag
+                            .get_sink_nodes()
+                            .into_iter()
+                            .map(to_expanded_index)
+                            .collect(),
+                    ));
+                } else {
+                    let node_i = expanded_dag.add_node(node.clone());
+                    endpoints.push((vec![node_i], vec![node_i]));
+                }
+            }
+
+            for edge in self.edge_references() {
+                for &exit_node_i in endpoints[edge.source().index()].1.iter() {
+                    for &entry_node_i in endpoints[edge.target().index()].0.iter() {
+                        expanded_dag.add_edge(exit_node_i, entry_node_i, *edge.weight());
+                    }
+                }
+            }
+
+            for node_i in expanded_dag.node_indices() {
+                expanded_dag[node_i].id = node_i.index() as i32;
+            }
+            *self = expanded_dag;
+        }
+        panic!("Cyclic sub_dag_ref found.");
+    }
+}
+
+/// A DAG that caches its topological order.
+/// The order is computed on the first use and dropped by the structural mutations (`add_node`, `add_edge`,
+/// `remove_node` and `remove_edge`), which must go through this type. Updating the parameters keeps the order.
+#[derive(Debug, Clone, Default)]
+pub struct CachedOrderDag {
+    dag: Graph<NodeData, i32>,
+    topological_order: OnceCell<Vec<NodeIndex>>,
+}
+
+impl CachedOrderDag {
+    pub fn new(dag: Graph<NodeData, i32>) -> Self {
+        Self {
+            dag,
+            topological_order: OnceCell::new(),
+        }
+    }
+
+    pub fn get_dag(&self) -> &Graph<NodeData, i32> {
+        &self.dag
+    }
+
+    pub fn into_dag(self) -> Graph<NodeData, i32> {
+        self.dag
+    }
+
+    /// Topological order of the nodes, computed once until the structure changes. Panics if the graph has a cycle.
+    pub fn get_topological_order(&self) -> &[NodeIndex] {
+        self.topological_order
+            .get_or_init(|| self.dag.get_topological_order())
+    }
+
+    pub fn add_node(&mut self, node_data: NodeData) -> NodeIndex {
+        self.topological_order.take();
+        self.dag.add_node(node_data)
+    }
+
+    pub fn add_edge(&mut self, source: NodeIndex, target: NodeIndex, weight: i32) -> EdgeIndex {
+        self.topological_order.take();
+        self.dag.add_edge(source, target, weight)
+    }
+
+    pub fn remove_node(&mut self, node_i: NodeIndex) -> Option<NodeData> {
+        self.topological_order.take();
+        self.dag.remove_node(node_i)
+    }
+
+    pub fn remove_edge(&mut self, edge_i: EdgeIndex) -> Option<i32> {
+        self.topological_order.take();
+        self.dag.remove_edge(edge_i)
+    }
+
+    pub fn add_param(&mut self, node_i: NodeIndex, key: &str, value: i32) {
+        self.dag.add_param(node_i, key, value);
+    }
+
+    pub fn update_param(&mut self, node_i: NodeIndex, key: &str, value: i32) {
+        self.dag.update_param(node_i, key, value);
+    }
+
+    /// Same as `GraphExtension::calculate_earliest_start_times`, over the cached order.
+    pub fn calculate_earliest_start_times(&mut self) {
+        let sorted_nodes = self
+            .topological_order
+            .get_or_init(|| self.dag.get_topological_order());
+        calculate_earliest_start_times_in_order(&mut self.dag, sorted_nodes);
+    }
+
+    /// Same as `GraphExtension::calculate_latest_start_times`, over the cached order.
+    pub fn calculate_latest_start_times(&mut self) {
+        let sorted_nodes = self
+            .topological_order
+            .get_or_init(|| self.dag.get_topological_order());
+        calculate_earliest_start_times_in_order(&mut self.dag, sorted_nodes);
+        calculate_latest_start_times_in_order(&mut self.dag, sorted_nodes);
+    }
+}
+
+/// Body of `calculate_earliest_start_times` over a topological order computed by the caller,
+/// so that the order can be shared with `calculate_latest_start_times_in_order`.
+fn calculate_earliest_start_times_in_order(
+    dag: &mut Graph<NodeData, i32>,
+    sorted_nodes: &[NodeIndex],
+) {
+    let mut earliest_start_times = vec![0; dag.node_count()];
+
+    for &node_i in sorted_nodes {
+        let max_earliest_start_time = dag
+            .edges_directed(node_i, Incoming)
+            .map(|edge| {
+                let source_node = edge.source();
+                let exe_time = dag[source_node].params["execution_time"];
+                earliest_start_times[source_node.index()] + exe_time
+            })
+            .max_by(|a, b| a.partial_cmp(b).unwrap())
+            .unwrap_or(0);
+
+        earliest_start_times[node_i.index()] = max_earliest_start_time;
+        if dag[node_i].params.contains_key("earliest_start_time") {
+            dag.update_param(node_i, "earliest_start_time", max_earliest_start_time);
+        } else {
+            dag.add_param(node_i, "earliest_start_time", max_earliest_start_time);
+        }
+    }
+    assert!(
+        !earliest_start_times.iter().any(|&time| time < 0),
+        "The earliest start times should be non-negative."
+    );
+}
+
+/// Body of `calculate_latest_start_times` over a topological order computed by the caller.
+/// The earliest start times must have been calculated.
+fn calculate_latest_start_times_in_order(
+    dag: &mut Graph<NodeData, i32>,
+    sorted_nodes: &[NodeIndex],
+) {
+    let mut latest_start_times = vec![i32::MAX; dag.node_count()];
+    let sink_node_index = dag.get_sink_nodes();
+    latest_start_times[sink_node_index[0].index()] =
+        dag[sink_node_index[0]].params["earliest_start_time"];
+
+    for &node_i in sorted_nodes.iter().rev() {
+        let min_latest_start_time = dag
+            .edges_directed(node_i, Outgoing)
+            .map(|edge| {
+                let target_node = edge.target();
+                let pre_exe_time = dag[node_i].params["execution_time"];
+                latest_start_times[target_node.index()] - pre_exe_time
+            })
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+            .unwrap_or(dag[sink_node_index[0]].params["earliest_start_time"]);
+
+        latest_start_times[node_i.index()] = min_latest_start_time;
+        if dag[node_i].params.contains_key("latest_start_time") {
+            dag.update_param(node_i, "latest_start_time", min_latest_start_time);
+        } else {
+            dag.add_param(node_i, "latest_start_time", min_latest_start_time);
+        }
+    }
+
+    assert!(
+        !latest_start_times.iter().any(|&time| time < 0),
+        "The latest start times should be non-negative."
+    );
 }
 
 #[cfg(test)]
@@ -588,6 +1156,48 @@ mod tests {
         NodeData { id, params }
     }
 
+    #[test]
+    fn test_node_data_with_params_normal() {
+        let node = NodeData::with_params(
+            1,
+            &[("execution_time", 10), ("period", 100), ("priority", 2)],
+        );
+        assert_eq!(node.get_id(), 1);
+        assert_eq!(node.params.len(), 3);
+        assert_eq!(node.get_params_value("execution_time"), 10);
+        assert_eq!(node.get_params_value("period"), 100);
+        assert_eq!(node.get_params_value("priority"), 2);
+    }
+
+    #[test]
+    fn test_get_communication_volume_normal() {
+        let dag = crate::dag_creator::create_dag_from_yaml(
+            "tests/sample_dags/fan_in_fan_out_format.yaml",
+            false,
+        );
+        assert_eq!(dag.get_communication_volume(), 531);
+    }
+
+    #[test]
+    fn test_get_communication_volume_no_edge() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        dag.add_node(create_node(0, "execution_time", 3));
+        assert_eq!(dag.get_communication_volume(), 0);
+    }
+
+    #[test]
+    fn test_sequential_makespan_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 3));
+        let n1 = dag.add_node(create_node(1, "execution_time", 4));
+        let n2 = dag.add_node(create_node(2, "execution_time", 5));
+        dag.add_edge(n0, n1, 10);
+        dag.add_edge(n0, n2, 10);
+        assert_eq!(dag.sequential_makespan(), 3 + 4 + 5);
+        assert_eq!(dag.sequential_makespan(), dag.get_volume());
+        assert_eq!(dag.sequential_makespan_with_comm(), 3 + 4 + 5);
+    }
+
     #[test]
     fn test_add_param_normal() {
         let mut dag = Graph::<NodeData, i32>::new();
@@ -691,6 +1301,40 @@ mod tests {
         assert_eq!(dag[n4].params["latest_start_time"], 59);
     }
 
+    #[test]
+    fn test_cached_order_dag_reuses_order_until_mutated() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 4));
+        let n1 = dag.add_node(create_node(1, "execution_time", 7));
+        let n2 = dag.add_node(create_node(2, "execution_time", 5));
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n0, n2, 1);
+        dag.add_dummy_sink_node();
+        let mut cached_order_dag = CachedOrderDag::new(dag.clone());
+
+        let order = cached_order_dag.get_topological_order().to_vec();
+        assert_eq!(order, toposort(&dag, None).unwrap());
+        let order_ptr = cached_order_dag.get_topological_order().as_ptr();
+        cached_order_dag.calculate_latest_start_times();
+        cached_order_dag.update_param(n1, "execution_time", 8);
+        assert_eq!(cached_order_dag.get_topological_order().as_ptr(), order_ptr);
+        assert_eq!(
+            cached_order_dag.get_dag()[n2].params["earliest_start_time"],
+            4
+        );
+        assert_eq!(
+            cached_order_dag.get_dag()[n2].params["latest_start_time"],
+            6
+        );
+
+        // n3 must come before n0, so the cached order is no longer valid.
+        let n3 = cached_order_dag.add_node(create_node(3, "execution_time", 1));
+        cached_order_dag.add_edge(n3, n0, 1);
+        let order = cached_order_dag.get_topological_order().to_vec();
+        assert_eq!(order, toposort(cached_order_dag.get_dag(), None).unwrap());
+        assert_eq!(order[0], n3);
+    }
+
     #[test]
     fn test_calculate_lasted_finish_times_normal() {
         let mut dag = Graph::<NodeData, i32>::new();
@@ -736,6 +1380,85 @@ mod tests {
         assert_eq!(critical_path, &[n0, n2, n4]);
     }
 
+    #[test]
+    fn test_get_critical_path_with_comm_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 4));
+        let n1 = dag.add_node(create_node(1, "execution_time", 10));
+        let n2 = dag.add_node(create_node(2, "execution_time", 8));
+        let n3 = dag.add_node(create_node(3, "execution_time", 1));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 5);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n3, 0);
+
+        assert_eq!(dag.get_critical_path(), vec![n0, n1, n3]);
+        // 4 + 5 + 8 + 1 = 18 > 4 + 10 + 1 = 15.
+        assert_eq!(dag.get_critical_path_with_comm(), vec![n0, n2, n3]);
+
+        // With equal lengths, the path with the smaller node ids is returned.
+        dag.update_param(n2, "execution_time", 5);
+        assert_eq!(dag.get_critical_path_with_comm(), vec![n0, n1, n3]);
+    }
+
+    #[test]
+    fn test_get_critical_path_multiple_critical_paths_deterministic() {
+        // Add the edges of the path with larger ids first, so that BFS finds it first.
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 3));
+        let n1 = dag.add_node(create_node(1, "execution_time", 5));
+        let n2 = dag.add_node(create_node(2, "execution_time", 5));
+        let n3 = dag.add_node(create_node(3, "execution_time", 1));
+        dag.add_edge(n0, n2, 0);
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n2, n3, 0);
+        dag.add_edge(n1, n3, 0);
+
+        let critical_path = dag.get_critical_path();
+        assert_eq!(critical_path, vec![n0, n1, n3]);
+        assert_eq!(dag.get_critical_path(), critical_path);
+    }
+
+    #[test]
+    fn test_critical_path_common_prefix_multiple_critical_paths() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 3));
+        let n1 = dag.add_node(create_node(1, "execution_time", 2));
+        let n2 = dag.add_node(create_node(2, "execution_time", 5));
+        let n3 = dag.add_node(create_node(3, "execution_time", 5));
+        let n4 = dag.add_node(create_node(4, "execution_time", 1));
+        let n5 = dag.add_node(create_node(5, "execution_time", 1));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n1, n2, 0);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n4, 0);
+        dag.add_edge(n3, n4, 0);
+        dag.add_edge(n0, n5, 0);
+
+        let critical_paths = dag.get_critical_paths();
+        assert_eq!(critical_paths.len(), 2);
+        assert_eq!(dag.critical_path_common_prefix(), vec![n0, n1]);
+    }
+
+    #[test]
+    fn test_bottleneck_node_chain_base() {
+        let mut dag = crate::dag_creator::create_dag_from_yaml(
+            "tests/sample_dags/chain_base_format.yaml",
+            false,
+        );
+        // The critical path starts from the second source node (id 11), not from the 73-WCET node 0.
+        let bottleneck_node = dag.bottleneck_node();
+        assert_eq!(bottleneck_node, NodeIndex::new(15));
+        assert_eq!(dag[bottleneck_node].get_params_value("execution_time"), 108);
+
+        let critical_path = dag.get_critical_path();
+        let critical_path_length = dag.get_total_wcet_from_nodes(&critical_path);
+        assert_eq!(
+            dag.get_bottleneck_contribution(),
+            108.0 / critical_path_length as f32
+        );
+    }
+
     #[test]
     fn test_get_non_critical_nodes_when_critical_path_single() {
         let mut dag = Graph::<NodeData, i32>::new();
@@ -862,6 +1585,74 @@ mod tests {
         assert_eq!(dag.get_source_nodes(), vec![NodeIndex::new(3)]);
     }
 
+    #[test]
+    fn test_get_topological_order_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 0));
+        let n1 = dag.add_node(create_node(1, "execution_time", 0));
+        let n2 = dag.add_node(create_node(2, "execution_time", 0));
+        dag.add_edge(n2, n1, 1);
+        dag.add_edge(n1, n0, 1);
+        assert_eq!(dag.get_topological_order(), toposort(&dag, None).unwrap());
+        assert_eq!(dag.get_topological_order(), vec![n2, n1, n0]);
+
+        let n3 = dag.add_node(create_node(3, "execution_time", 0));
+        dag.add_edge(n3, n2, 1);
+        assert_eq!(dag.get_topological_order(), vec![n3, n2, n1, n0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_topological_order_cycle() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 0));
+        let n1 = dag.add_node(create_node(1, "execution_time", 0));
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n1, n0, 1);
+        dag.get_topological_order();
+    }
+
+    #[test]
+    fn test_dag_fingerprint_ignores_priority() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 3));
+        let n1 = dag.add_node(create_node(1, "execution_time", 4));
+        dag.add_edge(n0, n1, 1);
+        let fingerprint = dag.dag_fingerprint();
+
+        dag.add_param(n0, "priority", 0);
+        assert_eq!(dag.dag_fingerprint(), fingerprint);
+
+        let mut retimed_dag = dag.clone();
+        retimed_dag.update_param(n1, "execution_time", 5);
+        assert_ne!(retimed_dag.dag_fingerprint(), fingerprint);
+        let n2 = dag.add_node(create_node(2, "execution_time", 1));
+        dag.add_edge(n0, n2, 1);
+        assert_ne!(dag.dag_fingerprint(), fingerprint);
+    }
+
+    #[test]
+    fn test_build_id_index_after_node_removal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 0));
+        let n1 = dag.add_node(create_node(1, "execution_time", 0));
+        let n2 = dag.add_node(create_node(2, "execution_time", 0));
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n1, n2, 1);
+        assert_eq!(
+            dag.build_id_index(),
+            HashMap::from([(0, n0), (1, n1), (2, n2)])
+        );
+
+        // petgraph moves the last node into the removed slot.
+        dag.remove_node(n0);
+        let id_index = dag.build_id_index();
+        assert_eq!(id_index.len(), 2);
+        assert_eq!(id_index[&2], NodeIndex::new(0));
+        assert_eq!(dag[id_index[&1]].id, 1);
+        assert!(!id_index.contains_key(&0));
+    }
+
     #[test]
     fn test_get_sink_nodes_normal() {
         let mut dag = Graph::<NodeData, i32>::new();
@@ -909,6 +1700,36 @@ mod tests {
         assert!(dag[sink_index].id == sink_index.index() as i32);
     }
 
+    #[test]
+    fn test_validate_required_params_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 3), ("period", 10)],
+        ));
+        dag.add_node(create_node(1, "execution_time", 6));
+
+        assert_eq!(dag.validate_required_params(&["execution_time"]), Ok(()));
+        assert_eq!(dag.validate_required_params(&[]), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_required_params_missing_execution_time() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        dag.add_node(create_node(0, "execution_time", 3));
+        dag.add_node(create_node(1, "period", 10));
+        dag.add_node(create_node(2, "execution_time", 5));
+
+        assert_eq!(
+            dag.validate_required_params(&["execution_time", "period"]),
+            Err(vec![
+                (0, "period".to_string()),
+                (1, "execution_time".to_string()),
+                (2, "period".to_string())
+            ])
+        );
+    }
+
     #[test]
     fn test_get_volume_normal() {
         let mut dag = Graph::<NodeData, i32>::new();
@@ -956,6 +1777,17 @@ mod tests {
         assert_eq!(dag.get_total_wcet_from_nodes(&nodes0), 4);
     }
 
+    #[test]
+    fn test_get_total_wcet_from_nodes_duplicate_node() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 4));
+        let n1 = dag.add_node(create_node(1, "execution_time", 7));
+        dag.add_edge(n0, n1, 10);
+
+        assert_eq!(dag.get_total_wcet_from_nodes(&[n0, n0, n1]), 15);
+        assert_eq!(dag.get_total_wcet_from_nodes(&[]), 0);
+    }
+
     #[test]
     #[should_panic]
     fn test_get_total_wcet_from_nodes_node_no_includes_execution_time() {
@@ -1004,6 +1836,199 @@ mod tests {
         assert_eq!(dag.get_head_period(), Some(3));
     }
 
+    #[test]
+    fn test_expand_sub_dags_two_level_nesting() {
+        // Inner sub-DAG: e -> f
+        let mut inner_dag = Graph::<NodeData, i32>::new();
+        let e = inner_dag.add_node(create_node(0, "execution_time", 5));
+        let f = inner_dag.add_node(create_node(1, "execution_time", 6));
+        inner_dag.add_param(f, "end_to_end_deadline", 20);
+        inner_dag.add_edge(e, f, 1);
+
+        // Middle sub-DAG: c -> placeholder(2) -> d
+        let mut middle_dag = Graph::<NodeData, i32>::new();
+        let c = middle_dag.add_node(create_node(0, "execution_time", 3));
+        let p2 = middle_dag.add_node(create_node(1, "sub_dag_ref", 2));
+        let d = middle_dag.add_node(create_node(2, "execution_time", 4));
+        middle_dag.add_param(c, "period", 40);
+        middle_dag.add_edge(c, p2, 2);
+        middle_dag.add_edge(p2, d, 3);
+
+        // Top DAG: a -> placeholder(1) -> b
+        let mut dag = Graph::<NodeData, i32>::new();
+        let a = dag.add_node(create_node(0, "execution_time", 1));
+        let p1 = dag.add_node(create_node(1, "sub_dag_ref", 1));
+        let b = dag.add_node(create_node(2, "execution_time", 2));
+        dag.add_param(a, "period", 100);
+        dag.add_edge(a, p1, 4);
+        dag.add_edge(p1, b, 5);
+
+        let registry = HashMap::from([("1".to_string(), middle_dag), ("2".to_string(), inner_dag)]);
+        dag.expand_sub_dags(&registry);
+
+        assert_eq!(dag.node_count(), 6);
+        assert_eq!(dag.edge_count(), 5);
+        assert!(!petgraph::algo::is_cyclic_directed(&dag));
+        for node_i in dag.node_indices() {
+            assert_eq!(dag[node_i].id, node_i.index() as i32);
+            assert!(!dag[node_i].params.contains_key("sub_dag_ref"));
+        }
+        assert_eq!(dag.get_volume(), 1 + 3 + 5 + 6 + 4 + 2);
+        assert_eq!(dag.get_communication_volume(), 4 + 2 + 1 + 3 + 5);
+        assert_eq!(dag.get_head_period(), Some(100));
+        assert_eq!(dag.get_source_nodes().len(), 1);
+        assert_eq!(dag.get_sink_nodes().len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cyclic sub_dag_ref found.")]
+    fn test_expand_sub_dags_cyclic_reference() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        dag.add_node(create_node(0, "sub_dag_ref", 0));
+        let registry = HashMap::from([("0".to_string(), dag.clone())]);
+        dag.expand_sub_dags(&registry);
+    }
+
+    #[test]
+    fn test_get_zero_slack_chains_shared_prefix() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 2));
+        let n1 = dag.add_node(create_node(1, "execution_time", 3));
+        let n2 = dag.add_node(create_node(2, "execution_time", 5));
+        let n3 = dag.add_node(create_node(3, "execution_time", 5));
+        let n4 = dag.add_node(create_node(4, "execution_time", 1));
+        let n5 = dag.add_node(create_node(5, "execution_time", 1));
+        // Two critical paths n0 -> n1 -> n2 -> n4 and n0 -> n1 -> n3 -> n4 share the prefix n0 -> n1.
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n1, n2, 1);
+        dag.add_edge(n1, n3, 1);
+        dag.add_edge(n2, n4, 1);
+        dag.add_edge(n3, n4, 1);
+        // n5 has slack.
+        dag.add_edge(n0, n5, 1);
+        dag.add_edge(n5, n4, 1);
+
+        assert_eq!(dag.get_zero_slack_chains(), vec![vec![n0, n1, n2, n3, n4]]);
+        assert_eq!(dag.node_count(), 6);
+    }
+
+    #[test]
+    fn test_get_zero_slack_chains_separate_chains() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let a0 = dag.add_node(create_node(0, "execution_time", 4));
+        let a1 = dag.add_node(create_node(1, "execution_time", 6));
+        let b0 = dag.add_node(create_node(2, "execution_time", 5));
+        let b1 = dag.add_node(create_node(3, "execution_time", 5));
+        let c0 = dag.add_node(create_node(4, "execution_time", 3));
+        dag.add_edge(a0, a1, 1);
+        dag.add_edge(b0, b1, 1);
+
+        assert_eq!(
+            dag.get_zero_slack_chains(),
+            vec![vec![a0, a1], vec![b0, b1]]
+        );
+        assert!(dag[c0].params["latest_start_time"] > 0);
+    }
+
+    #[test]
+    fn test_find_infeasible_node_deadlines_mis_assigned() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 4));
+        let n1 = dag.add_node(create_node(1, "execution_time", 6));
+        let n2 = dag.add_node(create_node(2, "execution_time", 3));
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n1, n2, 1);
+        dag.add_param(
+            n0,
+            "int_scaled_node_relative_deadline",
+            4 * INT_SCALED_DEADLINE_FACTOR,
+        );
+        // n1 cannot start before 4.
+        dag.add_param(
+            n1,
+            "int_scaled_node_relative_deadline",
+            3 * INT_SCALED_DEADLINE_FACTOR,
+        );
+        dag.add_param(
+            n2,
+            "int_scaled_node_relative_deadline",
+            13 * INT_SCALED_DEADLINE_FACTOR,
+        );
+
+        assert_eq!(dag.find_infeasible_node_deadlines(), vec![n1]);
+    }
+
+    #[test]
+    fn test_transitive_reduction_diamond_with_shortcut() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 1));
+        let n1 = dag.add_node(create_node(1, "execution_time", 1));
+        let n2 = dag.add_node(create_node(2, "execution_time", 1));
+        let n3 = dag.add_node(create_node(3, "execution_time", 1));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+        dag.add_edge(n0, n3, 2);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n3, 0);
+
+        dag.transitive_reduction();
+
+        assert_eq!(dag.edge_count(), 4);
+        assert!(dag.find_edge(n0, n3).is_none());
+        for (source, target) in [(n0, n1), (n0, n2), (n1, n3), (n2, n3)] {
+            assert!(dag.find_edge(source, target).is_some());
+        }
+        assert_eq!(dag.get_des_nodes(n0).unwrap().len(), 3);
+
+        // A DAG without shortcuts is unchanged.
+        dag.transitive_reduction();
+        assert_eq!(dag.edge_count(), 4);
+    }
+
+    #[test]
+    fn test_prune_beyond_deadline_late_node() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 10));
+        let n1 = dag.add_node(create_node(1, "execution_time", 20));
+        let n2 = dag.add_node(create_node(2, "execution_time", 5));
+        let n3 = dag.add_node(create_node(3, "execution_time", 5));
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n0, n2, 1);
+        dag.add_edge(n1, n3, 1);
+        dag.add_edge(n2, n3, 1);
+
+        // n3 cannot start before 30.
+        let pruned_nodes = dag.prune_beyond_deadline(25);
+        assert_eq!(pruned_nodes.len(), 1);
+        assert_eq!(pruned_nodes[0].id, 3);
+        assert_eq!(dag.node_count(), 3);
+        assert_eq!(dag.edge_count(), 2);
+        assert_eq!(dag.get_sink_nodes(), vec![n1, n2]);
+
+        assert!(dag.prune_beyond_deadline(30).is_empty());
+        assert_eq!(dag.node_count(), 3);
+    }
+
+    #[test]
+    fn test_set_period_for_utilization_normal() {
+        let mut rng = crate::rng::SeededRng::new(0);
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", rng.gen_range(1, 100)));
+        for id in 1..10 {
+            let node_i = dag.add_node(create_node(id, "execution_time", rng.gen_range(1, 100)));
+            dag.add_edge(n0, node_i, 1);
+        }
+
+        dag.set_period_for_utilization(0.5);
+        let utilization = dag.get_volume() as f32 / dag.get_head_period().unwrap() as f32;
+        assert!((utilization - 0.5).abs() < 0.01);
+
+        // An existing period is overwritten.
+        dag.set_period_for_utilization(2.0);
+        let utilization = dag.get_volume() as f32 / dag.get_head_period().unwrap() as f32;
+        assert!((utilization - 2.0).abs() < 0.05);
+    }
+
     #[test]
     fn test_get_head_period_node_no_includes_period() {
         let mut dag = Graph::<NodeData, i32>::new();
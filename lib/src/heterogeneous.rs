@@ -0,0 +1,224 @@
+//! Heterogeneous processor module. Each core has a speed factor relative to a core of `HomogeneousProcessor`.
+//! The speed is kept as the frequency of the core, i.e., speed 1.0 is `NOMINAL_FREQUENCY`.
+use crate::{
+    core::{Core, ProcessResult, NOMINAL_FREQUENCY},
+    graph_extension::NodeData,
+    processor::ProcessorBase,
+};
+
+/// Ticks needed to finish `work` on a core running at `frequency` (rounded up).
+fn work_to_ticks(work: i32, frequency: i32) -> i32 {
+    ((work as i64 * NOMINAL_FREQUENCY as i64 + frequency as i64 - 1) / frequency as i64) as i32
+}
+
+/// Work done in `ticks` on a core running at `frequency` (rounded up).
+fn ticks_to_work(ticks: i32, frequency: i32) -> i32 {
+    ((ticks as i64 * frequency as i64 + NOMINAL_FREQUENCY as i64 - 1) / NOMINAL_FREQUENCY as i64)
+        as i32
+}
+
+#[derive(Clone, Debug)]
+pub struct HeterogeneousProcessor {
+    pub cores: Vec<Core>,
+}
+
+impl ProcessorBase for HeterogeneousProcessor {
+    /// All cores run at speed 1.0, i.e., the same as `HomogeneousProcessor`.
+    fn new(num_cores: usize) -> Self {
+        HeterogeneousProcessor::new(vec![1.0; num_cores])
+    }
+
+    /// The node takes `ceil(execution_time / speed)` ticks on the core. The dummy nodes are not scaled.
+    fn allocate_specific_core(&mut self, core_id: usize, node_data: &NodeData) -> bool {
+        let core = &mut self.cores[core_id];
+        if !core.allocate(node_data) {
+            return false;
+        }
+        // `Core::allocate` resumes a preempted node with its remaining time as is, but `preempt` converted it to the work.
+        if node_data.params.contains_key("is_preempted") && !node_data.params.contains_key("dummy")
+        {
+            core.remain_proc_time = work_to_ticks(core.remain_proc_time, core.frequency);
+        }
+        true
+    }
+
+    fn process(&mut self) -> Vec<ProcessResult> {
+        self.cores.iter_mut().map(|core| core.process()).collect()
+    }
+
+    fn get_number_of_cores(&self) -> usize {
+        self.cores.len()
+    }
+
+    fn get_idle_core_num(&self) -> usize {
+        self.cores.iter().filter(|core| core.get_is_idle()).count()
+    }
+
+    fn get_idle_core_index(&self) -> Option<usize> {
+        self.cores.iter().position(|core| core.get_is_idle())
+    }
+
+    /// The remaining `execution_time` of the preempted node is converted back to the work (rounded up),
+    /// so that it resumes correctly on a core with a different speed.
+    fn preempt(&mut self, core_id: usize) -> Option<NodeData> {
+        let mut node_data = self.cores[core_id].preempt()?;
+        let remain_ticks = node_data.params["execution_time"];
+        node_data.params.insert(
+            "execution_time".to_string(),
+            ticks_to_work(remain_ticks, self.cores[core_id].frequency),
+        );
+        Some(node_data)
+    }
+
+    fn get_max_value_and_index(&self, key: &str) -> Option<(i32, usize)> {
+        self.cores
+            .iter()
+            .enumerate()
+            .filter_map(|(index, core)| {
+                let node_data = core.get_processing_node().as_ref()?;
+                let value = node_data.params.get(key)?;
+                Some((*value, index))
+            })
+            .max_by_key(|&(value, _)| value)
+    }
+
+    fn get_processing_node_and_remain_time(&self, core_id: usize) -> Option<(NodeData, i32)> {
+        let core = &self.cores[core_id];
+        core.get_processing_node()
+            .clone()
+            .map(|node_data| (node_data, core.remain_proc_time))
+    }
+}
+
+impl HeterogeneousProcessor {
+    /// Create a processor with one core per speed factor, e.g., `vec![2.0, 2.0, 1.0, 1.0]` for big.LITTLE.
+    /// The frequency of each core is the speed times `NOMINAL_FREQUENCY` (rounded).
+    pub fn new(speeds: Vec<f32>) -> Self {
+        if speeds.is_empty() {
+            panic!("The number of cores must be at least 1.");
+        }
+        let cores = speeds
+            .iter()
+            .map(|&speed| {
+                let frequency = (speed * NOMINAL_FREQUENCY as f32).round() as i32;
+                if frequency <= 0 {
+                    panic!("The speed must be positive: {}", speed);
+                }
+                Core {
+                    frequency,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        Self { cores }
+    }
+
+    pub fn allocate_any_idle_core(&mut self, node_data: &NodeData) -> bool {
+        if let Some(idle_core_i) = self.get_idle_core_index() {
+            self.allocate_specific_core(idle_core_i, node_data)
+        } else {
+            false
+        }
+    }
+
+    pub fn get_core_speed(&self, core_id: usize) -> f32 {
+        self.cores[core_id].frequency as f32 / NOMINAL_FREQUENCY as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dag_scheduler::DAGSchedulerBase, fixed_priority_scheduler::FixedPriorityScheduler,
+        homogeneous::tests::count_ticks_until_done,
+    };
+    use petgraph::Graph;
+
+    #[test]
+    fn test_heterogeneous_processor_new() {
+        let heterogeneous_processor = HeterogeneousProcessor::new(vec![2.0, 2.0, 1.0, 1.0]);
+        assert_eq!(heterogeneous_processor.get_number_of_cores(), 4);
+        assert_eq!(heterogeneous_processor.get_core_speed(0), 2.0);
+        assert_eq!(heterogeneous_processor.get_core_speed(3), 1.0);
+
+        let unit_speed_processor = <HeterogeneousProcessor as ProcessorBase>::new(2);
+        assert_eq!(unit_speed_processor.get_core_speed(1), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "The speed must be positive: 0")]
+    fn test_heterogeneous_processor_new_zero_speed() {
+        HeterogeneousProcessor::new(vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_heterogeneous_processor_slow_core_takes_longer() {
+        let mut heterogeneous_processor = HeterogeneousProcessor::new(vec![2.0, 1.0, 0.5]);
+        let node = NodeData::with_params(0, &[("execution_time", 5)]);
+
+        heterogeneous_processor.allocate_specific_core(0, &node);
+        assert_eq!(count_ticks_until_done(&mut heterogeneous_processor, 0), 3);
+        heterogeneous_processor.allocate_specific_core(1, &node);
+        assert_eq!(count_ticks_until_done(&mut heterogeneous_processor, 1), 5);
+        heterogeneous_processor.allocate_specific_core(2, &node);
+        assert_eq!(count_ticks_until_done(&mut heterogeneous_processor, 2), 10);
+    }
+
+    #[test]
+    fn test_heterogeneous_processor_dummy_node_not_scaled() {
+        let mut heterogeneous_processor = HeterogeneousProcessor::new(vec![0.5]);
+        let dummy_node = NodeData::with_params(0, &[("execution_time", 1), ("dummy", -1)]);
+
+        heterogeneous_processor.allocate_specific_core(0, &dummy_node);
+        assert_eq!(count_ticks_until_done(&mut heterogeneous_processor, 0), 1);
+    }
+
+    #[test]
+    fn test_heterogeneous_processor_allocate_any_idle_core() {
+        let mut heterogeneous_processor = HeterogeneousProcessor::new(vec![1.0, 0.5]);
+        let node = NodeData::with_params(0, &[("execution_time", 4)]);
+
+        assert!(heterogeneous_processor.allocate_any_idle_core(&node));
+        assert!(heterogeneous_processor.allocate_any_idle_core(&node));
+        assert!(!heterogeneous_processor.allocate_any_idle_core(&node));
+        assert_eq!(heterogeneous_processor.cores[0].remain_proc_time, 4);
+        assert_eq!(heterogeneous_processor.cores[1].remain_proc_time, 8);
+    }
+
+    #[test]
+    fn test_heterogeneous_processor_preempt_and_resume_on_other_core() {
+        let mut heterogeneous_processor = HeterogeneousProcessor::new(vec![0.5, 2.0]);
+        heterogeneous_processor
+            .allocate_specific_core(0, &NodeData::with_params(0, &[("execution_time", 4)]));
+        for _ in 0..2 {
+            heterogeneous_processor.process();
+        }
+
+        // 1 of the 4 units of work is done, so 3 remain.
+        let preempted_node = heterogeneous_processor.preempt(0).unwrap();
+        assert_eq!(preempted_node.params["execution_time"], 3);
+        heterogeneous_processor.allocate_specific_core(1, &preempted_node);
+        assert_eq!(count_ticks_until_done(&mut heterogeneous_processor, 1), 2);
+    }
+
+    #[test]
+    fn test_heterogeneous_processor_with_fixed_priority_scheduler() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 6), ("priority", 0)],
+        ));
+        dag.add_node(NodeData::with_params(
+            1,
+            &[("execution_time", 6), ("priority", 1)],
+        ));
+
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &HeterogeneousProcessor::new(vec![2.0, 0.5]));
+        let (schedule_length, _) = fixed_priority_scheduler.schedule();
+
+        // The node on the slow core takes 12 instead of 6.
+        assert_eq!(schedule_length, 12);
+    }
+}
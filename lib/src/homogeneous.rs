@@ -1,15 +1,53 @@
 //! Homogeneous processor module. This module uses Core struct.
-use crate::{core::Core, core::ProcessResult, graph_extension::NodeData, processor::ProcessorBase};
+use crate::processor::PowerState;
+use crate::{
+    core::Core, core::ProcessResult, core::TimeBase, core::NOMINAL_FREQUENCY,
+    graph_extension::NodeData, processor::ProcessorBase,
+};
+use std::collections::BTreeMap;
+
+/// Power drawn by a core per unit time in each state, and the energy to wake a core up from Sleep.
+/// `active_power` is drawn at `NOMINAL_FREQUENCY` and scales with the square of the core frequency (DVFS).
+/// `idle_power` and `sleep_power` are static and do not depend on the frequency.
+#[derive(Clone, Debug)]
+pub struct PowerModel {
+    pub active_power: f64,
+    pub idle_power: f64,
+    pub sleep_power: f64,
+    pub wakeup_energy: f64,
+}
+
+/// Policy that decides the state of a core without a node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IdleGovernor {
+    /// Stay in Idle, so that the next node starts without a wake-up.
+    AlwaysOn,
+    /// Enter Sleep as soon as the core becomes idle, and pay `wakeup_energy` when a node is allocated.
+    RaceToIdle,
+}
+
+#[derive(Clone, Debug)]
+struct PowerManagement {
+    power_model: PowerModel,
+    idle_governor: IdleGovernor,
+    core_states: Vec<PowerState>,
+    energy_by_state: BTreeMap<PowerState, f64>,
+}
 
 #[derive(Clone, Debug)]
 pub struct HomogeneousProcessor {
     pub cores: Vec<Core>,
+    power_management: Option<PowerManagement>,
 }
 
 impl ProcessorBase for HomogeneousProcessor {
     fn new(num_cores: usize) -> Self {
+        if num_cores == 0 {
+            panic!("The number of cores must be at least 1.");
+        }
         Self {
             cores: vec![Core::default(); num_cores],
+            power_management: None,
         }
     }
 
@@ -18,6 +56,7 @@ impl ProcessorBase for HomogeneousProcessor {
     }
 
     fn process(&mut self) -> Vec<ProcessResult> {
+        self.account_energy();
         self.cores.iter_mut().map(|core| core.process()).collect()
     }
 
@@ -53,6 +92,17 @@ impl ProcessorBase for HomogeneousProcessor {
             })
             .max_by_key(|&(value, _)| value)
     }
+
+    fn get_processing_node_and_remain_time(&self, core_id: usize) -> Option<(NodeData, i32)> {
+        let core = &self.cores[core_id];
+        core.get_processing_node()
+            .clone()
+            .map(|node_data| (node_data, core.remain_proc_time))
+    }
+
+    fn get_consumed_energy(&self) -> f64 {
+        self.get_total_energy()
+    }
 }
 
 impl HomogeneousProcessor {
@@ -63,14 +113,124 @@ impl HomogeneousProcessor {
             false
         }
     }
+
+    /// Set the frequency of the core used for the nodes allocated from now on (DVFS).
+    /// The node running on the core keeps the execution time looked up at allocation.
+    pub fn set_core_frequency(&mut self, core_id: usize, frequency: i32) {
+        if frequency <= 0 {
+            panic!("The frequency must be positive: {}", frequency);
+        }
+        self.cores[core_id].frequency = frequency;
+    }
+
+    /// Set how the cores interpret `execution_time` of the nodes allocated from now on.
+    /// Under `TimeBase::Cycles`, a node takes a different number of ticks on cores with different frequencies.
+    pub fn set_time_base(&mut self, time_base: TimeBase) {
+        for core in self.cores.iter_mut() {
+            core.time_base = time_base;
+        }
+    }
+
+    /// Enable energy accounting. Idle cores are put in the state decided by `idle_governor`.
+    pub fn set_power_management(&mut self, power_model: PowerModel, idle_governor: IdleGovernor) {
+        let idle_state = get_idle_state(idle_governor);
+        self.power_management = Some(PowerManagement {
+            power_model,
+            idle_governor,
+            core_states: vec![idle_state; self.cores.len()],
+            energy_by_state: BTreeMap::new(),
+        });
+    }
+
+    /// Energy consumed in each power state so far. The wake-up energy is counted in Sleep.
+    /// Empty if the power management is not set.
+    pub fn get_energy_by_state(&self) -> BTreeMap<PowerState, f64> {
+        self.power_management
+            .as_ref()
+            .map(|power_management| power_management.energy_by_state.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn get_total_energy(&self) -> f64 {
+        self.get_energy_by_state().values().sum()
+    }
+
+    /// Charge the energy of the current unit time according to the state of each core.
+    /// A core running a dummy node of a scheduler is not active.
+    fn account_energy(&mut self) {
+        let Some(power_management) = self.power_management.as_mut() else {
+            return;
+        };
+        let power_model = &power_management.power_model;
+        let idle_state = get_idle_state(power_management.idle_governor);
+        for (core, core_state) in self
+            .cores
+            .iter()
+            .zip(power_management.core_states.iter_mut())
+        {
+            let is_idle = core
+                .get_processing_node()
+                .as_ref()
+                .is_none_or(|node_data| node_data.params.contains_key("dummy"));
+            let next_state = if is_idle {
+                idle_state
+            } else {
+                PowerState::Active
+            };
+            if *core_state == PowerState::Sleep && next_state == PowerState::Active {
+                *power_management
+                    .energy_by_state
+                    .entry(PowerState::Sleep)
+                    .or_default() += power_model.wakeup_energy;
+            }
+            let power = match next_state {
+                PowerState::Active => {
+                    let relative_frequency = core.frequency as f64 / NOMINAL_FREQUENCY as f64;
+                    power_model.active_power * relative_frequency * relative_frequency
+                }
+                PowerState::Idle => power_model.idle_power,
+                PowerState::Sleep => power_model.sleep_power,
+            };
+            *power_management
+                .energy_by_state
+                .entry(next_state)
+                .or_default() += power;
+            *core_state = next_state;
+        }
+    }
+}
+
+fn get_idle_state(idle_governor: IdleGovernor) -> PowerState {
+    match idle_governor {
+        IdleGovernor::AlwaysOn => PowerState::Idle,
+        IdleGovernor::RaceToIdle => PowerState::Sleep,
+    }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
-    use crate::{core::ProcessResult, graph_extension::NodeData, processor::ProcessorBase};
+    use crate::{
+        core::ProcessResult, dag_scheduler::DAGSchedulerBase,
+        fixed_priority_scheduler::FixedPriorityScheduler, graph_extension::NodeData,
+        log::DAGSchedulerLog, processor::ProcessorBase,
+    };
+    use petgraph::Graph;
     use std::collections::BTreeMap;
 
+    pub(crate) fn count_ticks_until_done(
+        processor: &mut impl ProcessorBase,
+        core_id: usize,
+    ) -> i32 {
+        let mut ticks = 0;
+        loop {
+            ticks += 1;
+            if let ProcessResult::Done(_) = processor.process()[core_id] {
+                return ticks;
+            }
+        }
+    }
+
     fn create_node(id: i32, key: &str, value: i32) -> NodeData {
         let mut params = BTreeMap::new();
         params.insert(key.to_string(), value);
@@ -88,6 +248,46 @@ mod tests {
         }
     }
 
+    #[test]
+    #[should_panic(expected = "The number of cores must be at least 1.")]
+    fn test_processor_new_zero_cores() {
+        HomogeneousProcessor::new(0);
+    }
+
+    #[test]
+    fn test_processor_core_frequency_wcet_table() {
+        let mut homogeneous_processor = HomogeneousProcessor::new(2);
+        homogeneous_processor.set_core_frequency(1, 50);
+        let node = NodeData::with_params(0, &[("execution_time", 10), ("wcet_at_50", 20)]);
+
+        homogeneous_processor.allocate_specific_core(0, &node);
+        assert_eq!(count_ticks_until_done(&mut homogeneous_processor, 0), 10);
+        homogeneous_processor.allocate_specific_core(1, &node);
+        assert_eq!(count_ticks_until_done(&mut homogeneous_processor, 1), 20);
+
+        // Without a table entry, the execution time is scaled inversely with the frequency.
+        homogeneous_processor.set_core_frequency(1, 30);
+        homogeneous_processor.allocate_specific_core(1, &node);
+        assert_eq!(count_ticks_until_done(&mut homogeneous_processor, 1), 34);
+        homogeneous_processor.set_core_frequency(1, 200);
+        homogeneous_processor.allocate_specific_core(1, &create_node(0, "execution_time", 10));
+        assert_eq!(count_ticks_until_done(&mut homogeneous_processor, 1), 5);
+    }
+
+    #[test]
+    fn test_processor_time_base_cycles() {
+        let mut homogeneous_processor = HomogeneousProcessor::new(2);
+        homogeneous_processor.set_time_base(TimeBase::Cycles);
+        homogeneous_processor.set_core_frequency(0, 100);
+        homogeneous_processor.set_core_frequency(1, 300);
+        let node = create_node(0, "execution_time", 1000);
+
+        homogeneous_processor.allocate_specific_core(0, &node);
+        assert_eq!(count_ticks_until_done(&mut homogeneous_processor, 0), 10);
+        homogeneous_processor.allocate_specific_core(1, &node);
+        assert_eq!(count_ticks_until_done(&mut homogeneous_processor, 1), 4);
+    }
+
     #[test]
     fn test_processor_allocate_normal() {
         let mut homogeneous_processor = HomogeneousProcessor::new(2);
@@ -265,4 +465,91 @@ mod tests {
             Some((11, 1))
         );
     }
+
+    fn run_power_managed_schedule(idle_governor: IdleGovernor) -> HomogeneousProcessor {
+        let mut homogeneous_processor = HomogeneousProcessor::new(2);
+        homogeneous_processor.set_power_management(
+            PowerModel {
+                active_power: 1.0,
+                idle_power: 0.5,
+                sleep_power: 0.05,
+                wakeup_energy: 0.2,
+            },
+            idle_governor,
+        );
+        // Core 0 runs a node for 3 unit times and is idle for 7. Core 1 is idle for all 10.
+        homogeneous_processor.allocate_specific_core(0, &create_node(0, "execution_time", 3));
+        for _ in 0..10 {
+            homogeneous_processor.process();
+        }
+        homogeneous_processor
+    }
+
+    #[test]
+    fn test_processor_energy_race_to_idle_and_always_on() {
+        let always_on_processor = run_power_managed_schedule(IdleGovernor::AlwaysOn);
+        let energy_by_state = always_on_processor.get_energy_by_state();
+        assert!((energy_by_state[&PowerState::Active] - 3.0).abs() < 1e-9);
+        assert!((energy_by_state[&PowerState::Idle] - 8.5).abs() < 1e-9);
+        assert!(!energy_by_state.contains_key(&PowerState::Sleep));
+        assert!((always_on_processor.get_total_energy() - 11.5).abs() < 1e-9);
+
+        let race_to_idle_processor = run_power_managed_schedule(IdleGovernor::RaceToIdle);
+        let energy_by_state = race_to_idle_processor.get_energy_by_state();
+        assert!((energy_by_state[&PowerState::Active] - 3.0).abs() < 1e-9);
+        assert!(!energy_by_state.contains_key(&PowerState::Idle));
+        // 17 unit times of sleep and one wake-up of core 0.
+        assert!((energy_by_state[&PowerState::Sleep] - (17.0 * 0.05 + 0.2)).abs() < 1e-9);
+        assert!(race_to_idle_processor.get_total_energy() < always_on_processor.get_total_energy());
+    }
+
+    fn run_fixed_priority_schedule_at_frequency(frequency: i32) -> (i32, DAGSchedulerLog) {
+        let mut dag = Graph::<NodeData, i32>::new();
+        dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 10), ("priority", 0)],
+        ));
+        let mut homogeneous_processor = HomogeneousProcessor::new(1);
+        homogeneous_processor.set_core_frequency(0, frequency);
+        homogeneous_processor.set_power_management(
+            PowerModel {
+                active_power: 1.0,
+                idle_power: 0.0,
+                sleep_power: 0.0,
+                wakeup_energy: 0.0,
+            },
+            IdleGovernor::AlwaysOn,
+        );
+        let mut fixed_priority_scheduler =
+            FixedPriorityScheduler::new(&dag, &homogeneous_processor);
+        let (schedule_length, _) = fixed_priority_scheduler.schedule();
+        (schedule_length, fixed_priority_scheduler.get_log())
+    }
+
+    #[test]
+    fn test_processor_energy_dvfs() {
+        let (nominal_schedule_length, nominal_log) =
+            run_fixed_priority_schedule_at_frequency(NOMINAL_FREQUENCY);
+        let (half_schedule_length, half_log) =
+            run_fixed_priority_schedule_at_frequency(NOMINAL_FREQUENCY / 2);
+
+        // At half the frequency, the node takes twice as long at a quarter of the power.
+        assert_eq!(nominal_schedule_length, 10);
+        assert_eq!(half_schedule_length, 20);
+        let half_job_interval = &half_log.get_job_intervals()[0];
+        assert_eq!(
+            (half_job_interval.start_time, half_job_interval.end_time),
+            (0, 20)
+        );
+        assert!((nominal_log.get_total_energy() - 10.0).abs() < 1e-9);
+        assert!((half_log.get_total_energy() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_processor_energy_without_power_management() {
+        let mut homogeneous_processor = HomogeneousProcessor::new(1);
+        homogeneous_processor.process();
+        assert!(homogeneous_processor.get_energy_by_state().is_empty());
+        assert_eq!(homogeneous_processor.get_total_energy(), 0.0);
+    }
 }
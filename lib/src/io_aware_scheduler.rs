@@ -0,0 +1,133 @@
+use crate::getset_dag_scheduler;
+use crate::{
+    dag_scheduler::{DAGSchedulerBase, ExecutionTimeMode},
+    fixed_priority_scheduler::{
+        sort_by_priority, warn_missing_priorities, DEFAULT_FALLBACK_PRIORITY,
+    },
+    graph_extension::NodeData,
+    log::*,
+    processor::ProcessorBase,
+};
+use petgraph::Graph;
+use std::collections::VecDeque;
+
+/// Fixed-priority scheduler that runs I/O nodes (`kind: io`) on DMA engines separate from the compute cores.
+/// The last `num_dma_cores` cores of the processor are the DMA engines, which run only I/O nodes,
+/// and the other cores run only compute nodes.
+#[derive(Clone, Default)]
+pub struct IoAwareScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    dag: Graph<NodeData, i32>,
+    processor: T,
+    log: DAGSchedulerLog,
+    seed: Option<u64>,
+    dispatch_overhead: i32,
+    execution_time_mode: ExecutionTimeMode,
+    num_dma_cores: usize,
+}
+
+impl<T> IoAwareScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    pub fn set_num_dma_cores(&mut self, num_dma_cores: usize) {
+        assert!(
+            num_dma_cores <= self.processor.get_number_of_cores(),
+            "The number of DMA cores exceeds the number of cores."
+        );
+        self.num_dma_cores = num_dma_cores;
+    }
+
+    pub fn get_num_dma_cores(&self) -> usize {
+        self.num_dma_cores
+    }
+
+    fn is_dma_core(&self, core_index: usize) -> bool {
+        core_index >= self.processor.get_number_of_cores() - self.num_dma_cores
+    }
+}
+
+impl<T> DAGSchedulerBase<T> for IoAwareScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    /// There are no DMA cores until `set_num_dma_cores` is called.
+    fn new(dag: &Graph<NodeData, i32>, processor: &T) -> Self {
+        Self {
+            dag: dag.clone(),
+            processor: processor.clone(),
+            log: DAGSchedulerLog::new(dag, processor.get_number_of_cores()),
+            seed: None,
+            dispatch_overhead: 0,
+            execution_time_mode: ExecutionTimeMode::default(),
+            num_dma_cores: 0,
+        }
+    }
+
+    getset_dag_scheduler!(T);
+
+    fn sort_ready_queue(&self, ready_queue: &mut VecDeque<NodeData>) {
+        sort_by_priority(ready_queue, DEFAULT_FALLBACK_PRIORITY);
+    }
+
+    fn prepare_schedule(&mut self, dag: &Graph<NodeData, i32>) {
+        warn_missing_priorities(dag, DEFAULT_FALLBACK_PRIORITY);
+        if self.num_dma_cores == self.processor.get_number_of_cores() {
+            panic!("No compute cores. At least one core must not be a DMA core.");
+        }
+        if self.num_dma_cores == 0 && dag.node_weights().any(|node| node.is_io()) {
+            panic!("The DAG has I/O nodes, but there are no DMA cores.");
+        }
+    }
+
+    fn can_allocate_to_core(&self, node_data: &NodeData, core_index: usize) -> bool {
+        node_data.is_io() == self.is_dma_core(core_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_extension::NODE_KIND_IO;
+    use crate::homogeneous::HomogeneousProcessor;
+
+    #[test]
+    fn test_io_aware_scheduler_io_node_on_dma_core() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(0, &[("execution_time", 2)]));
+        let n1 = dag.add_node(NodeData::with_params(1, &[("execution_time", 5)]));
+        let n2 = dag.add_node(NodeData::with_params(
+            2,
+            &[("execution_time", 5), ("kind", NODE_KIND_IO)],
+        ));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+
+        // One compute core and one DMA core.
+        let mut scheduler = IoAwareScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        scheduler.set_num_dma_cores(1);
+        let (schedule_length, _) = scheduler.schedule();
+
+        // The I/O node runs concurrently with the compute node.
+        assert_eq!(schedule_length, 2 + 5);
+        let job_intervals = scheduler.get_log().get_job_intervals();
+        for job_interval in job_intervals.iter() {
+            let expected_core_id = if job_interval.node_id == 2 { 1 } else { 0 };
+            assert_eq!(job_interval.core_id, expected_core_id);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The DAG has I/O nodes, but there are no DMA cores.")]
+    fn test_io_aware_scheduler_no_dma_core() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 1), ("kind", NODE_KIND_IO)],
+        ));
+        let mut scheduler = IoAwareScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        scheduler.schedule();
+    }
+}
@@ -1,11 +1,20 @@
+pub mod alap_scheduler;
+pub mod analysis;
 pub mod core;
 pub mod dag_creator;
+pub mod dag_generator;
 pub mod dag_scheduler;
 pub mod dag_set_scheduler;
+pub mod edf_scheduler;
 pub mod fixed_priority_scheduler;
 pub mod global_edf_scheduler;
 pub mod graph_extension;
+pub mod heterogeneous;
 pub mod homogeneous;
+pub mod io_aware_scheduler;
+pub mod llf_scheduler;
 pub mod log;
 pub mod processor;
+pub mod resource_constrained_scheduler;
+pub mod rng;
 pub mod util;
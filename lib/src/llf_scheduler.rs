@@ -0,0 +1,139 @@
+use crate::dag_set_scheduler::{
+    DAGSetSchedulerBase, DAGStateManager, NodeDataWrapper, SchedulerConfig,
+};
+use crate::getset_dag_set_scheduler;
+use crate::{
+    graph_extension::NodeData, homogeneous::HomogeneousProcessor, log::DAGSetSchedulerLog,
+    processor::ProcessorBase,
+};
+use petgraph::graph::Graph;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Global least-laxity-first scheduler for DAG sets.
+/// The laxity of a ready node is node_absolute_deadline - current_time - remaining execution time of the node.
+/// `get_dynamic_priority` returns the laxity, so it is computed whenever a node becomes ready and recomputed
+/// for every waiting node each tick. It is also recorded as the `laxity` parameter. The laxity of a running node does not change, so preemption can compare it with `PreemptiveType::Preemptive`
+/// whose key is `laxity`.
+pub struct LeastLaxityFirstScheduler {
+    dag_set: Vec<Graph<NodeData, i32>>,
+    processor: HomogeneousProcessor,
+    log: DAGSetSchedulerLog,
+    current_time: i32,
+    ready_queue: BTreeSet<NodeDataWrapper>,
+    managers: Vec<DAGStateManager>,
+    remaining_times: BTreeMap<(usize, i32), i32>,
+    config: SchedulerConfig,
+}
+
+impl DAGSetSchedulerBase<HomogeneousProcessor> for LeastLaxityFirstScheduler {
+    fn new(dag_set: &[Graph<NodeData, i32>], processor: &HomogeneousProcessor) -> Self {
+        Self {
+            dag_set: dag_set.to_vec(),
+            processor: processor.clone(),
+            log: DAGSetSchedulerLog::new(dag_set, processor.get_number_of_cores()),
+            current_time: 0,
+            ready_queue: BTreeSet::new(),
+            managers: Vec::new(),
+            remaining_times: BTreeMap::new(),
+            config: SchedulerConfig::default(),
+        }
+    }
+
+    getset_dag_set_scheduler!(HomogeneousProcessor);
+
+    fn get_dynamic_priority(&self, node_data: &mut NodeData, current_time: i32) -> Option<i32> {
+        // A preempted node carries its remaining execution time as `execution_time`.
+        let params = &mut node_data.params;
+        let laxity = params["node_absolute_deadline"] - current_time - params["execution_time"];
+        params.insert("laxity".to_string(), laxity);
+        Some(laxity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dag_set_scheduler::{PreemptiveType, SchedulerConfig};
+    use crate::global_edf_scheduler::{tests::create_single_node_dag, GlobalEDFScheduler};
+    use crate::graph_extension::GraphExtension;
+
+    fn create_dag_set(execution_times_and_periods: &[(i32, i32)]) -> Vec<Graph<NodeData, i32>> {
+        execution_times_and_periods
+            .iter()
+            .enumerate()
+            .map(|(dag_id, &(execution_time, period))| {
+                let mut dag = create_single_node_dag(execution_time, period, period);
+                dag.set_dag_param("dag_id", dag_id as i32);
+                dag
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_llf_diverges_from_edf() {
+        // DAG 0 has the earlier deadline, but DAG 1 has the smaller laxity (12 - 8 = 4 < 10 - 2 = 8).
+        let dag_set = create_dag_set(&[(2, 10), (8, 12)]);
+        let processor = HomogeneousProcessor::new(1);
+        let config = SchedulerConfig::default().single_instance(true);
+
+        let mut edf_scheduler = GlobalEDFScheduler::new(&dag_set, &processor);
+        edf_scheduler.schedule_with_config(&config);
+        let edf_log = edf_scheduler.get_log();
+        assert_eq!(edf_log.get_finish_times(0), &[2]);
+        assert_eq!(edf_log.get_finish_times(1), &[10]);
+
+        let mut llf_scheduler = LeastLaxityFirstScheduler::new(&dag_set, &processor);
+        llf_scheduler.schedule_with_config(&config);
+        let llf_log = llf_scheduler.get_log();
+        assert_eq!(llf_log.get_finish_times(0), &[10]);
+        assert_eq!(llf_log.get_finish_times(1), &[8]);
+    }
+
+    #[test]
+    fn test_llf_preemptive_laxity_of_waiting_node_decreases() {
+        // DAG 0 runs with the constant laxity 20 - 10 = 10, while the laxity of DAG 1 decreases from 15 - 2 = 13
+        // and falls below it at 4.
+        let dag_set = create_dag_set(&[(10, 20), (2, 15)]);
+        let mut llf_scheduler =
+            LeastLaxityFirstScheduler::new(&dag_set, &HomogeneousProcessor::new(1));
+        llf_scheduler.schedule_with_config(
+            &SchedulerConfig::default()
+                .single_instance(true)
+                .preemptive_type(PreemptiveType::Preemptive {
+                    key: "laxity".to_string(),
+                }),
+        );
+
+        let log = llf_scheduler.get_log();
+        assert_eq!(log.total_preemptions(), 1);
+        assert_eq!(log.get_finish_times(0), &[12]);
+        assert_eq!(log.get_finish_times(1), &[6]);
+    }
+
+    #[test]
+    fn test_llf_successor_has_laxity_when_inserted() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[
+                ("execution_time", 2),
+                ("period", 20),
+                ("end_to_end_deadline", 20),
+            ],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(1, &[("execution_time", 3)]));
+        dag.add_edge(n0, n1, 1);
+        dag.set_dag_param("dag_id", 0);
+
+        let mut llf_scheduler =
+            LeastLaxityFirstScheduler::new(&[dag], &HomogeneousProcessor::new(1));
+        llf_scheduler.schedule_until(2);
+
+        // n1 became ready on the completion of n0 and is waiting for the next tick.
+        let ready_queue = llf_scheduler.get_ready_queue();
+        assert_eq!(ready_queue.len(), 1);
+        let node_data_wrapper = ready_queue.first().unwrap();
+        assert_eq!(node_data_wrapper.node_data.id, 1);
+        assert_eq!(node_data_wrapper.dynamic_priority, Some(20 - 2 - 3));
+    }
+}
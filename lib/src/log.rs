@@ -1,46 +1,128 @@
 use crate::graph_extension::{GraphExtension, NodeData};
+use crate::processor::PowerState;
 use crate::util::append_info_to_yaml;
 use log::warn;
 use petgraph::Graph;
 use serde::Serialize;
 use serde_derive::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Write,
+    time::Duration,
+};
 
 pub fn dump_struct(file_path: &str, target_struct: &impl Serialize) {
     let yaml = serde_yaml::to_string(&target_struct).expect("Failed to serialize.");
     append_info_to_yaml(file_path, &yaml);
 }
 
+/// Write the struct as YAML to `writer` (e.g., stdout) instead of appending it to a file.
+pub fn dump_struct_to_writer(writer: &mut impl Write, target_struct: &impl Serialize) {
+    let yaml = serde_yaml::to_string(&target_struct).expect("Failed to serialize.");
+    writer
+        .write_all(yaml.as_bytes())
+        .expect("Failed to write to the writer.");
+}
+
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct DAGSetInfo {
     total_utilization: f32,
+    max_density: f32,
     each_dag_info: Vec<DAGInfo>,
 }
 
 impl DAGSetInfo {
     pub fn new(dag_set: &[Graph<NodeData, i32>]) -> Self {
         let mut total_utilization = 0.0;
+        let mut max_density: f32 = 0.0;
         let mut each_dag_info = Vec::new();
 
         for dag in dag_set.iter() {
             let dag_info = DAGInfo::new(dag);
             total_utilization += dag_info.get_utilization();
+            max_density = max_density.max(dag_info.density);
             each_dag_info.push(dag_info);
         }
 
         Self {
             total_utilization,
+            max_density,
             each_dag_info,
         }
     }
 }
 
+/// Write the DAG set information as a LaTeX `tabular` with one row per DAG.
+/// The id column is the index of the DAG in the set.
+pub fn dump_dag_set_info_latex(info: &DAGSetInfo, file_path: &str) {
+    let mut latex = String::new();
+    latex.push_str("\\begin{tabular}{rrrrrr}\n\\hline\n");
+    latex.push_str(
+        "id & critical path length & period & deadline & volume & utilization \\\\\n\\hline\n",
+    );
+    for (dag_id, dag_info) in info.each_dag_info.iter().enumerate() {
+        latex.push_str(&format!(
+            "{} & {} & {} & {} & {} & {:.3} \\\\\n",
+            dag_id,
+            dag_info.critical_path_length,
+            dag_info.period,
+            dag_info.end_to_end_deadline,
+            dag_info.volume,
+            dag_info.utilization
+        ));
+    }
+    latex.push_str("\\hline\n\\end{tabular}\n");
+    std::fs::write(file_path, latex).expect("Failed to write the LaTeX file.");
+}
+
+/// Write the result of `analysis::schedulability_sweep` as CSV with one row per utilization bucket.
+pub fn dump_schedulability_sweep_csv(sweep: &[(f32, f32)], file_path: &str) {
+    let mut csv = String::from("utilization,schedulable_fraction\n");
+    for (utilization_bucket, schedulable_fraction) in sweep {
+        csv.push_str(&format!(
+            "{:.2},{:.3}\n",
+            utilization_bucket, schedulable_fraction
+        ));
+    }
+    std::fs::write(file_path, csv).expect("Failed to write the CSV file.");
+}
+
+/// Write the communication times of the DAG as an N x N matrix of space-separated integers (N = number of nodes).
+/// The entry at row i and column j is the weight of the edge from the i-th to the j-th node, or 0 if there is no edge.
+/// Rows and columns are in ascending order of node id, so the ids do not have to be contiguous.
+pub fn dump_adjacency_matrix(dag: &Graph<NodeData, i32>, file_path: &str) {
+    let id_index = dag.build_id_index();
+    let mut ids: Vec<i32> = id_index.keys().copied().collect();
+    ids.sort_unstable();
+    let position: BTreeMap<i32, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+    let mut matrix = vec![vec![0; ids.len()]; ids.len()];
+    for edge in dag.raw_edges() {
+        let source_id = dag[edge.source()].id;
+        let target_id = dag[edge.target()].id;
+        matrix[position[&source_id]][position[&target_id]] = edge.weight;
+    }
+    let text: String = matrix
+        .iter()
+        .map(|row| {
+            let row: Vec<String> = row.iter().map(|weight| weight.to_string()).collect();
+            row.join(" ") + "\n"
+        })
+        .collect();
+    std::fs::write(file_path, text).expect("Failed to write the adjacency matrix file.");
+}
+
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct DAGInfo {
     critical_path_length: i32,
     period: i32,
     end_to_end_deadline: i32,
     volume: i32,
+    communication_volume: i32,
     utilization: f32,
+    density: f32,
+    bottleneck_node_id: i32,
+    bottleneck_contribution: f32,
 }
 
 impl DAGInfo {
@@ -61,13 +143,34 @@ impl DAGInfo {
             (_, _) => period as f32 / volume as f32,
         };
 
-        let critical_path = dag.clone().get_critical_path();
+        let mut cloned_dag = dag.clone();
+        let critical_path = cloned_dag.get_critical_path();
+        let (bottleneck_node_id, bottleneck_contribution) = if critical_path.is_empty() {
+            (0, 0.0)
+        } else {
+            let bottleneck_node = cloned_dag.bottleneck_node();
+            (
+                dag[bottleneck_node].id,
+                cloned_dag.get_bottleneck_contribution(),
+            )
+        };
+        let critical_path_length = dag.get_total_wcet_from_nodes(&critical_path);
+        // The density is 0 when neither the period nor the end-to-end deadline is set, as is the utilization.
+        let density = match (end_to_end_deadline, period) {
+            (0, 0) => 0.0,
+            (0, _) => critical_path_length as f32 / period as f32,
+            (_, _) => critical_path_length as f32 / end_to_end_deadline as f32,
+        };
         Self {
-            critical_path_length: dag.get_total_wcet_from_nodes(&critical_path),
+            critical_path_length,
             period,
             end_to_end_deadline,
             volume,
+            communication_volume: dag.get_communication_volume(),
             utilization,
+            density,
+            bottleneck_node_id,
+            bottleneck_contribution,
         }
     }
 
@@ -95,6 +198,17 @@ pub struct DAGLog {
     response_time: Vec<i32>,
     average_response_time: f32,
     worst_response_time: i32,
+    /// Percentiles over the finished instances. See `calculate_response_time_percentiles`.
+    #[serde(default)]
+    p50_response_time: i32,
+    #[serde(default)]
+    p99_response_time: i32,
+    /// Delay of each release enforced by `min_separation`. Empty if the DAG does not have `min_separation`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    separation_delay: Vec<i32>,
+    /// Job ids of the instances aborted at their deadline under `DeadlineMissPolicy::Abort`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    aborted_job_ids: Vec<usize>,
 }
 
 impl DAGLog {
@@ -106,6 +220,10 @@ impl DAGLog {
             response_time: Default::default(),
             average_response_time: Default::default(),
             worst_response_time: Default::default(),
+            p50_response_time: Default::default(),
+            p99_response_time: Default::default(),
+            separation_delay: Default::default(),
+            aborted_job_ids: Default::default(),
         }
     }
 
@@ -113,7 +231,7 @@ impl DAGLog {
         // Unequal lengths indicate that the DAG was not completed within the hyper_period, and deadline miss occurred.
         if self.release_time.len() != self.finish_time.len() {
             // Mark as a deadline miss by maximizing the response time.
-            self.finish_time.push(std::i32::MAX);
+            self.finish_time.push(i32::MAX);
         }
         self.response_time = self
             .release_time
@@ -123,13 +241,61 @@ impl DAGLog {
             .collect();
     }
 
+    /// Response times of the instances that finished.
+    /// Instances marked as unfinished by `calculate_response_time` (including aborted ones) are excluded,
+    /// since their response times are not meaningful and summing them overflows.
+    fn finished_response_times(&self) -> impl Iterator<Item = i32> + '_ {
+        self.response_time
+            .iter()
+            .zip(self.finish_time.iter())
+            .filter(|(_, &finish_time)| finish_time != i32::MAX)
+            .map(|(&response_time, _)| response_time)
+    }
+
+    /// A DAG without finished DAG-level releases (e.g., a multi-rate DAG) has an average response time of 0.
     pub fn calculate_average_response_time(&mut self) {
-        self.average_response_time =
-            self.response_time.iter().sum::<i32>() as f32 / self.response_time.len() as f32;
+        let finished_response_times: Vec<i32> = self.finished_response_times().collect();
+        if finished_response_times.is_empty() {
+            self.average_response_time = 0.0;
+            return;
+        }
+        self.average_response_time = finished_response_times
+            .iter()
+            .map(|&response_time| response_time as i64)
+            .sum::<i64>() as f32
+            / finished_response_times.len() as f32;
     }
 
     pub fn calculate_worst_response_time(&mut self) {
-        self.worst_response_time = *self.response_time.iter().max().unwrap();
+        self.worst_response_time = self.finished_response_times().max().unwrap_or(0);
+    }
+
+    /// Nearest-rank percentiles of the response times, one for each of `ps` in percent (e.g., 99.0).
+    /// Unfinished instances are excluded as in `finished_response_times`,
+    /// since they are reported as deadline misses instead. Each percentile is 0 if no instance finished.
+    pub fn calculate_response_time_percentiles(&self, ps: &[f32]) -> Vec<i32> {
+        let mut finished_response_times: Vec<i32> = self.finished_response_times().collect();
+        finished_response_times.sort_unstable();
+        let len = finished_response_times.len();
+        ps.iter()
+            .map(|&p| {
+                if len == 0 {
+                    return 0;
+                }
+                let rank = (p.clamp(0.0, 100.0) / 100.0 * len as f32).ceil() as usize;
+                finished_response_times[rank.clamp(1, len) - 1]
+            })
+            .collect()
+    }
+
+    pub fn calculate_median_and_tail_response_time(&mut self) {
+        let percentiles = self.calculate_response_time_percentiles(&[50.0, 99.0]);
+        self.p50_response_time = percentiles[0];
+        self.p99_response_time = percentiles[1];
+    }
+
+    pub fn get_released_job_count(&self) -> i32 {
+        self.release_time.len() as i32
     }
 }
 
@@ -139,6 +305,40 @@ pub enum JobEventTimes {
     ResumeTime(i32),
     FinishTime(i32),
     PreemptedTime(i32),
+    AbortedTime(i32),
+}
+
+impl JobEventTimes {
+    /// Name of the variant, e.g., `StartTime`.
+    fn get_name(&self) -> &'static str {
+        match self {
+            JobEventTimes::StartTime(_) => "StartTime",
+            JobEventTimes::ResumeTime(_) => "ResumeTime",
+            JobEventTimes::FinishTime(_) => "FinishTime",
+            JobEventTimes::PreemptedTime(_) => "PreemptedTime",
+            JobEventTimes::AbortedTime(_) => "AbortedTime",
+        }
+    }
+
+    fn get_time(&self) -> i32 {
+        match *self {
+            JobEventTimes::StartTime(time)
+            | JobEventTimes::ResumeTime(time)
+            | JobEventTimes::FinishTime(time)
+            | JobEventTimes::PreemptedTime(time)
+            | JobEventTimes::AbortedTime(time) => time,
+        }
+    }
+
+    fn get_kind(&self) -> ScheduleEventKind {
+        match self {
+            JobEventTimes::StartTime(_) => ScheduleEventKind::Start,
+            JobEventTimes::ResumeTime(_) => ScheduleEventKind::Resume,
+            JobEventTimes::FinishTime(_) => ScheduleEventKind::Finish,
+            JobEventTimes::PreemptedTime(_) => ScheduleEventKind::Preempt,
+            JobEventTimes::AbortedTime(_) => ScheduleEventKind::Abort,
+        }
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -169,6 +369,161 @@ impl JobLog {
     }
 }
 
+/// Write the job events as CSV with the columns `dag_id,node_id,job_id,core_id,event,time`, one row per event
+/// in the order they were logged. `event` is the name of the `JobEventTimes` variant.
+fn dump_job_logs_to_csv<'a>(job_logs: impl Iterator<Item = &'a JobLog>, file_path: &str) {
+    let mut csv = String::from("dag_id,node_id,job_id,core_id,event,time\n");
+    for job_log in job_logs {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            job_log.dag_id,
+            job_log.node_id,
+            job_log.job_id,
+            job_log.core_id,
+            job_log.event_time.get_name(),
+            job_log.event_time.get_time()
+        ));
+    }
+    std::fs::write(file_path, csv).expect("Failed to write the CSV file.");
+}
+
+const GANTT_LANE_HEIGHT: i32 = 30;
+const GANTT_LABEL_WIDTH: i32 = 70;
+const GANTT_CHART_WIDTH: f32 = 1000.0;
+const GANTT_COLORS: [&str; 8] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+];
+
+/// Render the job intervals as an SVG Gantt chart with one lane per core.
+/// Each execution segment is a box labeled `dag_id:node_id` and colored by `dag_id`,
+/// so a preempted job appears as split boxes. The time axis is scaled to fit `GANTT_CHART_WIDTH`.
+fn render_gantt_svg(job_intervals: &[JobInterval], number_of_cores: usize) -> String {
+    let schedule_length = job_intervals
+        .iter()
+        .map(|job_interval| job_interval.end_time)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let scale = GANTT_CHART_WIDTH / schedule_length as f32;
+    let height = GANTT_LANE_HEIGHT * (number_of_cores as i32 + 1);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"sans-serif\" font-size=\"10\">\n",
+        GANTT_LABEL_WIDTH as f32 + GANTT_CHART_WIDTH,
+        height
+    );
+    for core_id in 0..number_of_cores {
+        let y = GANTT_LANE_HEIGHT * core_id as i32;
+        svg.push_str(&format!(
+            "  <text x=\"0\" y=\"{}\">core {}</text>\n",
+            y + GANTT_LANE_HEIGHT / 2 + 4,
+            core_id
+        ));
+        svg.push_str(&format!(
+            "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#cccccc\"/>\n",
+            GANTT_LABEL_WIDTH,
+            y + GANTT_LANE_HEIGHT,
+            GANTT_LABEL_WIDTH as f32 + GANTT_CHART_WIDTH,
+            y + GANTT_LANE_HEIGHT
+        ));
+    }
+    for job_interval in job_intervals {
+        let x = GANTT_LABEL_WIDTH as f32 + job_interval.start_time as f32 * scale;
+        let y = GANTT_LANE_HEIGHT * job_interval.core_id as i32 + 2;
+        let width = (job_interval.end_time - job_interval.start_time) as f32 * scale;
+        svg.push_str(&format!(
+            "  <rect x=\"{:.1}\" y=\"{}\" width=\"{:.1}\" height=\"{}\" fill=\"{}\" stroke=\"#333333\"/>\n",
+            x,
+            y,
+            width,
+            GANTT_LANE_HEIGHT - 4,
+            GANTT_COLORS[job_interval.dag_id % GANTT_COLORS.len()]
+        ));
+        svg.push_str(&format!(
+            "  <text x=\"{:.1}\" y=\"{}\">{}:{}</text>\n",
+            x + 2.0,
+            y + GANTT_LANE_HEIGHT / 2 + 2,
+            job_interval.dag_id,
+            job_interval.node_id
+        ));
+    }
+    svg.push_str(&format!(
+        "  <text x=\"{}\" y=\"{}\">0</text>\n  <text x=\"{:.1}\" y=\"{}\" text-anchor=\"end\">{}</text>\n",
+        GANTT_LABEL_WIDTH,
+        height - 10,
+        GANTT_LABEL_WIDTH as f32 + GANTT_CHART_WIDTH,
+        height - 10,
+        schedule_length
+    ));
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Execution interval of a job on a core, reconstructed from the job events.
+/// A preempted job is split into one interval per execution segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobInterval {
+    pub core_id: usize,
+    pub dag_id: usize,
+    pub node_id: usize,
+    pub job_id: usize,
+    pub start_time: i32,
+    pub end_time: i32,
+}
+
+/// Pair the start/resume events with the preempted/finish events of the same job.
+/// Segments that have not been closed by the end of the log are omitted.
+pub fn get_job_intervals(job_logs: &[JobLog]) -> Vec<JobInterval> {
+    let mut open_events: BTreeMap<(usize, usize, usize), (usize, i32)> = BTreeMap::new();
+    let mut job_intervals = Vec::new();
+    for job_log in job_logs {
+        let job_key = (job_log.dag_id, job_log.node_id, job_log.job_id);
+        match job_log.event_time {
+            JobEventTimes::StartTime(time) | JobEventTimes::ResumeTime(time) => {
+                open_events.insert(job_key, (job_log.core_id, time));
+            }
+            JobEventTimes::PreemptedTime(time)
+            | JobEventTimes::FinishTime(time)
+            | JobEventTimes::AbortedTime(time) => {
+                if let Some((core_id, start_time)) = open_events.remove(&job_key) {
+                    job_intervals.push(JobInterval {
+                        core_id,
+                        dag_id: job_log.dag_id,
+                        node_id: job_log.node_id,
+                        job_id: job_log.job_id,
+                        start_time,
+                        end_time: time,
+                    });
+                }
+            }
+        }
+    }
+    job_intervals
+}
+
+/// Maximum number of intervals overlapping at any tick. Warn if it never reaches `number_of_cores`, i.e., over-provisioned.
+fn calculate_peak_concurrency(job_intervals: &[JobInterval], number_of_cores: usize) -> usize {
+    // At the same time, an interval ends before another starts.
+    let mut events: Vec<(i32, i32)> = job_intervals
+        .iter()
+        .flat_map(|job_interval| [(job_interval.start_time, 1), (job_interval.end_time, -1)])
+        .collect();
+    events.sort();
+    let mut concurrency = 0;
+    let mut peak_concurrency = 0;
+    for (_, delta) in events {
+        concurrency += delta;
+        peak_concurrency = peak_concurrency.max(concurrency);
+    }
+    let peak_concurrency = peak_concurrency as usize;
+    if peak_concurrency < number_of_cores {
+        warn!(
+            "At most {} of {} cores were busy simultaneously. The processor is over-provisioned.",
+            peak_concurrency, number_of_cores
+        );
+    }
+    peak_concurrency
+}
+
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct ProcessorLog {
     average_utilization: f32,
@@ -186,6 +541,10 @@ impl ProcessorLog {
     }
 
     fn calculate_average_utilization(&mut self) {
+        if self.core_logs.is_empty() {
+            self.average_utilization = 0.0;
+            return;
+        }
         self.average_utilization = self
             .core_logs
             .iter()
@@ -195,6 +554,10 @@ impl ProcessorLog {
     }
 
     fn calculate_variance_utilization(&mut self) {
+        if self.core_logs.is_empty() {
+            self.variance_utilization = 0.0;
+            return;
+        }
         self.variance_utilization = self
             .core_logs
             .iter()
@@ -204,6 +567,12 @@ impl ProcessorLog {
     }
 
     fn calculate_cores_utilization(&mut self, schedule_length: i32) {
+        if schedule_length <= 0 {
+            warn!(
+                "Schedule length is {}. The utilization of all cores is set to 0.",
+                schedule_length
+            );
+        }
         for core_log in self.core_logs.iter_mut() {
             core_log.calculate_utilization(schedule_length);
         }
@@ -227,16 +596,40 @@ impl CoreLog {
     }
 
     fn calculate_utilization(&mut self, schedule_length: i32) {
-        self.utilization = self.total_proc_time as f32 / schedule_length as f32;
+        self.utilization = if schedule_length > 0 {
+            self.total_proc_time as f32 / schedule_length as f32
+        } else {
+            0.0
+        };
     }
 }
 
+/// How much of a scheduler log is dumped.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LogVerbosity {
+    /// Only the DAG, processor and response time information, without the per-job event logs.
+    Summary,
+    #[default]
+    Full,
+}
+
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct DAGSchedulerLog {
     dag_info: DAGInfo,
     processor_info: ProcessorInfo,
     node_logs: Vec<JobLog>,
     processor_log: ProcessorLog,
+    /// Energy consumed by the processor during the schedule (see `ProcessorBase::get_consumed_energy`).
+    #[serde(default)]
+    total_energy: f64,
+}
+
+#[derive(Serialize)]
+struct DAGSchedulerLogSummary<'a> {
+    dag_info: &'a DAGInfo,
+    processor_info: &'a ProcessorInfo,
+    processor_log: &'a ProcessorLog,
+    total_energy: f64,
 }
 
 impl DAGSchedulerLog {
@@ -246,6 +639,7 @@ impl DAGSchedulerLog {
             processor_info: ProcessorInfo::new(num_cores),
             node_logs: Vec::new(),
             processor_log: ProcessorLog::new(num_cores),
+            total_energy: 0.0,
         }
     }
 
@@ -289,9 +683,120 @@ impl DAGSchedulerLog {
         self.processor_log.calculate_variance_utilization();
     }
 
+    pub fn get_job_intervals(&self) -> Vec<JobInterval> {
+        get_job_intervals(&self.node_logs)
+    }
+
+    pub fn write_total_energy(&mut self, total_energy: f64) {
+        self.total_energy = total_energy;
+    }
+
+    pub fn get_total_energy(&self) -> f64 {
+        self.total_energy
+    }
+
+    /// Maximum number of cores busy simultaneously at any tick during the schedule.
+    pub fn peak_concurrency(&self) -> usize {
+        calculate_peak_concurrency(
+            &self.get_job_intervals(),
+            self.processor_info.number_of_cores,
+        )
+    }
+
     pub fn dump_log_to_yaml(&self, file_path: &str) {
-        dump_struct(file_path, self);
+        self.dump_log_to_yaml_with_verbosity(file_path, LogVerbosity::Full);
     }
+
+    /// Dump the job events of `node_logs` as CSV (see `dump_job_logs_to_csv`).
+    pub fn dump_job_logs_to_csv(&self, file_path: &str) {
+        dump_job_logs_to_csv(self.node_logs.iter(), file_path);
+    }
+
+    /// Dump the schedule as an SVG Gantt chart with one lane per core (see `render_gantt_svg`).
+    pub fn dump_gantt_svg(&self, file_path: &str) {
+        let svg = render_gantt_svg(
+            &self.get_job_intervals(),
+            self.processor_info.number_of_cores,
+        );
+        std::fs::write(file_path, svg).expect("Failed to write the SVG file.");
+    }
+
+    pub fn dump_log_to_yaml_with_verbosity(&self, file_path: &str, verbosity: LogVerbosity) {
+        match verbosity {
+            LogVerbosity::Full => dump_struct(file_path, self),
+            LogVerbosity::Summary => dump_struct(
+                file_path,
+                &DAGSchedulerLogSummary {
+                    dag_info: &self.dag_info,
+                    processor_info: &self.processor_info,
+                    processor_log: &self.processor_log,
+                    total_energy: self.total_energy,
+                },
+            ),
+        }
+    }
+}
+
+/// Instrumentation of a scheduling run, for spotting pathological inputs and performance regressions of the simulator.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationStats {
+    /// Simulated time at which the scheduling finished, i.e., the hyper-period for a complete run.
+    pub simulated_time: i32,
+    /// Number of unit times processed.
+    pub ticks: i32,
+    /// Number of job events (start, resume, preempted, finish and aborted) written to the log.
+    pub events: i32,
+    /// Wall-clock duration of `schedule`. None if it was not measured.
+    pub wall_clock_time: Option<Duration>,
+}
+
+/// A DAG instance that missed its deadline.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeadlineMiss {
+    pub dag_id: usize,
+    pub job_id: usize,
+    pub deadline: i32,
+    /// None if the instance had not finished by the end of the log.
+    pub finish_time: Option<i32>,
+    /// `finish_time` - `deadline`. None if the instance had not finished.
+    pub overrun: Option<i32>,
+}
+
+/// A node that was ready at the end of the simulation but had never been dispatched in its job.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StarvedNode {
+    pub dag_id: usize,
+    pub node_id: usize,
+    pub job_id: usize,
+}
+
+/// Why a DAG instance missed its deadline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeadlineMissCause {
+    /// A node of the instance was ready but had never been dispatched by the end of the simulation.
+    Starved,
+    /// Every ready node of the instance was dispatched, but the instance did not finish in time.
+    RanLate,
+}
+
+/// Kind of a job event in `get_schedule_events`.
+/// The variants ending a run come first, so that at the same time and core they sort before the next run starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScheduleEventKind {
+    Finish,
+    Preempt,
+    Abort,
+    Start,
+    Resume,
+}
+
+/// A job event of `get_schedule_events`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduleEvent {
+    pub dag_id: usize,
+    pub node_id: usize,
+    pub job_id: usize,
+    pub kind: ScheduleEventKind,
 }
 
 #[derive(Clone, Default, Serialize, Deserialize)]
@@ -301,6 +806,28 @@ pub struct DAGSetSchedulerLog {
     dag_set_log: Vec<DAGLog>,
     node_set_logs: Vec<Vec<JobLog>>,
     processor_log: ProcessorLog,
+    // Not dumped because the wall-clock time is not reproducible.
+    #[serde(skip)]
+    simulation_stats: SimulationStats,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deadline_misses: Option<Vec<DeadlineMiss>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    energy_by_state: Option<BTreeMap<PowerState, f64>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    starved_nodes: Vec<StarvedNode>,
+}
+
+#[derive(Serialize)]
+struct DAGSetSchedulerLogSummary<'a> {
+    dag_set_info: &'a DAGSetInfo,
+    processor_info: &'a ProcessorInfo,
+    dag_set_log: &'a [DAGLog],
+    processor_log: &'a ProcessorLog,
+}
+
+#[derive(Serialize)]
+struct PerCoreSchedule<'a> {
+    per_core_schedule: &'a [Vec<JobLog>],
 }
 
 impl DAGSetSchedulerLog {
@@ -316,6 +843,10 @@ impl DAGSetSchedulerLog {
             dag_set_log,
             node_set_logs: vec![Vec::new(); dag_set.len()],
             processor_log: ProcessorLog::new(num_cores),
+            simulation_stats: Default::default(),
+            deadline_misses: None,
+            energy_by_state: None,
+            starved_nodes: Vec::new(),
         }
     }
 
@@ -327,6 +858,38 @@ impl DAGSetSchedulerLog {
         self.dag_set_log[dag_id].finish_time.push(finish_time);
     }
 
+    pub fn write_separation_delay(&mut self, dag_id: usize, delay: i32) {
+        self.dag_set_log[dag_id].separation_delay.push(delay);
+    }
+
+    pub fn get_separation_delays(&self, dag_id: usize) -> &[i32] {
+        &self.dag_set_log[dag_id].separation_delay
+    }
+
+    pub fn get_finish_times(&self, dag_id: usize) -> &[i32] {
+        &self.dag_set_log[dag_id].finish_time
+    }
+
+    /// Response time of each instance. Available after `calculate_response_time`.
+    pub fn get_response_times(&self, dag_id: usize) -> &[i32] {
+        &self.dag_set_log[dag_id].response_time
+    }
+
+    pub fn get_release_times(&self, dag_id: usize) -> &[i32] {
+        &self.dag_set_log[dag_id].release_time
+    }
+
+    /// Record the instance as aborted. Its finish time is i32::MAX, i.e., it is regarded as not finished.
+    pub fn write_dag_abort(&mut self, dag_id: usize, job_id: usize) {
+        let dag_log = &mut self.dag_set_log[dag_id];
+        dag_log.finish_time.push(i32::MAX);
+        dag_log.aborted_job_ids.push(job_id);
+    }
+
+    pub fn get_aborted_job_ids(&self, dag_id: usize) -> &[usize] {
+        &self.dag_set_log[dag_id].aborted_job_ids
+    }
+
     pub fn write_allocating_job(
         &mut self,
         node_data: &NodeData,
@@ -361,12 +924,27 @@ impl DAGSetSchedulerLog {
         let dag_id = node_data.get_params_value("dag_id") as usize;
         let job_log = JobLog::new(core_id, dag_id, node_data.id as usize, job_id, event_time);
         self.node_set_logs[dag_id].push(job_log);
+        self.simulation_stats.events += 1;
     }
 
+    /// Called once per unit time, so it also counts the ticks of the simulation.
     pub fn write_processing_time(&mut self, core_indices: &[usize]) {
         for core_index in core_indices {
             self.processor_log.core_logs[*core_index].total_proc_time += 1;
         }
+        self.simulation_stats.ticks += 1;
+    }
+
+    pub fn set_simulated_time(&mut self, simulated_time: i32) {
+        self.simulation_stats.simulated_time = simulated_time;
+    }
+
+    pub fn set_wall_clock_time(&mut self, wall_clock_time: Duration) {
+        self.simulation_stats.wall_clock_time = Some(wall_clock_time);
+    }
+
+    pub fn get_simulation_stats(&self) -> &SimulationStats {
+        &self.simulation_stats
     }
 
     pub fn calculate_response_time(&mut self) {
@@ -374,6 +952,7 @@ impl DAGSetSchedulerLog {
             dag_log.calculate_response_time();
             dag_log.calculate_average_response_time();
             dag_log.calculate_worst_response_time();
+            dag_log.calculate_median_and_tail_response_time();
         }
     }
 
@@ -385,14 +964,333 @@ impl DAGSetSchedulerLog {
     }
 
     pub fn dump_log_to_yaml(&self, file_path: &str) {
-        dump_struct(file_path, self);
+        self.dump_log_to_yaml_with_verbosity(file_path, LogVerbosity::Full);
+    }
+
+    /// Dump the job events of `node_set_logs` as CSV (see `dump_job_logs_to_csv`), DAG by DAG.
+    pub fn dump_job_logs_to_csv(&self, file_path: &str) {
+        dump_job_logs_to_csv(self.node_set_logs.iter().flatten(), file_path);
+    }
+
+    /// Dump the schedule as an SVG Gantt chart with one lane per core (see `render_gantt_svg`).
+    pub fn dump_gantt_svg(&self, file_path: &str) {
+        let svg = render_gantt_svg(
+            &self.get_job_intervals(),
+            self.processor_info.number_of_cores,
+        );
+        std::fs::write(file_path, svg).expect("Failed to write the SVG file.");
+    }
+
+    /// `LogVerbosity::Summary` omits `node_set_logs`, which dominate the file size of long simulations.
+    pub fn dump_log_to_yaml_with_verbosity(&self, file_path: &str, verbosity: LogVerbosity) {
+        match verbosity {
+            LogVerbosity::Full => dump_struct(file_path, self),
+            LogVerbosity::Summary => dump_struct(
+                file_path,
+                &DAGSetSchedulerLogSummary {
+                    dag_set_info: &self.dag_set_info,
+                    processor_info: &self.processor_info,
+                    dag_set_log: &self.dag_set_log,
+                    processor_log: &self.processor_log,
+                },
+            ),
+        }
+    }
+
+    /// Return the earliest `(dag_id, absolute_deadline)` at which a DAG instance missed its deadline.
+    /// An instance that has not finished is also regarded as a miss.
+    /// The deadline is the end-to-end deadline of each DAG, or the period if it is not set.
+    pub fn first_deadline_miss(&self, dag_set: &[Graph<NodeData, i32>]) -> Option<(usize, i32)> {
+        self.get_all_deadline_misses(dag_set)
+            .iter()
+            .map(|deadline_miss| (deadline_miss.dag_id, deadline_miss.deadline))
+            .min_by_key(|&(dag_id, miss_time)| (miss_time, dag_id))
+    }
+
+    /// Return every DAG instance that missed its deadline, in the order of dag_id and job_id.
+    /// The deadline is determined as in `first_deadline_miss`.
+    pub fn get_all_deadline_misses(&self, dag_set: &[Graph<NodeData, i32>]) -> Vec<DeadlineMiss> {
+        self.dag_set_log
+            .iter()
+            .flat_map(|dag_log| {
+                let dag = &dag_set[dag_log.dag_id];
+                let relative_deadline = dag
+                    .get_end_to_end_deadline()
+                    .or_else(|| dag.get_head_period())
+                    .unwrap();
+                dag_log
+                    .release_time
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(job_id, release_time)| {
+                        let deadline = release_time + relative_deadline;
+                        // calculate_response_time marks an unfinished instance with i32::MAX.
+                        let finish_time = dag_log
+                            .finish_time
+                            .get(job_id)
+                            .copied()
+                            .filter(|&finish_time| finish_time != i32::MAX);
+                        match finish_time {
+                            Some(finish_time) if finish_time <= deadline => None,
+                            _ => Some(DeadlineMiss {
+                                dag_id: dag_log.dag_id,
+                                job_id,
+                                deadline,
+                                finish_time,
+                                overrun: finish_time.map(|finish_time| finish_time - deadline),
+                            }),
+                        }
+                    })
+            })
+            .collect()
+    }
+
+    pub fn write_starved_nodes(&mut self, starved_nodes: Vec<StarvedNode>) {
+        self.starved_nodes = starved_nodes;
+    }
+
+    /// Nodes that were ready but had never been dispatched at the end of the simulation.
+    pub fn get_starved_nodes(&self) -> &[StarvedNode] {
+        &self.starved_nodes
+    }
+
+    /// Distinguish an instance starved in the ready queue from one that ran but finished late.
+    pub fn get_deadline_miss_cause(&self, deadline_miss: &DeadlineMiss) -> DeadlineMissCause {
+        let is_starved = self.starved_nodes.iter().any(|starved_node| {
+            starved_node.dag_id == deadline_miss.dag_id
+                && starved_node.job_id == deadline_miss.job_id
+        });
+        if is_starved {
+            DeadlineMissCause::Starved
+        } else {
+            DeadlineMissCause::RanLate
+        }
+    }
+
+    /// Include the energy consumed in each power state (see `HomogeneousProcessor::get_energy_by_state`) in the dumped log.
+    pub fn write_energy_by_state(&mut self, energy_by_state: BTreeMap<PowerState, f64>) {
+        self.energy_by_state = Some(energy_by_state);
+    }
+
+    /// Include the deadline misses in the dumped log.
+    pub fn write_deadline_misses(&mut self, dag_set: &[Graph<NodeData, i32>]) {
+        self.deadline_misses = Some(self.get_all_deadline_misses(dag_set));
+    }
+
+    /// Number of jobs actually released for the DAG. Compare with `util::expected_job_count` to detect release bugs.
+    pub fn get_released_job_count(&self, dag_id: usize) -> i32 {
+        self.dag_set_log[dag_id].get_released_job_count()
+    }
+
+    /// Number of preemptions of all jobs in the schedule.
+    pub fn total_preemptions(&self) -> usize {
+        self.get_preemptions_per_dag().iter().sum()
+    }
+
+    /// Number of preemptions of the jobs of each DAG, indexed by dag_id.
+    pub fn get_preemptions_per_dag(&self) -> Vec<usize> {
+        self.node_set_logs
+            .iter()
+            .map(|job_logs| {
+                job_logs
+                    .iter()
+                    .filter(|job_log| matches!(job_log.event_time, JobEventTimes::PreemptedTime(_)))
+                    .count()
+            })
+            .collect()
+    }
+
+    pub fn get_job_intervals(&self) -> Vec<JobInterval> {
+        self.node_set_logs
+            .iter()
+            .flat_map(|job_logs| get_job_intervals(job_logs))
+            .collect()
+    }
+
+    /// Job events regrouped by core, indexed by core_id. The events of each core are in the order of time,
+    /// and at the same time, the end of a run comes before the start of the next one (see `ScheduleEventKind`).
+    pub fn get_per_core_schedule(&self) -> Vec<Vec<JobLog>> {
+        let mut per_core_schedule = vec![Vec::new(); self.processor_info.number_of_cores];
+        for job_log in self.node_set_logs.iter().flatten() {
+            per_core_schedule[job_log.core_id].push(job_log.clone());
+        }
+        for job_logs in per_core_schedule.iter_mut() {
+            job_logs.sort_by_key(|job_log| {
+                (job_log.event_time.get_time(), job_log.event_time.get_kind())
+            });
+        }
+        per_core_schedule
+    }
+
+    /// Dump `get_per_core_schedule` under the `per_core_schedule` key, i.e., one timeline per core.
+    pub fn dump_per_core_schedule_to_yaml(&self, file_path: &str) {
+        dump_struct(
+            file_path,
+            &PerCoreSchedule {
+                per_core_schedule: &self.get_per_core_schedule(),
+            },
+        );
+    }
+
+    /// All job events as (time, core_id, event), sorted by time and then by core.
+    /// At the same time and core, the end of a run comes before the start of the next one.
+    pub fn get_schedule_events(&self) -> Vec<(i32, usize, ScheduleEvent)> {
+        let mut schedule_events: Vec<_> = self
+            .node_set_logs
+            .iter()
+            .flatten()
+            .map(|job_log| {
+                (
+                    job_log.event_time.get_time(),
+                    job_log.core_id,
+                    ScheduleEvent {
+                        dag_id: job_log.dag_id,
+                        node_id: job_log.node_id,
+                        job_id: job_log.job_id,
+                        kind: job_log.event_time.get_kind(),
+                    },
+                )
+            })
+            .collect();
+        schedule_events.sort_by_key(|&(time, core_id, event)| (time, core_id, event.kind));
+        schedule_events
+    }
+
+    /// Communication time actually incurred in the schedule: the sum of the edge weights over all jobs
+    /// whose producer and consumer ran on different cores. The producer is placed on the core where it finished,
+    /// and the consumer on the core where it started. Edges of jobs that did not run are not counted.
+    pub fn total_communication_cost(&self, dag_set: &[Graph<NodeData, i32>]) -> i32 {
+        let mut start_cores = BTreeMap::new();
+        let mut finish_cores = BTreeMap::new();
+        for job_log in self.node_set_logs.iter().flatten() {
+            let job_key = (job_log.dag_id, job_log.node_id, job_log.job_id);
+            match job_log.event_time {
+                JobEventTimes::StartTime(_) => {
+                    start_cores.insert(job_key, job_log.core_id);
+                }
+                JobEventTimes::FinishTime(_) => {
+                    finish_cores.insert(job_key, job_log.core_id);
+                }
+                _ => {}
+            }
+        }
+
+        let mut total_communication_cost = 0;
+        for (dag_id, dag) in dag_set.iter().enumerate() {
+            for edge in dag.raw_edges() {
+                let producer_id = dag[edge.source()].id as usize;
+                let consumer_id = dag[edge.target()].id as usize;
+                for (&(_, _, job_id), &consumer_core) in
+                    start_cores.range((dag_id, consumer_id, 0)..=(dag_id, consumer_id, usize::MAX))
+                {
+                    if finish_cores
+                        .get(&(dag_id, producer_id, job_id))
+                        .is_some_and(|&producer_core| producer_core != consumer_core)
+                    {
+                        total_communication_cost += edge.weight;
+                    }
+                }
+            }
+        }
+        total_communication_cost
+    }
+
+    /// For each `(dag_id, node_id)`, the fraction of its jobs that started on its most common core.
+    /// 1.0 means that the node always ran on the same core. Nodes that never started are omitted.
+    pub fn core_affinity_report(&self) -> HashMap<(usize, usize), f32> {
+        let mut start_cores = BTreeMap::new();
+        for job_log in self.node_set_logs.iter().flatten() {
+            if let JobEventTimes::StartTime(_) = job_log.event_time {
+                start_cores
+                    .entry((job_log.dag_id, job_log.node_id, job_log.job_id))
+                    .or_insert(job_log.core_id);
+            }
+        }
+
+        let mut core_counts: BTreeMap<(usize, usize), BTreeMap<usize, usize>> = BTreeMap::new();
+        for ((dag_id, node_id, _), core_id) in start_cores {
+            *core_counts
+                .entry((dag_id, node_id))
+                .or_default()
+                .entry(core_id)
+                .or_default() += 1;
+        }
+        core_counts
+            .into_iter()
+            .map(|(node_key, counts)| {
+                let job_count: usize = counts.values().sum();
+                let most_common_count = counts.values().max().unwrap();
+                (node_key, *most_common_count as f32 / job_count as f32)
+            })
+            .collect()
+    }
+
+    /// Maximum number of cores busy simultaneously at any tick during the schedule.
+    pub fn peak_concurrency(&self) -> usize {
+        calculate_peak_concurrency(
+            &self.get_job_intervals(),
+            self.processor_info.number_of_cores,
+        )
+    }
+
+    /// Aggregate utilization of all cores in successive windows of length `window`.
+    /// Each element is `(window_start_time, utilization)`, covering up to the last job event.
+    pub fn windowed_utilization(&self, window: i32) -> Vec<(i32, f32)> {
+        assert!(window > 0, "The window length must be positive.");
+        let job_intervals = self.get_job_intervals();
+        let schedule_end = job_intervals
+            .iter()
+            .map(|job_interval| job_interval.end_time)
+            .max()
+            .unwrap_or(0);
+        let window_capacity = (window * self.processor_info.number_of_cores as i32) as f32;
+
+        (0..schedule_end)
+            .step_by(window as usize)
+            .map(|window_start| {
+                let window_end = window_start + window;
+                let busy_time: i32 = job_intervals
+                    .iter()
+                    .map(|job_interval| {
+                        (job_interval.end_time.min(window_end)
+                            - job_interval.start_time.max(window_start))
+                        .max(0)
+                    })
+                    .sum();
+                (window_start, busy_time as f32 / window_capacity)
+            })
+            .collect()
     }
 }
 
+/// Dump the schedule in the Trace Event Format (JSON) viewable in Perfetto or chrome://tracing.
+/// Each execution segment of a job becomes a complete event with `pid = dag_id`, `tid = core_id` and `name = node_id`,
+/// so a preempted job is split into multiple slices. One time unit of the simulation is shown as one microsecond.
+pub fn dump_chrome_trace(log: &DAGSetSchedulerLog, file_path: &str) {
+    let trace_events: Vec<String> = log
+        .get_job_intervals()
+        .iter()
+        .map(|job_interval| {
+            format!(
+                "{{\"name\":\"{}\",\"cat\":\"job\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":{},\"tid\":{},\"args\":{{\"job_id\":{}}}}}",
+                job_interval.node_id,
+                job_interval.start_time,
+                job_interval.end_time - job_interval.start_time,
+                job_interval.dag_id,
+                job_interval.core_id,
+                job_interval.job_id
+            )
+        })
+        .collect();
+    let json = format!("{{\"traceEvents\":[\n{}\n]}}\n", trace_events.join(",\n"));
+    std::fs::write(file_path, json).expect("Failed to write the chrome trace file.");
+}
+
 #[derive(Serialize, Deserialize)]
 struct DAGSchedulerResultInfo {
     schedule_length: i32,
     period_factor: f32,
+    speedup: f32,
     result: bool,
 }
 
@@ -400,22 +1298,768 @@ pub fn dump_dag_scheduler_result_to_yaml(
     file_path: &str,
     schedule_length: i32,
     period_factor: f32,
+    speedup: f32,
     result: bool,
 ) {
     let result_info = DAGSchedulerResultInfo {
         schedule_length,
         period_factor,
+        speedup,
         result,
     };
     dump_struct(file_path, &result_info);
 }
 
+pub fn dump_dag_scheduler_result_to_writer(
+    writer: &mut impl Write,
+    schedule_length: i32,
+    period_factor: f32,
+    speedup: f32,
+    result: bool,
+) {
+    let result_info = DAGSchedulerResultInfo {
+        schedule_length,
+        period_factor,
+        speedup,
+        result,
+    };
+    dump_struct_to_writer(writer, &result_info);
+}
+
 #[derive(Serialize, Deserialize)]
 struct DAGSetSchedulerResultInfo {
     result: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_deadline_misses: Option<usize>,
+    /// Whether each DAG missed a deadline at least once, indexed by dag_id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_dag_missed: Option<Vec<bool>>,
+}
+
+impl DAGSetSchedulerResultInfo {
+    fn new(result: bool) -> Self {
+        Self {
+            result,
+            num_deadline_misses: None,
+            per_dag_missed: None,
+        }
+    }
+
+    /// The result is schedulable iff there is no deadline miss.
+    fn with_misses(deadline_misses: &[DeadlineMiss], number_of_dags: usize) -> Self {
+        let mut per_dag_missed = vec![false; number_of_dags];
+        for deadline_miss in deadline_misses {
+            per_dag_missed[deadline_miss.dag_id] = true;
+        }
+        Self {
+            result: deadline_misses.is_empty(),
+            num_deadline_misses: Some(deadline_misses.len()),
+            per_dag_missed: Some(per_dag_missed),
+        }
+    }
 }
 
 pub fn dump_dag_set_scheduler_result_to_yaml(file_path: &str, result: bool) {
-    let result_info = DAGSetSchedulerResultInfo { result };
-    dump_struct(file_path, &result_info);
+    dump_struct(file_path, &DAGSetSchedulerResultInfo::new(result));
+}
+
+pub fn dump_dag_set_scheduler_result_to_writer(writer: &mut impl Write, result: bool) {
+    dump_struct_to_writer(writer, &DAGSetSchedulerResultInfo::new(result));
+}
+
+/// Dump the result together with the number of deadline misses and which DAGs missed,
+/// e.g., from `DAGSetSchedulerLog::get_all_deadline_misses`.
+pub fn dump_dag_set_scheduler_result_with_misses(
+    file_path: &str,
+    deadline_misses: &[DeadlineMiss],
+    number_of_dags: usize,
+) {
+    dump_struct(
+        file_path,
+        &DAGSetSchedulerResultInfo::with_misses(deadline_misses, number_of_dags),
+    );
+}
+
+pub fn dump_dag_set_scheduler_result_with_misses_to_writer(
+    writer: &mut impl Write,
+    deadline_misses: &[DeadlineMiss],
+    number_of_dags: usize,
+) {
+    dump_struct_to_writer(
+        writer,
+        &DAGSetSchedulerResultInfo::with_misses(deadline_misses, number_of_dags),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::graph::NodeIndex;
+    use std::fs::{read_to_string, remove_file};
+    use yaml_rust::YamlLoader;
+
+    fn create_node(id: i32, dag_id: i32) -> NodeData {
+        NodeData::with_params(id, &[("execution_time", 1), ("dag_id", dag_id)])
+    }
+
+    fn create_dag_set(num_dags: usize) -> Vec<Graph<NodeData, i32>> {
+        (0..num_dags)
+            .map(|dag_id| {
+                let mut dag = Graph::<NodeData, i32>::new();
+                let mut node = create_node(0, dag_id as i32);
+                node.params.insert("period".to_string(), 100);
+                dag.add_node(node);
+                dag
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dump_struct_to_writer_normal() {
+        let dag_set = create_dag_set(2);
+        let mut log = DAGSetSchedulerLog::new(&dag_set, 2);
+        log.write_energy_by_state(BTreeMap::from([(PowerState::Active, 1.5)]));
+        let mut output = Vec::new();
+        dump_struct_to_writer(&mut output, &log);
+        dump_dag_set_scheduler_result_to_writer(&mut output, true);
+
+        let yaml_docs = YamlLoader::load_from_str(&String::from_utf8(output).unwrap()).unwrap();
+        let yaml_doc = &yaml_docs[0];
+        assert_eq!(
+            yaml_doc["processor_info"]["number_of_cores"]
+                .as_i64()
+                .unwrap(),
+            2
+        );
+        assert_eq!(yaml_doc["dag_set_log"].as_vec().unwrap().len(), 2);
+        assert_eq!(yaml_doc["energy_by_state"]["Active"].as_f64().unwrap(), 1.5);
+        assert!(yaml_doc["result"].as_bool().unwrap());
+    }
+
+    #[test]
+    fn test_dump_dag_set_scheduler_result_with_misses() {
+        let dag_set = create_dag_set(3);
+        let mut log = DAGSetSchedulerLog::new(&dag_set, 2);
+        for dag_id in 0..3 {
+            log.write_dag_release_time(dag_id, 0);
+        }
+        log.write_dag_finish_time(0, 50);
+        log.write_dag_finish_time(1, 150);
+        // DAG 2 has not finished, which is also a miss.
+        let deadline_misses = log.get_all_deadline_misses(&dag_set);
+
+        let mut output = Vec::new();
+        dump_dag_set_scheduler_result_with_misses_to_writer(
+            &mut output,
+            &deadline_misses,
+            dag_set.len(),
+        );
+        let yaml_docs = YamlLoader::load_from_str(&String::from_utf8(output).unwrap()).unwrap();
+
+        assert!(!yaml_docs[0]["result"].as_bool().unwrap());
+        assert_eq!(yaml_docs[0]["num_deadline_misses"].as_i64().unwrap(), 2);
+        let per_dag_missed: Vec<bool> = yaml_docs[0]["per_dag_missed"]
+            .as_vec()
+            .unwrap()
+            .iter()
+            .map(|missed| missed.as_bool().unwrap())
+            .collect();
+        assert_eq!(per_dag_missed, vec![false, true, true]);
+
+        // The format without deadline misses is kept as is.
+        let mut output = Vec::new();
+        dump_dag_set_scheduler_result_to_writer(&mut output, true);
+        let yaml_docs = YamlLoader::load_from_str(&String::from_utf8(output).unwrap()).unwrap();
+        assert!(yaml_docs[0]["num_deadline_misses"].is_badvalue());
+        assert!(yaml_docs[0]["per_dag_missed"].is_badvalue());
+    }
+
+    #[test]
+    fn test_dag_set_info_max_density() {
+        let mut dag_set = create_dag_set(2);
+        dag_set[1][NodeIndex::new(0)]
+            .params
+            .insert("end_to_end_deadline".to_string(), 4);
+        let dag_set_info = DAGSetInfo::new(&dag_set);
+
+        assert_eq!(dag_set_info.each_dag_info[0].density, 0.01);
+        assert_eq!(dag_set_info.each_dag_info[1].density, 0.25);
+        assert_eq!(dag_set_info.max_density, 0.25);
+    }
+
+    #[test]
+    fn test_dump_dag_set_info_latex_normal() {
+        let dag_set = create_dag_set(3);
+        let file_path = "tests/dag_set_info_latex_test.tex";
+        dump_dag_set_info_latex(&DAGSetInfo::new(&dag_set), file_path);
+        let latex = read_to_string(file_path).unwrap();
+        remove_file(file_path).unwrap();
+
+        assert!(latex.starts_with("\\begin{tabular}"));
+        assert!(latex
+            .contains("id & critical path length & period & deadline & volume & utilization \\\\"));
+        let data_rows: Vec<&str> = latex
+            .lines()
+            .filter(|line| line.ends_with("\\\\") && !line.starts_with("id"))
+            .collect();
+        assert_eq!(data_rows.len(), 3);
+        assert!(data_rows[2].starts_with("2 & 1 & 100 & 0 & 1 &"));
+    }
+
+    #[test]
+    fn test_dump_schedulability_sweep_csv_normal() {
+        let file_path = "tests/schedulability_sweep_test.csv";
+        dump_schedulability_sweep_csv(&[(0.2, 1.0), (1.5, 0.5)], file_path);
+        let csv = read_to_string(file_path).unwrap();
+        remove_file(file_path).unwrap();
+
+        assert_eq!(
+            csv,
+            "utilization,schedulable_fraction\n0.20,1.000\n1.50,0.500\n"
+        );
+    }
+
+    #[test]
+    fn test_dump_adjacency_matrix_non_contiguous_ids() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, 0));
+        let n5 = dag.add_node(create_node(5, 0));
+        let n2 = dag.add_node(create_node(2, 0));
+        dag.add_edge(n0, n5, 3);
+        dag.add_edge(n0, n2, 1);
+        dag.add_edge(n2, n5, 7);
+
+        let file_path = "tests/adjacency_matrix_test.txt";
+        dump_adjacency_matrix(&dag, file_path);
+        let text = read_to_string(file_path).unwrap();
+        remove_file(file_path).unwrap();
+
+        // Rows and columns are ids 0, 2 and 5.
+        assert_eq!(text, "0 1 3\n0 0 7\n0 0 0\n");
+    }
+
+    #[test]
+    fn test_calculate_utilization_no_nan() {
+        let mut processor_log = ProcessorLog::new(0);
+        processor_log.calculate_cores_utilization(10);
+        processor_log.calculate_average_utilization();
+        processor_log.calculate_variance_utilization();
+        assert_eq!(processor_log.average_utilization, 0.0);
+        assert_eq!(processor_log.variance_utilization, 0.0);
+
+        let mut core_log = CoreLog::new(0);
+        core_log.calculate_utilization(0);
+        assert_eq!(core_log.utilization, 0.0);
+    }
+
+    #[test]
+    fn test_first_deadline_miss_normal() {
+        let mut dag_set = create_dag_set(2);
+        dag_set[0][NodeIndex::new(0)]
+            .params
+            .insert("end_to_end_deadline".to_string(), 50);
+        let mut log = DAGSetSchedulerLog::new(&dag_set, 1);
+        // DAG 0 (deadline 50): meets at 0, misses the instance released at 100.
+        log.write_dag_release_time(0, 0);
+        log.write_dag_finish_time(0, 40);
+        log.write_dag_release_time(0, 100);
+        log.write_dag_finish_time(0, 160);
+        // DAG 1 (period 100): the instance released at 100 has not finished.
+        log.write_dag_release_time(1, 0);
+        log.write_dag_finish_time(1, 90);
+        log.write_dag_release_time(1, 100);
+
+        assert_eq!(log.first_deadline_miss(&dag_set), Some((0, 150)));
+    }
+
+    #[test]
+    fn test_get_all_deadline_misses_two_misses() {
+        let mut dag_set = create_dag_set(2);
+        dag_set[0][NodeIndex::new(0)]
+            .params
+            .insert("end_to_end_deadline".to_string(), 50);
+        let mut log = DAGSetSchedulerLog::new(&dag_set, 1);
+        // DAG 0 (deadline 50): meets at 0, overruns by 10 the instance released at 100.
+        log.write_dag_release_time(0, 0);
+        log.write_dag_finish_time(0, 40);
+        log.write_dag_release_time(0, 100);
+        log.write_dag_finish_time(0, 160);
+        // DAG 1 (period 100): overruns by 5 the instance released at 0, and meets at 100.
+        log.write_dag_release_time(1, 0);
+        log.write_dag_finish_time(1, 105);
+        log.write_dag_release_time(1, 100);
+        log.write_dag_finish_time(1, 190);
+        log.calculate_response_time();
+
+        assert_eq!(
+            log.get_all_deadline_misses(&dag_set),
+            vec![
+                DeadlineMiss {
+                    dag_id: 0,
+                    job_id: 1,
+                    deadline: 150,
+                    finish_time: Some(160),
+                    overrun: Some(10),
+                },
+                DeadlineMiss {
+                    dag_id: 1,
+                    job_id: 0,
+                    deadline: 100,
+                    finish_time: Some(105),
+                    overrun: Some(5),
+                },
+            ]
+        );
+        assert_eq!(log.first_deadline_miss(&dag_set), Some((1, 100)));
+
+        let mut output = Vec::new();
+        dump_struct_to_writer(&mut output, &log);
+        let yaml_without_misses = String::from_utf8(output).unwrap();
+        assert!(!yaml_without_misses.contains("deadline_misses"));
+        log.write_deadline_misses(&dag_set);
+        let mut output = Vec::new();
+        dump_struct_to_writer(&mut output, &log);
+        let yaml_docs = YamlLoader::load_from_str(&String::from_utf8(output).unwrap()).unwrap();
+        assert_eq!(
+            yaml_docs[0]["deadline_misses"][0]["overrun"]
+                .as_i64()
+                .unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_calculate_response_time_percentiles_normal() {
+        let mut dag_log = DAGLog::new(0);
+        for (release_time, finish_time) in (0..10).map(|i| (i * 100, i * 100 + (i + 1) * 10)) {
+            dag_log.release_time.push(release_time);
+            dag_log.finish_time.push(finish_time);
+        }
+        // The last instance is unfinished and excluded from the percentiles.
+        dag_log.release_time.push(1000);
+        dag_log.calculate_response_time();
+        dag_log.calculate_median_and_tail_response_time();
+
+        assert_eq!(
+            dag_log.calculate_response_time_percentiles(&[0.0, 50.0, 90.0, 100.0]),
+            vec![10, 50, 90, 100]
+        );
+        assert_eq!(dag_log.p50_response_time, 50);
+        assert_eq!(dag_log.p99_response_time, 100);
+    }
+
+    #[test]
+    fn test_calculate_response_time_percentiles_empty() {
+        let mut dag_log = DAGLog::new(0);
+        dag_log.calculate_response_time();
+        assert_eq!(
+            dag_log.calculate_response_time_percentiles(&[50.0, 99.0]),
+            vec![0, 0]
+        );
+
+        // Only an unfinished instance is the same as no instance.
+        dag_log.release_time.push(0);
+        dag_log.calculate_response_time();
+        assert_eq!(
+            dag_log.calculate_response_time_percentiles(&[50.0, 99.0]),
+            vec![0, 0]
+        );
+    }
+
+    #[test]
+    fn test_calculate_response_time_percentiles_single_element() {
+        let mut dag_log = DAGLog::new(0);
+        dag_log.release_time.push(10);
+        dag_log.finish_time.push(35);
+        dag_log.calculate_response_time();
+        assert_eq!(
+            dag_log.calculate_response_time_percentiles(&[0.0, 50.0, 99.0]),
+            vec![25, 25, 25]
+        );
+    }
+
+    #[test]
+    fn test_get_all_deadline_misses_unfinished() {
+        let dag_set = create_dag_set(1);
+        let mut log = DAGSetSchedulerLog::new(&dag_set, 1);
+        log.write_dag_release_time(0, 0);
+        log.calculate_response_time();
+
+        let deadline_misses = log.get_all_deadline_misses(&dag_set);
+        assert_eq!(deadline_misses.len(), 1);
+        assert_eq!(deadline_misses[0].finish_time, None);
+        assert_eq!(deadline_misses[0].overrun, None);
+    }
+
+    #[test]
+    fn test_first_deadline_miss_no_miss() {
+        let dag_set = create_dag_set(1);
+        let mut log = DAGSetSchedulerLog::new(&dag_set, 1);
+        log.write_dag_release_time(0, 0);
+        log.write_dag_finish_time(0, 100);
+
+        assert_eq!(log.first_deadline_miss(&dag_set), None);
+    }
+
+    #[test]
+    fn test_get_job_intervals_split_by_preemption() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(1), 1);
+        let node = create_node(0, 0);
+        log.write_job_event(&node, 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&node, 0, 0, JobEventTimes::PreemptedTime(4));
+        log.write_job_event(&node, 0, 0, JobEventTimes::ResumeTime(6));
+        log.write_job_event(&node, 0, 0, JobEventTimes::FinishTime(10));
+
+        let job_intervals = log.get_job_intervals();
+        assert_eq!(job_intervals.len(), 2);
+        assert_eq!(
+            (job_intervals[0].start_time, job_intervals[0].end_time),
+            (0, 4)
+        );
+        assert_eq!(
+            (job_intervals[1].start_time, job_intervals[1].end_time),
+            (6, 10)
+        );
+    }
+
+    #[test]
+    fn test_windowed_utilization_front_loaded_burst() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(2), 2);
+        // Both cores are busy in 0-10, then only core 0 is busy in 10-40.
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::FinishTime(10));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::FinishTime(40));
+
+        let windowed_utilization = log.windowed_utilization(10);
+        assert_eq!(
+            windowed_utilization,
+            vec![(0, 1.0), (10, 0.5), (20, 0.5), (30, 0.5)]
+        );
+        assert!(windowed_utilization[0].1 > windowed_utilization[3].1);
+    }
+
+    #[test]
+    fn test_total_communication_cost_same_core_and_split() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, 0));
+        let n1 = dag.add_node(create_node(1, 0));
+        let n2 = dag.add_node(create_node(2, 0));
+        dag.add_edge(n0, n1, 3);
+        dag.add_edge(n0, n2, 5);
+        let dag_set = vec![dag];
+        let write_job = |log: &mut DAGSetSchedulerLog, node_id: i32, core_id: usize, start: i32| {
+            let node = create_node(node_id, 0);
+            log.write_job_event(&node, core_id, 0, JobEventTimes::StartTime(start));
+            log.write_job_event(&node, core_id, 0, JobEventTimes::FinishTime(start + 1));
+        };
+
+        // All nodes on core 0.
+        let mut same_core_log = DAGSetSchedulerLog::new(&dag_set, 2);
+        write_job(&mut same_core_log, 0, 0, 0);
+        write_job(&mut same_core_log, 1, 0, 1);
+        write_job(&mut same_core_log, 2, 0, 2);
+        assert_eq!(same_core_log.total_communication_cost(&dag_set), 0);
+
+        // Node 2 is split onto core 1, so only the edge 0 -> 2 is cross-core.
+        let mut split_log = DAGSetSchedulerLog::new(&dag_set, 2);
+        write_job(&mut split_log, 0, 0, 0);
+        write_job(&mut split_log, 1, 0, 1);
+        write_job(&mut split_log, 2, 1, 1);
+        assert_eq!(split_log.total_communication_cost(&dag_set), 5);
+    }
+
+    #[test]
+    fn test_core_affinity_report_one_migration() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(1), 2);
+        // Node 0 runs on core 0 except for the third release, and node 1 always runs on core 1.
+        for (job_id, core_id) in [0, 0, 1, 0].into_iter().enumerate() {
+            let release_time = job_id as i32 * 10;
+            let node = create_node(0, 0);
+            log.write_job_event(
+                &node,
+                core_id,
+                job_id,
+                JobEventTimes::StartTime(release_time),
+            );
+            log.write_job_event(
+                &node,
+                core_id,
+                job_id,
+                JobEventTimes::FinishTime(release_time + 1),
+            );
+            let node = create_node(1, 0);
+            log.write_job_event(&node, 1, job_id, JobEventTimes::StartTime(release_time + 1));
+            log.write_job_event(
+                &node,
+                1,
+                job_id,
+                JobEventTimes::FinishTime(release_time + 2),
+            );
+        }
+
+        let core_affinity = log.core_affinity_report();
+        assert_eq!(core_affinity.len(), 2);
+        assert_eq!(core_affinity[&(0, 0)], 0.75);
+        assert_eq!(core_affinity[&(0, 1)], 1.0);
+    }
+
+    #[test]
+    fn test_dump_log_to_yaml_with_verbosity_summary() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(2), 2);
+        log.write_dag_release_time(0, 0);
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::FinishTime(4));
+        log.write_dag_finish_time(0, 4);
+        log.calculate_response_time();
+
+        let file_path = "tests/dump_log_summary_test.yaml";
+        log.dump_log_to_yaml_with_verbosity(file_path, LogVerbosity::Summary);
+        let yaml_docs = YamlLoader::load_from_str(&read_to_string(file_path).unwrap()).unwrap();
+        remove_file(file_path).unwrap();
+        let yaml_doc = &yaml_docs[0];
+        assert!(yaml_doc["node_set_logs"].is_badvalue());
+        assert!(!yaml_doc["dag_set_info"].is_badvalue());
+        assert!(!yaml_doc["processor_log"].is_badvalue());
+        assert_eq!(
+            yaml_doc["dag_set_log"][0]["response_time"][0]
+                .as_i64()
+                .unwrap(),
+            4
+        );
+        assert_eq!(
+            yaml_doc["dag_set_log"][0]["worst_response_time"]
+                .as_i64()
+                .unwrap(),
+            4
+        );
+        assert_eq!(
+            yaml_doc["dag_set_log"][0]["p99_response_time"]
+                .as_i64()
+                .unwrap(),
+            4
+        );
+
+        log.dump_log_to_yaml_with_verbosity(file_path, LogVerbosity::Full);
+        let yaml_docs = YamlLoader::load_from_str(&read_to_string(file_path).unwrap()).unwrap();
+        remove_file(file_path).unwrap();
+        assert_eq!(yaml_docs[0]["node_set_logs"][0].as_vec().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_get_schedule_events_normal() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(2), 2);
+        // Node 1 is written as starting before node 0 is written as finishing at the same time.
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(1, 0), 0, 0, JobEventTimes::StartTime(4));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::FinishTime(4));
+        log.write_job_event(&create_node(1, 0), 0, 0, JobEventTimes::FinishTime(9));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::PreemptedTime(3));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::ResumeTime(5));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::FinishTime(8));
+
+        let schedule_events = log.get_schedule_events();
+        assert_eq!(
+            schedule_events.len(),
+            log.node_set_logs
+                .iter()
+                .map(|job_logs| job_logs.len())
+                .sum()
+        );
+        assert!(schedule_events
+            .windows(2)
+            .all(|pair| (pair[0].0, pair[0].1) <= (pair[1].0, pair[1].1)));
+        let timeline: Vec<_> = schedule_events
+            .iter()
+            .map(|&(time, core_id, event)| (time, core_id, event.dag_id, event.kind))
+            .collect();
+        assert_eq!(
+            timeline,
+            vec![
+                (0, 0, 0, ScheduleEventKind::Start),
+                (0, 1, 1, ScheduleEventKind::Start),
+                (3, 1, 1, ScheduleEventKind::Preempt),
+                (4, 0, 0, ScheduleEventKind::Finish),
+                (4, 0, 0, ScheduleEventKind::Start),
+                (5, 1, 1, ScheduleEventKind::Resume),
+                (8, 1, 1, ScheduleEventKind::Finish),
+                (9, 0, 0, ScheduleEventKind::Finish),
+            ]
+        );
+        assert_eq!(schedule_events[3].2.node_id, 0);
+        assert_eq!(schedule_events[4].2.node_id, 1);
+    }
+
+    #[test]
+    fn test_get_per_core_schedule_normal() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(2), 2);
+        // DAG 0 is preempted on core 1 and resumed on core 0 at the time DAG 1 finishes there.
+        // The events of DAG 0 are stored first, so the resume precedes the finish before sorting.
+        log.write_job_event(&create_node(0, 1), 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 1), 0, 0, JobEventTimes::FinishTime(4));
+        log.write_job_event(&create_node(1, 1), 1, 0, JobEventTimes::StartTime(6));
+        log.write_job_event(&create_node(1, 1), 1, 0, JobEventTimes::FinishTime(9));
+        log.write_job_event(&create_node(0, 0), 1, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 0), 1, 0, JobEventTimes::PreemptedTime(3));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::ResumeTime(4));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::FinishTime(8));
+
+        let per_core_schedule = log.get_per_core_schedule();
+        assert_eq!(per_core_schedule.len(), 2);
+        // Every job event appears exactly once, on the core it was written for.
+        let mut regrouped_events: Vec<_> = per_core_schedule
+            .iter()
+            .enumerate()
+            .flat_map(|(core_id, job_logs)| {
+                job_logs.iter().map(move |job_log| {
+                    assert_eq!(job_log.core_id, core_id);
+                    (
+                        job_log.dag_id,
+                        job_log.node_id,
+                        job_log.job_id,
+                        job_log.event_time.get_time(),
+                    )
+                })
+            })
+            .collect();
+        regrouped_events.sort();
+        let mut original_events: Vec<_> = log
+            .node_set_logs
+            .iter()
+            .flatten()
+            .map(|job_log| {
+                (
+                    job_log.dag_id,
+                    job_log.node_id,
+                    job_log.job_id,
+                    job_log.event_time.get_time(),
+                )
+            })
+            .collect();
+        original_events.sort();
+        assert_eq!(regrouped_events, original_events);
+        let core0_times: Vec<i32> = per_core_schedule[0]
+            .iter()
+            .map(|job_log| job_log.event_time.get_time())
+            .collect();
+        assert_eq!(core0_times, vec![0, 4, 4, 8]);
+        let core0_names: Vec<&str> = per_core_schedule[0]
+            .iter()
+            .map(|job_log| job_log.event_time.get_name())
+            .collect();
+        assert_eq!(
+            core0_names,
+            vec!["StartTime", "FinishTime", "ResumeTime", "FinishTime"]
+        );
+
+        let file_path = "tests/per_core_schedule_test.yaml";
+        log.dump_per_core_schedule_to_yaml(file_path);
+        let yaml_docs = YamlLoader::load_from_str(&read_to_string(file_path).unwrap()).unwrap();
+        remove_file(file_path).unwrap();
+        let yaml_per_core_schedule = yaml_docs[0]["per_core_schedule"].as_vec().unwrap();
+        assert_eq!(yaml_per_core_schedule.len(), 2);
+        assert_eq!(yaml_per_core_schedule[1].as_vec().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_dump_job_logs_to_csv_dag_set() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(2), 2);
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::FinishTime(10));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::PreemptedTime(3));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::ResumeTime(5));
+
+        let file_path = "tests/job_logs_dag_set_test.csv";
+        log.dump_job_logs_to_csv(file_path);
+        let csv = read_to_string(file_path).unwrap();
+        remove_file(file_path).unwrap();
+
+        assert_eq!(
+            csv,
+            "dag_id,node_id,job_id,core_id,event,time\n\
+             0,0,0,0,StartTime,0\n\
+             0,0,0,0,FinishTime,10\n\
+             1,0,0,1,StartTime,0\n\
+             1,0,0,1,PreemptedTime,3\n\
+             1,0,0,1,ResumeTime,5\n"
+        );
+    }
+
+    #[test]
+    fn test_dump_job_logs_to_csv_dag() {
+        let dag = create_dag_set(1).remove(0);
+        let mut log = DAGSchedulerLog::new(&dag, 2);
+        log.write_allocating_job(&dag[NodeIndex::new(0)], 1, 2);
+        log.write_finishing_job(&dag[NodeIndex::new(0)], 1, 3);
+
+        let file_path = "tests/job_logs_dag_test.csv";
+        log.dump_job_logs_to_csv(file_path);
+        let csv = read_to_string(file_path).unwrap();
+        remove_file(file_path).unwrap();
+
+        assert_eq!(
+            csv,
+            "dag_id,node_id,job_id,core_id,event,time\n0,0,0,1,StartTime,2\n0,0,0,1,FinishTime,3\n"
+        );
+    }
+
+    #[test]
+    fn test_dump_gantt_svg_preempted_job_split() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(2), 2);
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::FinishTime(10));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::PreemptedTime(3));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::ResumeTime(5));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::FinishTime(8));
+
+        let file_path = "tests/gantt_test.svg";
+        log.dump_gantt_svg(file_path);
+        let svg = read_to_string(file_path).unwrap();
+        remove_file(file_path).unwrap();
+
+        assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect ").count(), 3);
+        assert_eq!(svg.matches(">0:0</text>").count(), 1);
+        // The preempted job of DAG 1 is drawn as two boxes on core 1.
+        assert_eq!(svg.matches(">1:0</text>").count(), 2);
+        assert!(svg.contains(">core 1</text>"));
+        // 10 time units are scaled to the chart width, so the box from 5 to 8 starts at 70 + 500.
+        assert!(svg.contains("<rect x=\"570.0\" y=\"32\" width=\"300.0\""));
+    }
+
+    #[test]
+    fn test_dump_chrome_trace_normal() {
+        let mut log = DAGSetSchedulerLog::new(&create_dag_set(2), 2);
+        // DAG 0 runs without preemption, DAG 1 is preempted once.
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 0), 0, 0, JobEventTimes::FinishTime(10));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::StartTime(0));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::PreemptedTime(3));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::ResumeTime(5));
+        log.write_job_event(&create_node(0, 1), 1, 0, JobEventTimes::FinishTime(8));
+
+        let file_path = "tests/chrome_trace_test.json";
+        dump_chrome_trace(&log, file_path);
+        // JSON is a subset of YAML, so the trace can be parsed by the YAML loader.
+        let trace = YamlLoader::load_from_str(&read_to_string(file_path).unwrap()).unwrap();
+        remove_file(file_path).unwrap();
+
+        let trace_events = trace[0]["traceEvents"].as_vec().unwrap();
+        assert_eq!(trace_events.len(), 3);
+        assert!(trace_events
+            .iter()
+            .all(|event| event["ph"].as_str().unwrap() == "X"));
+        let dag0_events: Vec<_> = trace_events
+            .iter()
+            .filter(|event| event["pid"].as_i64().unwrap() == 0)
+            .collect();
+        assert_eq!(dag0_events.len(), 1);
+        assert_eq!(dag0_events[0]["tid"].as_i64().unwrap(), 0);
+        assert_eq!(dag0_events[0]["name"].as_str().unwrap(), "0");
+        assert_eq!(dag0_events[0]["ts"].as_i64().unwrap(), 0);
+        assert_eq!(dag0_events[0]["dur"].as_i64().unwrap(), 10);
+    }
 }
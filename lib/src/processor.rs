@@ -1,4 +1,13 @@
 use crate::{core::*, graph_extension::NodeData};
+use serde_derive::{Deserialize, Serialize};
+
+/// Power state of a core in a unit time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PowerState {
+    Active,
+    Idle,
+    Sleep,
+}
 
 pub trait ProcessorBase {
     fn new(num_cores: usize) -> Self;
@@ -9,4 +18,10 @@ pub trait ProcessorBase {
     fn get_idle_core_num(&self) -> usize;
     fn preempt(&mut self, core_id: usize) -> Option<NodeData>;
     fn get_max_value_and_index(&self, key: &str) -> Option<(i32, usize)>;
+    /// The node running on the core and its remaining processing time, or None if the core is idle.
+    fn get_processing_node_and_remain_time(&self, core_id: usize) -> Option<(NodeData, i32)>;
+    /// Energy consumed by `process` so far. 0.0 for a processor without an energy model.
+    fn get_consumed_energy(&self) -> f64 {
+        0.0
+    }
 }
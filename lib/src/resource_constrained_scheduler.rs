@@ -0,0 +1,163 @@
+use crate::getset_dag_scheduler;
+use crate::{
+    dag_scheduler::{DAGSchedulerBase, ExecutionTimeMode},
+    fixed_priority_scheduler::{
+        sort_by_priority, warn_missing_priorities, DEFAULT_FALLBACK_PRIORITY,
+    },
+    graph_extension::NodeData,
+    log::*,
+    processor::ProcessorBase,
+};
+use petgraph::Graph;
+use std::collections::VecDeque;
+
+/// Fixed-priority scheduler with a global resource pool shared by all cores.
+/// A ready node is dispatched only when the pool has at least its `resource_demand` units free,
+/// and the units are held until the node finishes. Nodes without `resource_demand` demand nothing.
+#[derive(Clone, Default)]
+pub struct ResourceConstrainedScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    dag: Graph<NodeData, i32>,
+    processor: T,
+    log: DAGSchedulerLog,
+    seed: Option<u64>,
+    dispatch_overhead: i32,
+    execution_time_mode: ExecutionTimeMode,
+    resource_capacity: i32,
+    available_resource: i32,
+}
+
+impl<T> ResourceConstrainedScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    pub fn set_resource_capacity(&mut self, resource_capacity: i32) {
+        self.resource_capacity = resource_capacity;
+        self.available_resource = resource_capacity;
+    }
+
+    pub fn get_resource_capacity(&self) -> i32 {
+        self.resource_capacity
+    }
+}
+
+fn get_resource_demand(node_data: &NodeData) -> i32 {
+    *node_data.params.get("resource_demand").unwrap_or(&0)
+}
+
+impl<T> DAGSchedulerBase<T> for ResourceConstrainedScheduler<T>
+where
+    T: ProcessorBase + Clone,
+{
+    /// The resource capacity is unlimited until `set_resource_capacity` is called.
+    fn new(dag: &Graph<NodeData, i32>, processor: &T) -> Self {
+        Self {
+            dag: dag.clone(),
+            processor: processor.clone(),
+            log: DAGSchedulerLog::new(dag, processor.get_number_of_cores()),
+            seed: None,
+            dispatch_overhead: 0,
+            execution_time_mode: ExecutionTimeMode::default(),
+            resource_capacity: i32::MAX,
+            available_resource: i32::MAX,
+        }
+    }
+
+    getset_dag_scheduler!(T);
+
+    fn sort_ready_queue(&self, ready_queue: &mut VecDeque<NodeData>) {
+        sort_by_priority(ready_queue, DEFAULT_FALLBACK_PRIORITY);
+    }
+
+    fn prepare_schedule(&mut self, dag: &Graph<NodeData, i32>) {
+        warn_missing_priorities(dag, DEFAULT_FALLBACK_PRIORITY);
+    }
+
+    fn try_acquire_resource(&mut self, node_data: &NodeData) -> bool {
+        let resource_demand = get_resource_demand(node_data);
+        if resource_demand > self.resource_capacity {
+            panic!(
+                "Resource demand {} of node {} exceeds the resource capacity {}.",
+                resource_demand, node_data.id, self.resource_capacity
+            );
+        }
+        if resource_demand <= self.available_resource {
+            self.available_resource -= resource_demand;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn release_resource(&mut self, node_data: &NodeData) {
+        self.available_resource += get_resource_demand(node_data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_extension::GraphExtension;
+    use crate::homogeneous::HomogeneousProcessor;
+    use petgraph::graph::NodeIndex;
+    use std::collections::BTreeMap;
+
+    fn create_node(id: i32, key: &str, value: i32) -> NodeData {
+        let mut params = BTreeMap::new();
+        params.insert(key.to_string(), value);
+        NodeData { id, params }
+    }
+
+    fn create_sample_dag() -> Graph<NodeData, i32> {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(create_node(0, "execution_time", 1));
+        let n1 = dag.add_node(create_node(1, "execution_time", 10));
+        let n2 = dag.add_node(create_node(2, "execution_time", 5));
+        dag.add_param(n0, "period", 100);
+        dag.add_param(n0, "priority", 0);
+        dag.add_param(n1, "priority", 0);
+        dag.add_param(n2, "priority", 1);
+        dag.add_param(n1, "resource_demand", 2);
+        dag.add_param(n2, "resource_demand", 1);
+        dag.add_edge(n0, n1, 1);
+        dag.add_edge(n0, n2, 1);
+
+        dag
+    }
+
+    #[test]
+    fn test_resource_constrained_scheduler_unlimited_resource() {
+        let dag = create_sample_dag();
+        let mut scheduler = ResourceConstrainedScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        let (schedule_length, _) = scheduler.schedule();
+
+        assert_eq!(schedule_length, 11);
+    }
+
+    #[test]
+    fn test_resource_constrained_scheduler_delay_by_resource() {
+        let dag = create_sample_dag();
+        let mut scheduler = ResourceConstrainedScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        scheduler.set_resource_capacity(2);
+        let (schedule_length, execution_order) = scheduler.schedule();
+
+        // n2 waits for n1 to release the resource although the second core is idle.
+        assert_eq!(schedule_length, 16);
+        assert_eq!(
+            execution_order,
+            vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2)]
+        );
+        assert_eq!(scheduler.available_resource, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_resource_constrained_scheduler_demand_exceeds_capacity() {
+        let dag = create_sample_dag();
+        let mut scheduler = ResourceConstrainedScheduler::new(&dag, &HomogeneousProcessor::new(2));
+        scheduler.set_resource_capacity(1);
+        scheduler.schedule();
+    }
+}
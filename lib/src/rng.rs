@@ -0,0 +1,80 @@
+//! Small seeded pseudo-random number generator (SplitMix64) for reproducible experiments.
+//! This is not cryptographically secure and is only intended for simulation.
+
+#[derive(Clone, Debug)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0.0, 1.0).
+    pub fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform integer in [low, high).
+    pub fn gen_range(&mut self, low: i32, high: i32) -> i32 {
+        assert!(
+            low < high,
+            "The range is empty. low: {}, high: {}",
+            low,
+            high
+        );
+        low + (self.next_u64() % (high - low) as u64) as i32
+    }
+
+    /// Shuffle the slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = (self.next_u64() % (i as u64 + 1)) as usize;
+            slice.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rng_same_seed_same_sequence() {
+        let mut rng_a = SeededRng::new(42);
+        let mut rng_b = SeededRng::new(42);
+        for _ in 0..10 {
+            assert_eq!(rng_a.next_u64(), rng_b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_seeded_rng_gen_range_within_bounds() {
+        let mut rng = SeededRng::new(0);
+        for _ in 0..1000 {
+            let value = rng.gen_range(-3, 5);
+            assert!((-3..5).contains(&value));
+            let real = rng.gen_f64();
+            assert!((0.0..1.0).contains(&real));
+        }
+    }
+
+    #[test]
+    fn test_seeded_rng_shuffle_is_permutation() {
+        let mut rng = SeededRng::new(7);
+        let mut values: Vec<i32> = (0..20).collect();
+        rng.shuffle(&mut values);
+        let mut sorted = values.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -4,9 +4,14 @@ use crate::{
 };
 use chrono::{DateTime, Utc};
 use log::{info, warn};
-use num_integer::lcm;
-use petgraph::graph::Graph;
+use num_integer::{gcd, lcm};
+use petgraph::{
+    graph::{Graph, NodeIndex},
+    visit::EdgeRef,
+};
+use serde_derive::Serialize;
 use std::{
+    collections::BTreeMap,
     fs::{self, OpenOptions},
     io::Write,
 };
@@ -15,12 +20,314 @@ use yaml_rust::YamlLoader;
 pub fn get_hyper_period(dag_set: &[Graph<NodeData, i32>]) -> i32 {
     let mut hyper_period = 1;
     for dag in dag_set {
-        let dag_period = dag.get_head_period().unwrap();
-        hyper_period = lcm(hyper_period, dag_period);
+        if dag.is_multi_rate() {
+            for node_period in dag.get_all_periods().unwrap().values() {
+                hyper_period = lcm(hyper_period, *node_period);
+            }
+        } else {
+            let dag_period = dag.get_head_period().unwrap();
+            hyper_period = lcm(hyper_period, dag_period);
+        }
     }
     hyper_period
 }
 
+/// Greatest common divisor of the periods of the DAG set, collected in the same way as `get_hyper_period`.
+/// The natural coarse tick size for aligning the releases.
+pub fn get_period_gcd(dag_set: &[Graph<NodeData, i32>]) -> i32 {
+    let mut period_gcd = 0;
+    for dag in dag_set {
+        if dag.is_multi_rate() {
+            for node_period in dag.get_all_periods().unwrap().values() {
+                period_gcd = gcd(period_gcd, *node_period);
+            }
+        } else {
+            period_gcd = gcd(period_gcd, dag.get_head_period().unwrap());
+        }
+    }
+    period_gcd
+}
+
+/// Blocking term of non-preemptive fixed-priority scheduling for a job at `priority_level`.
+/// Returns the longest execution time among nodes with a lower priority (a larger `priority` value),
+/// which may have started just before the job and cannot be preempted. Nodes without `priority` are ignored.
+pub fn max_blocking_for_priority(dag_set: &[Graph<NodeData, i32>], priority_level: i32) -> i32 {
+    dag_set
+        .iter()
+        .flat_map(|dag| dag.node_weights())
+        .filter(|node| {
+            node.params
+                .get("priority")
+                .is_some_and(|&priority| priority > priority_level)
+        })
+        .map(|node| node.get_params_value("execution_time"))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Difference between the critical paths of two versions of the same DAG.
+#[derive(Debug, PartialEq)]
+pub struct CriticalPathDiff {
+    /// Ids of the nodes on the critical path of `a` but not on that of `b`.
+    pub only_in_a: Vec<i32>,
+    /// Ids of the nodes on the critical path of `b` but not on that of `a`.
+    pub only_in_b: Vec<i32>,
+    /// Critical path length of `b` minus that of `a`.
+    pub length_delta: i32,
+}
+
+/// Compare the critical paths of `a` and `b`, e.g., the same DAG with WCETs estimated by different methods.
+/// The nodes are matched by their ids.
+pub fn critical_path_diff(
+    a: &mut Graph<NodeData, i32>,
+    b: &mut Graph<NodeData, i32>,
+) -> CriticalPathDiff {
+    let critical_path_a = a.get_critical_path();
+    let critical_path_b = b.get_critical_path();
+    let ids_a: Vec<i32> = critical_path_a.iter().map(|&node_i| a[node_i].id).collect();
+    let ids_b: Vec<i32> = critical_path_b.iter().map(|&node_i| b[node_i].id).collect();
+
+    CriticalPathDiff {
+        only_in_a: ids_a
+            .iter()
+            .filter(|id| !ids_b.contains(id))
+            .copied()
+            .collect(),
+        only_in_b: ids_b
+            .iter()
+            .filter(|id| !ids_a.contains(id))
+            .copied()
+            .collect(),
+        length_delta: b.get_total_wcet_from_nodes(&critical_path_b)
+            - a.get_total_wcet_from_nodes(&critical_path_a),
+    }
+}
+
+/// Maximum density among the DAGs of the set. The set is infeasible on any number of cores if it exceeds 1.
+pub fn max_density(dag_set: &[Graph<NodeData, i32>]) -> f32 {
+    dag_set
+        .iter()
+        .map(|dag| dag.clone().get_density())
+        .fold(0.0, f32::max)
+}
+
+/// Whether `order` contains every node of the DAG exactly once and every edge goes forward in it.
+/// Use this to check an execution order without pinning one of the valid orders.
+pub fn is_valid_topological_order(order: &[NodeIndex], dag: &Graph<NodeData, i32>) -> bool {
+    let mut position = vec![None; dag.node_count()];
+    for (i, node_i) in order.iter().enumerate() {
+        match position.get_mut(node_i.index()) {
+            Some(node_position @ None) => *node_position = Some(i),
+            _ => return false,
+        }
+    }
+    order.len() == dag.node_count()
+        && dag
+            .raw_edges()
+            .iter()
+            .all(|edge| position[edge.source().index()] < position[edge.target().index()])
+}
+
+/// Whether every node of the DAG has `priority`.
+/// `FixedPriorityScheduler` treats a node without `priority` as its fallback priority (the lowest by default).
+pub fn has_complete_priorities(dag: &Graph<NodeData, i32>) -> bool {
+    dag.node_weights()
+        .all(|node| node.params.contains_key("priority"))
+}
+
+/// Upper bound of the makespan of the DAG under any work-conserving scheduling on `num_cores` cores (Graham's bound):
+/// critical path length + (volume - critical path length) / `num_cores`.
+pub fn makespan_upper_bound(dag: &Graph<NodeData, i32>, num_cores: usize) -> f32 {
+    let mut dag = dag.clone();
+    let critical_path = dag.get_critical_path();
+    let critical_path_length = dag.get_total_wcet_from_nodes(&critical_path);
+    critical_path_length as f32
+        + (dag.get_volume() - critical_path_length) as f32 / num_cores as f32
+}
+
+/// Histogram of the normalized laxity of the DAGs, i.e., (deadline - `makespan_upper_bound`) / deadline,
+/// where the deadline is the end-to-end deadline or the period if it is not set.
+/// `bins` bins of equal width cover [0, 1). A negative laxity (the bound exceeds the deadline) is counted in the first bin.
+pub fn laxity_histogram(
+    dag_set: &[Graph<NodeData, i32>],
+    num_cores: usize,
+    bins: usize,
+) -> Vec<usize> {
+    assert!(bins > 0, "The number of bins must be positive.");
+    let mut histogram = vec![0; bins];
+    for dag in dag_set {
+        let deadline = dag
+            .get_end_to_end_deadline()
+            .or_else(|| dag.get_head_period())
+            .unwrap() as f32;
+        let laxity = (deadline - makespan_upper_bound(dag, num_cores)) / deadline;
+        let bin = ((laxity * bins as f32).floor().max(0.0) as usize).min(bins - 1);
+        histogram[bin] += 1;
+    }
+    histogram
+}
+
+/// Critical path length of each DAG of the set, in the order of the set.
+/// No scheduler on any number of cores can finish a job of the DAG earlier than this after its release.
+pub fn set_critical_path_bound(dag_set: &[Graph<NodeData, i32>]) -> Vec<i32> {
+    dag_set
+        .iter()
+        .map(|dag| {
+            let mut dag = dag.clone();
+            let critical_path = dag.get_critical_path();
+            dag.get_total_wcet_from_nodes(&critical_path)
+        })
+        .collect()
+}
+
+/// Indices of the DAGs whose critical path length exceeds the deadline, i.e., definitely infeasible.
+/// The deadline is the end-to-end deadline or the period if it is not set.
+pub fn critical_path_infeasible_dags(dag_set: &[Graph<NodeData, i32>]) -> Vec<usize> {
+    set_critical_path_bound(dag_set)
+        .into_iter()
+        .zip(dag_set)
+        .enumerate()
+        .filter(|(_, (critical_path_length, dag))| {
+            let deadline = dag
+                .get_end_to_end_deadline()
+                .or_else(|| dag.get_head_period())
+                .unwrap();
+            *critical_path_length > deadline
+        })
+        .map(|(dag_i, _)| dag_i)
+        .collect()
+}
+
+fn get_relative_deadline(dag: &Graph<NodeData, i32>) -> i32 {
+    dag.get_end_to_end_deadline()
+        .or_else(|| dag.get_head_period())
+        .unwrap()
+}
+
+/// Upper bound of the workload of a higher-priority DAG in a window of length `window` under global fixed-priority scheduling,
+/// with the carry-in job assumed to finish by its deadline.
+fn get_workload_bound(dag: &Graph<NodeData, i32>, num_cores: usize, window: f32) -> f32 {
+    let volume = dag.get_volume() as f32;
+    let period = dag.get_head_period().unwrap() as f32;
+    let shifted_window =
+        (window + get_relative_deadline(dag) as f32 - volume / num_cores as f32).max(0.0);
+    (shifted_window / period).floor() * volume
+        + volume.min(num_cores as f32 * (shifted_window % period))
+}
+
+/// Response time bound of the DAG under global fixed-priority scheduling on `num_cores` cores,
+/// interfered by `higher_priority_dags`. None if the bound exceeds the deadline
+/// (the end-to-end deadline or the period if it is not set).
+/// The bound does not depend on the priority order among `higher_priority_dags`.
+pub fn response_time_bound(
+    dag: &Graph<NodeData, i32>,
+    higher_priority_dags: &[&Graph<NodeData, i32>],
+    num_cores: usize,
+) -> Option<f32> {
+    let mut dag = dag.clone();
+    let critical_path = dag.get_critical_path();
+    let critical_path_length = dag.get_total_wcet_from_nodes(&critical_path) as f32;
+    let self_interference = (dag.get_volume() as f32 - critical_path_length) / num_cores as f32;
+    let deadline = get_relative_deadline(&dag) as f32;
+
+    let mut response_time = critical_path_length + self_interference;
+    while response_time <= deadline {
+        let interference: f32 = higher_priority_dags
+            .iter()
+            .map(|higher_priority_dag| {
+                get_workload_bound(higher_priority_dag, num_cores, response_time)
+            })
+            .sum();
+        let next_response_time =
+            critical_path_length + self_interference + interference / num_cores as f32;
+        if next_response_time == response_time {
+            return Some(response_time);
+        }
+        response_time = next_response_time;
+    }
+    None
+}
+
+/// Whether every DAG passes `response_time_bound` when the priorities follow `priority_order`
+/// (indices of `dag_set` from the highest priority).
+pub fn is_priority_order_schedulable(
+    dag_set: &[Graph<NodeData, i32>],
+    priority_order: &[usize],
+    num_cores: usize,
+) -> bool {
+    priority_order.iter().enumerate().all(|(level, &dag_i)| {
+        let higher_priority_dags: Vec<_> = priority_order[..level]
+            .iter()
+            .map(|&higher_dag_i| &dag_set[higher_dag_i])
+            .collect();
+        response_time_bound(&dag_set[dag_i], &higher_priority_dags, num_cores).is_some()
+    })
+}
+
+/// Audsley's optimal priority assignment with `response_time_bound` as the schedulability test.
+/// From the lowest priority level, a DAG schedulable with all the unassigned DAGs at higher priorities is assigned.
+/// Returns the indices of `dag_set` from the highest priority, or None if some level has no schedulable DAG.
+pub fn audsley_priority_assignment(
+    dag_set: &[Graph<NodeData, i32>],
+    num_cores: usize,
+) -> Option<Vec<usize>> {
+    let mut unassigned: Vec<usize> = (0..dag_set.len()).collect();
+    let mut priority_order = Vec::with_capacity(dag_set.len());
+    while !unassigned.is_empty() {
+        let position = unassigned.iter().position(|&dag_i| {
+            let higher_priority_dags: Vec<_> = unassigned
+                .iter()
+                .filter(|&&other_dag_i| other_dag_i != dag_i)
+                .map(|&other_dag_i| &dag_set[other_dag_i])
+                .collect();
+            response_time_bound(&dag_set[dag_i], &higher_priority_dags, num_cores).is_some()
+        })?;
+        priority_order.push(unassigned.remove(position));
+    }
+    priority_order.reverse();
+    Some(priority_order)
+}
+
+/// Rate-monotonic priority assignment: the DAG with the shorter head period gets the higher priority
+/// (a smaller value), and `priority` of every node of the DAG is set to the rank of the DAG starting from 0.
+/// Ties are broken by `dag_id` (the index in `dag_set` if it is not set). DAGs without a period come last.
+pub fn assign_rate_monotonic_priorities(dag_set: &mut [Graph<NodeData, i32>]) {
+    let mut dag_order: Vec<(i32, i32, usize)> = dag_set
+        .iter()
+        .enumerate()
+        .map(|(dag_i, dag)| {
+            let dag_id = dag
+                .node_weights()
+                .next()
+                .and_then(|node| node.params.get("dag_id").copied())
+                .unwrap_or(dag_i as i32);
+            (dag.get_head_period().unwrap_or(i32::MAX), dag_id, dag_i)
+        })
+        .collect();
+    dag_order.sort_unstable();
+    for (priority, (_, _, dag_i)) in dag_order.into_iter().enumerate() {
+        for node in dag_set[dag_i].node_weights_mut() {
+            node.params.insert("priority".to_string(), priority as i32);
+        }
+    }
+}
+
+/// Number of jobs of the DAG that should be released within the hyper-period.
+pub fn expected_job_count(dag: &Graph<NodeData, i32>, hyper_period: i32) -> i32 {
+    hyper_period / dag.get_head_period().unwrap()
+}
+
+/// Multiply the `execution_time` of every node by `wcet_factor`, rounding up.
+pub fn inflate_wcet(dag_set: &mut [Graph<NodeData, i32>], wcet_factor: f32) {
+    for dag in dag_set.iter_mut() {
+        for node in dag.node_weights_mut() {
+            if let Some(execution_time) = node.params.get_mut("execution_time") {
+                *execution_time = (*execution_time as f32 * wcet_factor).ceil() as i32;
+            }
+        }
+    }
+}
+
 pub fn adjust_to_implicit_deadline(dag_set: &mut [Graph<NodeData, i32>]) {
     for dag in dag_set.iter_mut() {
         let period = dag.get_head_period();
@@ -30,7 +337,7 @@ pub fn adjust_to_implicit_deadline(dag_set: &mut [Graph<NodeData, i32>]) {
                 if end_to_end_deadline != period {
                     warn!("In this algorithm, the period and the end-to-end deadline must be equal. Therefore, the end-to-end deadline is overridden by the period.");
                     dag.get_sink_nodes().iter().for_each(|&sink_i| {
-                        if dag[sink_i].params.get("end_to_end_deadline").is_some() {
+                        if dag[sink_i].params.contains_key("end_to_end_deadline") {
                             dag.update_param(sink_i, "end_to_end_deadline", period_value);
                         }
                     });
@@ -49,6 +356,74 @@ pub fn adjust_to_implicit_deadline(dag_set: &mut [Graph<NodeData, i32>]) {
     }
 }
 
+/// Counterpart of `adjust_to_implicit_deadline` that keeps the period and the end-to-end deadline independent.
+/// A missing end-to-end deadline is set to the period. The period is required, and the deadline must not exceed it.
+pub fn adjust_to_constrained_deadline(dag_set: &mut [Graph<NodeData, i32>]) {
+    for dag in dag_set.iter_mut() {
+        let period = dag
+            .get_head_period()
+            .unwrap_or_else(|| panic!("The period is required for the constrained deadline."));
+        match dag.get_end_to_end_deadline() {
+            Some(end_to_end_deadline) if end_to_end_deadline > period => {
+                panic!(
+                    "The end-to-end deadline {} exceeds the period {}.",
+                    end_to_end_deadline, period
+                );
+            }
+            Some(_) => {}
+            None => {
+                dag.add_param(dag.get_sink_nodes()[0], "end_to_end_deadline", period);
+            }
+        }
+    }
+}
+
+/// Check that the `dag_id` params of the DAG set are exactly 0..n, so that they can index per-DAG state.
+/// The ids are assigned when loading the DAG set from a directory.
+pub fn validate_dag_set_ids(dag_set: &[Graph<NodeData, i32>]) -> Result<(), String> {
+    let mut seen = vec![false; dag_set.len()];
+    for (i, dag) in dag_set.iter().enumerate() {
+        let dag_id = dag
+            .node_weight(NodeIndex::new(0))
+            .and_then(|node| node.params.get("dag_id"))
+            .ok_or_else(|| format!("The DAG at index {} has no dag_id", i))?;
+        if *dag_id < 0 || *dag_id as usize >= dag_set.len() {
+            return Err(format!(
+                "The dag_id {} of the DAG at index {} is out of 0..{}",
+                dag_id,
+                i,
+                dag_set.len()
+            ));
+        }
+        if seen[*dag_id as usize] {
+            return Err(format!(
+                "The dag_id {} of the DAG at index {} is duplicated",
+                dag_id, i
+            ));
+        }
+        seen[*dag_id as usize] = true;
+    }
+    Ok(())
+}
+
+/// Check that every node of the DAG set has all of the `required` parameters (see `validate_required_params`).
+/// The error names the first DAG with missing parameters.
+pub fn validate_dag_set_required_params(
+    dag_set: &[Graph<NodeData, i32>],
+    required: &[&str],
+) -> Result<(), String> {
+    for (i, dag) in dag_set.iter().enumerate() {
+        dag.validate_required_params(required)
+            .map_err(|missing_params| {
+                format!(
+                "The DAG at index {} has nodes without required parameters (node id, key): {:?}",
+                i, missing_params
+            )
+            })?;
+    }
+    Ok(())
+}
+
 pub fn load_yaml(file_path: &str) -> Vec<yaml_rust::Yaml> {
     if !file_path.ends_with(".yaml") && !file_path.ends_with(".yml") {
         panic!("Invalid file type: {}", file_path);
@@ -58,12 +433,7 @@ pub fn load_yaml(file_path: &str) -> Vec<yaml_rust::Yaml> {
 }
 
 pub fn append_info_to_yaml(file_path: &str, info: &str) {
-    if let Ok(mut file) = OpenOptions::new()
-        .write(true)
-        .append(true)
-        .create(true)
-        .open(file_path)
-    {
+    if let Ok(mut file) = OpenOptions::new().append(true).create(true).open(file_path) {
         if let Err(err) = file.write_all(info.as_bytes()) {
             eprintln!("Failed to write to file: {}", err);
         }
@@ -91,6 +461,129 @@ pub fn create_scheduler_log_yaml(dir_path: &str, alg_name: &str) -> String {
     create_yaml(dir_path, &file_name)
 }
 
+/// Render the DAG in the Graphviz DOT format.
+/// Each node is labeled with its id, `execution_time` and `priority` (if present), and each edge with its communication time.
+/// Nodes on the critical path are colored red.
+pub fn dag_to_dot(dag: &Graph<NodeData, i32>) -> String {
+    let critical_path = if dag.node_count() == 0 {
+        Vec::new()
+    } else {
+        dag.clone().get_critical_path()
+    };
+    let mut dot = String::from("digraph dag {\n");
+    for node_i in dag.node_indices() {
+        let node = &dag[node_i];
+        let mut label = format!("id: {}", node.id);
+        for key in ["execution_time", "priority"] {
+            if let Some(value) = node.params.get(key) {
+                label.push_str(&format!("\\n{}: {}", key, value));
+            }
+        }
+        let color = if critical_path.contains(&node_i) {
+            ", color=red, fontcolor=red"
+        } else {
+            ""
+        };
+        dot.push_str(&format!(
+            "    {} [label=\"{}\"{}];\n",
+            node_i.index(),
+            label,
+            color
+        ));
+    }
+    for edge in dag.edge_references() {
+        dot.push_str(&format!(
+            "    {} -> {} [label=\"{}\"];\n",
+            edge.source().index(),
+            edge.target().index(),
+            edge.weight()
+        ));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Write the DAG to `file_path` in the Graphviz DOT format (see `dag_to_dot`).
+pub fn dump_dag_to_dot(dag: &Graph<NodeData, i32>, file_path: &str) {
+    if let Some(folder_path) = std::path::Path::new(file_path).parent() {
+        if fs::metadata(folder_path).is_err() {
+            let _ = fs::create_dir_all(folder_path);
+            info!("Created folder: {}", folder_path.display());
+        }
+    }
+    if let Err(err) = fs::write(file_path, dag_to_dot(dag)) {
+        warn!("Failed to write file: {}", err);
+    }
+}
+
+/// Write each DAG of the DAGSet to `dag_<index>.dot` in `dir_path`.
+pub fn dump_dag_set_to_dot(dag_set: &[Graph<NodeData, i32>], dir_path: &str) {
+    for (dag_index, dag) in dag_set.iter().enumerate() {
+        dump_dag_to_dot(dag, &format!("{}/dag_{}.dot", dir_path, dag_index));
+    }
+}
+
+#[derive(Serialize)]
+struct DagYamlNode<'a> {
+    id: i32,
+    #[serde(flatten)]
+    params: &'a BTreeMap<String, i32>,
+}
+
+#[derive(Serialize)]
+struct DagYamlLink {
+    source: usize,
+    target: usize,
+    communication_time: i32,
+}
+
+/// The `nodes`/`links` schema of `create_dag_from_yaml` (the node-link format of NetworkX).
+#[derive(Serialize)]
+struct DagYaml<'a> {
+    directed: bool,
+    graph: BTreeMap<String, i32>,
+    links: Vec<DagYamlLink>,
+    multigraph: bool,
+    nodes: Vec<DagYamlNode<'a>>,
+}
+
+/// Write the DAG to `file_path` in the yaml format read by `create_dag_from_yaml`.
+/// The node ids, all node parameters and the communication times are written as they are, i.e., already scaled to integers.
+/// The links refer to the positions in `nodes`, which are the NodeIndex of the DAG.
+/// `EdgeData::params` are not part of the DAG and are not written.
+pub fn dump_dag_to_yaml(dag: &Graph<NodeData, i32>, file_path: &str) {
+    let dag_yaml = DagYaml {
+        directed: true,
+        graph: BTreeMap::new(),
+        links: dag
+            .edge_references()
+            .map(|edge| DagYamlLink {
+                source: edge.source().index(),
+                target: edge.target().index(),
+                communication_time: *edge.weight(),
+            })
+            .collect(),
+        multigraph: false,
+        nodes: dag
+            .node_weights()
+            .map(|node| DagYamlNode {
+                id: node.id,
+                params: &node.params,
+            })
+            .collect(),
+    };
+    if let Some(folder_path) = std::path::Path::new(file_path).parent() {
+        if fs::metadata(folder_path).is_err() {
+            let _ = fs::create_dir_all(folder_path);
+            info!("Created folder: {}", folder_path.display());
+        }
+    }
+    let yaml = serde_yaml::to_string(&dag_yaml).expect("Failed to serialize.");
+    if let Err(err) = fs::write(file_path, yaml) {
+        warn!("Failed to write file: {}", err);
+    }
+}
+
 pub fn get_process_core_indices(process_result: &[ProcessResult]) -> Vec<usize> {
     process_result
         .iter()
@@ -106,9 +599,10 @@ pub fn get_process_core_indices(process_result: &[ProcessResult]) -> Vec<usize>
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
-    use std::collections::BTreeMap;
+    use crate::dag_creator::create_dag_from_yaml;
+    use crate::global_edf_scheduler::tests::create_single_node_dag;
 
     fn create_dag() -> Graph<NodeData, i32> {
         let mut dag = Graph::<NodeData, i32>::new();
@@ -151,7 +645,10 @@ mod tests {
         dag
     }
 
-    fn create_dag_with_period_and_deadline(period: i32, deadline: i32) -> Graph<NodeData, i32> {
+    pub(crate) fn create_dag_with_period_and_deadline(
+        period: i32,
+        deadline: i32,
+    ) -> Graph<NodeData, i32> {
         let mut dag = Graph::<NodeData, i32>::new();
         let mut params = BTreeMap::new();
         params.insert("execution_time".to_owned(), 4);
@@ -179,6 +676,259 @@ mod tests {
         assert_eq!(get_hyper_period(&dag_set), 120);
     }
 
+    #[test]
+    fn test_get_period_gcd_normal() {
+        let dag_set = vec![
+            create_dag_with_period(20),
+            create_dag_with_period(30),
+            create_dag_with_period(50),
+        ];
+        assert_eq!(get_period_gcd(&dag_set), 10);
+    }
+
+    #[test]
+    fn test_critical_path_diff_moved_path() {
+        let mut dag_a = Graph::<NodeData, i32>::new();
+        let n0 = dag_a.add_node(NodeData::with_params(0, &[("execution_time", 3)]));
+        let n1 = dag_a.add_node(NodeData::with_params(1, &[("execution_time", 10)]));
+        let n2 = dag_a.add_node(NodeData::with_params(2, &[("execution_time", 5)]));
+        let n3 = dag_a.add_node(NodeData::with_params(3, &[("execution_time", 2)]));
+        dag_a.add_edge(n0, n1, 0);
+        dag_a.add_edge(n0, n2, 0);
+        dag_a.add_edge(n1, n3, 0);
+        dag_a.add_edge(n2, n3, 0);
+
+        let mut dag_b = dag_a.clone();
+        dag_b.update_param(n2, "execution_time", 20);
+
+        assert_eq!(
+            critical_path_diff(&mut dag_a, &mut dag_b),
+            CriticalPathDiff {
+                only_in_a: vec![1],
+                only_in_b: vec![2],
+                length_delta: 10,
+            }
+        );
+        assert_eq!(
+            critical_path_diff(&mut dag_a.clone(), &mut dag_a),
+            CriticalPathDiff {
+                only_in_a: vec![],
+                only_in_b: vec![],
+                length_delta: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_max_density_normal() {
+        let mut dag_set = vec![
+            create_dag_with_deadline(16),
+            create_dag_with_period(40),
+            create_dag_with_period_and_deadline(20, 10),
+        ];
+        assert_eq!(dag_set[0].get_density(), 0.5);
+        assert_eq!(dag_set[1].get_density(), 0.2);
+        assert_eq!(dag_set[2].get_density(), 0.8);
+        assert_eq!(max_density(&dag_set), 0.8);
+
+        dag_set.push(create_dag_with_deadline(4));
+        assert_eq!(max_density(&dag_set), 2.0);
+    }
+
+    #[test]
+    fn test_set_critical_path_bound_with_infeasible_dag() {
+        // The chain DAGs have a critical path length of 8.
+        let mut dag_set = vec![
+            create_dag_with_period(40),
+            create_dag_with_deadline(6),
+            create_dag_with_period_and_deadline(20, 8),
+        ];
+        let n2 = dag_set[2].add_node(NodeData::with_params(2, &[("execution_time", 5)]));
+        dag_set[2].add_edge(NodeIndex::new(0), n2, 0);
+
+        assert_eq!(set_critical_path_bound(&dag_set), vec![8, 8, 9]);
+        assert_eq!(critical_path_infeasible_dags(&dag_set), vec![1, 2]);
+        assert!(critical_path_infeasible_dags(&dag_set[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_is_valid_topological_order_diamond() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(0, &[("execution_time", 1)]));
+        let n1 = dag.add_node(NodeData::with_params(1, &[("execution_time", 1)]));
+        let n2 = dag.add_node(NodeData::with_params(2, &[("execution_time", 1)]));
+        let n3 = dag.add_node(NodeData::with_params(3, &[("execution_time", 1)]));
+        dag.add_edge(n0, n1, 0);
+        dag.add_edge(n0, n2, 0);
+        dag.add_edge(n1, n3, 0);
+        dag.add_edge(n2, n3, 0);
+
+        assert!(is_valid_topological_order(&[n0, n1, n2, n3], &dag));
+        assert!(is_valid_topological_order(&[n0, n2, n1, n3], &dag));
+        assert!(!is_valid_topological_order(&[n1, n0, n2, n3], &dag));
+        assert!(!is_valid_topological_order(&[n0, n1, n3, n2], &dag));
+        // Missing or duplicated nodes.
+        assert!(!is_valid_topological_order(&[n0, n1, n2], &dag));
+        assert!(!is_valid_topological_order(&[n0, n1, n1, n2, n3], &dag));
+    }
+
+    #[test]
+    fn test_has_complete_priorities_partially_prioritized() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 3), ("priority", 0)],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(1, &[("execution_time", 4)]));
+        dag.add_edge(n0, n1, 1);
+
+        assert!(!has_complete_priorities(&dag));
+        dag.add_param(n1, "priority", 1);
+        assert!(has_complete_priorities(&dag));
+    }
+
+    #[test]
+    fn test_laxity_histogram_normal() {
+        // The chain DAGs have a critical path length and a volume of 8.
+        let mut dag_set = vec![
+            create_dag_with_period_and_deadline(10, 10),
+            create_dag_with_period_and_deadline(16, 16),
+            create_dag_with_period_and_deadline(80, 80),
+            create_dag_with_period_and_deadline(40, 40),
+            create_dag_with_period_and_deadline(8, 8),
+            create_dag_with_period_and_deadline(8, 4),
+        ];
+        let mut parallel_dag = Graph::<NodeData, i32>::new();
+        let n0 = parallel_dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 2), ("period", 20)],
+        ));
+        let n1 = parallel_dag.add_node(NodeData::with_params(1, &[("execution_time", 4)]));
+        let n2 = parallel_dag.add_node(NodeData::with_params(2, &[("execution_time", 4)]));
+        let n3 = parallel_dag.add_node(NodeData::with_params(3, &[("execution_time", 2)]));
+        parallel_dag.add_edge(n0, n1, 0);
+        parallel_dag.add_edge(n0, n2, 0);
+        parallel_dag.add_edge(n1, n3, 0);
+        parallel_dag.add_edge(n2, n3, 0);
+        assert_eq!(makespan_upper_bound(&parallel_dag, 2), 10.0);
+        dag_set.push(parallel_dag);
+
+        // Laxities: 0.2, 0.5, 0.9, 0.8, 0.0, -1.0 and 0.5.
+        assert_eq!(laxity_histogram(&dag_set, 2, 4), vec![3, 0, 2, 2]);
+        assert_eq!(laxity_histogram(&dag_set, 2, 1), vec![7]);
+    }
+
+    #[test]
+    fn test_expected_job_count_normal() {
+        let dag_set = vec![create_dag_with_period(10), create_dag_with_period(15)];
+        let hyper_period = get_hyper_period(&dag_set);
+        assert_eq!(expected_job_count(&dag_set[0], hyper_period), 3);
+        assert_eq!(expected_job_count(&dag_set[1], hyper_period), 2);
+    }
+
+    #[test]
+    fn test_audsley_priority_assignment_beats_rate_monotonic() {
+        // DAG 0 has the longer period but the tighter deadline.
+        let dag_set = vec![
+            create_single_node_dag(3, 10, 4),
+            create_single_node_dag(2, 6, 6),
+        ];
+
+        let rate_monotonic_order = [1, 0];
+        assert!(!is_priority_order_schedulable(
+            &dag_set,
+            &rate_monotonic_order,
+            1
+        ));
+
+        let priority_order = audsley_priority_assignment(&dag_set, 1).unwrap();
+        assert_eq!(priority_order, vec![0, 1]);
+        assert!(is_priority_order_schedulable(&dag_set, &priority_order, 1));
+
+        // No order is feasible once DAG 1 cannot fit either.
+        let infeasible_dag_set = vec![
+            create_single_node_dag(3, 10, 4),
+            create_single_node_dag(2, 6, 4),
+        ];
+        assert_eq!(audsley_priority_assignment(&infeasible_dag_set, 1), None);
+    }
+
+    #[test]
+    fn test_validate_dag_set_ids_normal() {
+        let mut dag_set = vec![create_dag(), create_dag(), create_dag()];
+        for (dag_id, dag) in dag_set.iter_mut().enumerate() {
+            dag.set_dag_param("dag_id", dag_id as i32);
+        }
+        assert_eq!(validate_dag_set_ids(&dag_set), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_dag_set_ids_duplicated() {
+        let mut dag_set = vec![create_dag(), create_dag(), create_dag()];
+        for (dag_id, dag) in dag_set.iter_mut().enumerate() {
+            dag.set_dag_param("dag_id", dag_id as i32 % 2);
+        }
+        assert_eq!(
+            validate_dag_set_ids(&dag_set),
+            Err("The dag_id 0 of the DAG at index 2 is duplicated".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_dag_set_ids_gap_and_missing() {
+        let mut dag_set = vec![create_dag(), create_dag()];
+        dag_set[0].set_dag_param("dag_id", 0);
+        assert!(validate_dag_set_ids(&dag_set)
+            .unwrap_err()
+            .contains("has no dag_id"));
+        dag_set[1].set_dag_param("dag_id", 2);
+        assert!(validate_dag_set_ids(&dag_set)
+            .unwrap_err()
+            .contains("out of 0..2"));
+    }
+
+    #[test]
+    fn test_validate_dag_set_required_params_missing() {
+        let mut dag_set = vec![create_dag(), create_dag()];
+        assert_eq!(
+            validate_dag_set_required_params(&dag_set, &["execution_time"]),
+            Ok(())
+        );
+        dag_set[1].add_node(NodeData::with_params(1, &[("period", 10)]));
+        assert_eq!(
+            validate_dag_set_required_params(&dag_set, &["execution_time"]),
+            Err("The DAG at index 1 has nodes without required parameters (node id, key): [(1, \"execution_time\")]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_assign_rate_monotonic_priorities_normal() {
+        let mut dag_set = vec![
+            create_dag_with_period(30),
+            create_dag_with_period(10),
+            create_dag_with_period(30),
+            create_dag(),
+        ];
+        for (dag_id, dag) in dag_set.iter_mut().enumerate() {
+            dag.set_dag_param("dag_id", dag_id as i32);
+        }
+
+        assign_rate_monotonic_priorities(&mut dag_set);
+        let priorities: Vec<Vec<i32>> = dag_set
+            .iter()
+            .map(|dag| {
+                dag.node_weights()
+                    .map(|node| node.params["priority"])
+                    .collect()
+            })
+            .collect();
+        // The tie of period 30 is broken by dag_id, and the DAG without period comes last.
+        assert_eq!(
+            priorities,
+            vec![vec![1, 1], vec![0, 0], vec![2, 2], vec![3]]
+        );
+    }
+
     #[test]
     fn test_adjust_to_implicit_deadline_with_same_period_and_deadline() {
         let mut dag_set = vec![create_dag_with_period_and_deadline(10, 10)];
@@ -211,6 +961,25 @@ mod tests {
         assert_eq!(dag_set[0].get_end_to_end_deadline().unwrap(), 20);
     }
 
+    #[test]
+    fn test_adjust_to_constrained_deadline_normal() {
+        let mut dag_set = vec![
+            create_dag_with_period_and_deadline(20, 15),
+            create_dag_with_period(30),
+        ];
+        adjust_to_constrained_deadline(&mut dag_set);
+        assert_eq!(dag_set[0].get_head_period().unwrap(), 20);
+        assert_eq!(dag_set[0].get_end_to_end_deadline().unwrap(), 15);
+        assert_eq!(dag_set[1].get_end_to_end_deadline().unwrap(), 30);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the period")]
+    fn test_adjust_to_constrained_deadline_deadline_beyond_period() {
+        let mut dag_set = vec![create_dag_with_period_and_deadline(10, 20)];
+        adjust_to_constrained_deadline(&mut dag_set);
+    }
+
     #[test]
     #[should_panic]
     fn test_adjust_to_implicit_deadline_no_period_and_deadline() {
@@ -233,4 +1002,102 @@ mod tests {
         ];
         assert_eq!(get_process_core_indices(&process_result), vec![0, 3]);
     }
+
+    #[test]
+    fn test_max_blocking_for_priority_normal() {
+        fn create_node(id: i32, execution_time: i32, priority: i32) -> NodeData {
+            let mut params = BTreeMap::new();
+            params.insert("execution_time".to_owned(), execution_time);
+            params.insert("priority".to_owned(), priority);
+            NodeData { id, params }
+        }
+        let mut dag0 = Graph::<NodeData, i32>::new();
+        dag0.add_node(create_node(0, 5, 0));
+        dag0.add_node(create_node(1, 30, 0));
+        let mut dag1 = Graph::<NodeData, i32>::new();
+        dag1.add_node(create_node(0, 12, 1));
+        dag1.add_node(create_node(1, 20, 2));
+        let dag_set = vec![dag0, dag1];
+
+        assert_eq!(max_blocking_for_priority(&dag_set, 0), 20);
+        assert_eq!(max_blocking_for_priority(&dag_set, 1), 20);
+        assert_eq!(max_blocking_for_priority(&dag_set, 2), 0);
+    }
+
+    #[test]
+    fn test_dag_to_dot_normal() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 3), ("priority", 0)],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(1, &[("execution_time", 5)]));
+        let n2 = dag.add_node(NodeData::with_params(2, &[("execution_time", 1)]));
+        dag.add_edge(n0, n1, 2);
+        dag.add_edge(n0, n2, 0);
+
+        let dot = dag_to_dot(&dag);
+        assert!(dot.starts_with("digraph dag {\n"));
+        assert!(dot.contains(
+            "0 [label=\"id: 0\\nexecution_time: 3\\npriority: 0\", color=red, fontcolor=red];"
+        ));
+        assert!(dot.contains("1 [label=\"id: 1\\nexecution_time: 5\", color=red, fontcolor=red];"));
+        assert!(dot.contains("2 [label=\"id: 2\\nexecution_time: 1\"];"));
+        assert!(dot.contains("0 -> 1 [label=\"2\"];"));
+        assert!(dot.contains("0 -> 2 [label=\"0\"];"));
+    }
+
+    #[test]
+    fn test_dump_dag_to_yaml_round_trip() {
+        let dag = create_dag_from_yaml("tests/sample_dags/chain_base_format.yaml", false);
+        let file_path = "tests/dump_dag_to_yaml_test.yaml";
+        dump_dag_to_yaml(&dag, file_path);
+        let reloaded_dag = create_dag_from_yaml(file_path, false);
+        fs::remove_file(file_path).unwrap();
+
+        assert_eq!(reloaded_dag.node_count(), dag.node_count());
+        assert_eq!(reloaded_dag.edge_count(), dag.edge_count());
+        for node_i in dag.node_indices() {
+            assert_eq!(reloaded_dag[node_i], dag[node_i]);
+        }
+        for edge in dag.edge_references() {
+            let reloaded_edge = reloaded_dag
+                .find_edge(edge.source(), edge.target())
+                .unwrap();
+            assert_eq!(reloaded_dag[reloaded_edge], *edge.weight());
+        }
+    }
+
+    #[test]
+    fn test_dump_dag_to_yaml_communication_time() {
+        let mut dag = Graph::<NodeData, i32>::new();
+        let n0 = dag.add_node(NodeData::with_params(
+            0,
+            &[("execution_time", 3), ("period", 50)],
+        ));
+        let n1 = dag.add_node(NodeData::with_params(
+            1,
+            &[("execution_time", 4), ("priority", 1)],
+        ));
+        dag.add_edge(n0, n1, 7);
+        let file_path = "tests/dump_dag_to_yaml_communication_time_test.yaml";
+        dump_dag_to_yaml(&dag, file_path);
+        let reloaded_dag = create_dag_from_yaml(file_path, false);
+        fs::remove_file(file_path).unwrap();
+
+        assert_eq!(reloaded_dag.dag_fingerprint(), dag.dag_fingerprint());
+        assert_eq!(reloaded_dag[n0], dag[n0]);
+        assert_eq!(reloaded_dag[n1], dag[n1]);
+        assert_eq!(reloaded_dag[reloaded_dag.find_edge(n0, n1).unwrap()], 7);
+    }
+
+    #[test]
+    fn test_dump_dag_to_dot_normal() {
+        let dag = create_dag();
+        let file_path = "../lib/tests/dump_dag_to_dot_test.dot";
+        dump_dag_to_dot(&dag, file_path);
+        let dot = fs::read_to_string(file_path).unwrap();
+        fs::remove_file(file_path).unwrap();
+        assert_eq!(dot, dag_to_dot(&dag));
+    }
 }